@@ -143,6 +143,18 @@ fn run_with_ghidra(args: &CmdlineArgs) {
     );
     // Normalize the project and gather log messages generated from it.
     all_logs.append(&mut project.normalize());
+    // Register additional format string functions given in the configuration file.
+    if !config["FormatStringFunctions"].is_null() {
+        match serde_json::from_value(config["FormatStringFunctions"].clone()) {
+            Ok(format_string_functions) => project
+                .format_string_functions
+                .extend(format_string_functions),
+            Err(err) => all_logs.push(LogMessage::new_error(format!(
+                "Invalid FormatStringFunctions section in the configuration file: {}",
+                err
+            ))),
+        }
+    }
 
     // Generate the representation of the runtime memory image of the binary
     let mut runtime_memory_image = if let Some(bare_metal_config) = bare_metal_config_opt.as_ref() {
@@ -1,5 +1,8 @@
 use super::Interval;
-use super::{AbstractDomain, HasTop, RegisterDomain, SizedDomain, TryToBitvec, TryToInterval};
+use super::{
+    AbstractDomain, HasTop, RegisterDomain, SizedDomain, SpecializeByConditional, TryToBitvec,
+    TryToInterval,
+};
 use crate::intermediate_representation::*;
 use crate::prelude::*;
 
@@ -115,6 +118,55 @@ impl RegisterDomain for BitvectorDomain {
     }
 }
 
+impl BitvectorDomain {
+    /// Return `self` if `self` is `Top` or if the contained value satisfies the given condition.
+    /// Else return an error.
+    fn restrict_by(self, condition: impl FnOnce(&Bitvector) -> bool) -> Result<Self, Error> {
+        match &self {
+            BitvectorDomain::Top(_) => Ok(self),
+            BitvectorDomain::Value(value) if condition(value) => Ok(self),
+            BitvectorDomain::Value(_) => Err(anyhow!("Empty value")),
+        }
+    }
+}
+
+/// Since a `BitvectorDomain` can only represent exact values or `Top`,
+/// bounds cannot be used to restrict `Top` values.
+impl SpecializeByConditional for BitvectorDomain {
+    fn add_signed_less_equal_bound(self, bound: &Bitvector) -> Result<Self, Error> {
+        self.restrict_by(|value| value.checked_sle(bound).unwrap())
+    }
+
+    fn add_unsigned_less_equal_bound(self, bound: &Bitvector) -> Result<Self, Error> {
+        self.restrict_by(|value| value.checked_ule(bound).unwrap())
+    }
+
+    fn add_signed_greater_equal_bound(self, bound: &Bitvector) -> Result<Self, Error> {
+        self.restrict_by(|value| value.checked_sge(bound).unwrap())
+    }
+
+    fn add_unsigned_greater_equal_bound(self, bound: &Bitvector) -> Result<Self, Error> {
+        self.restrict_by(|value| value.checked_uge(bound).unwrap())
+    }
+
+    fn add_not_equal_bound(self, bound: &Bitvector) -> Result<Self, Error> {
+        self.restrict_by(|value| value != bound)
+    }
+
+    fn intersect(self, other: &Self) -> Result<Self, Error> {
+        match (&self, other) {
+            (BitvectorDomain::Top(_), _) => Ok(other.clone()),
+            (_, BitvectorDomain::Top(_)) => Ok(self),
+            (BitvectorDomain::Value(value), BitvectorDomain::Value(other_value))
+                if value == other_value =>
+            {
+                Ok(self)
+            }
+            _ => Err(anyhow!("Domain is empty.")),
+        }
+    }
+}
+
 impl std::ops::Add for BitvectorDomain {
     type Output = BitvectorDomain;
 
@@ -284,6 +336,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn conditional_specialization() {
+        let top = BitvectorDomain::new_top(ByteSize::new(8));
+        assert_eq!(bv(5).intersect(&bv(5)).unwrap(), bv(5));
+        assert!(bv(5).intersect(&bv(6)).is_err());
+        assert_eq!(top.clone().intersect(&bv(5)).unwrap(), bv(5));
+        assert_eq!(bv(5).intersect(&top).unwrap(), bv(5));
+
+        let bound = Bitvector::from_i64(0);
+        assert_eq!(bv(-1).add_signed_less_equal_bound(&bound).unwrap(), bv(-1));
+        assert!(bv(-1).add_unsigned_less_equal_bound(&bound).is_err());
+        assert!(bv(-1).add_signed_greater_equal_bound(&bound).is_err());
+        assert_eq!(
+            bv(-1).add_unsigned_greater_equal_bound(&bound).unwrap(),
+            bv(-1)
+        );
+        assert!(bv(0).add_not_equal_bound(&bound).is_err());
+        assert_eq!(top.clone().add_not_equal_bound(&bound).unwrap(), top);
+    }
+
     #[test]
     fn float_nan_bytesize() {
         let top_value = BitvectorDomain::new_top(ByteSize::new(8));
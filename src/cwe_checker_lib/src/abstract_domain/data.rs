@@ -1,6 +1,6 @@
 use super::{
-    AbstractDomain, AbstractIdentifier, HasTop, Interval, RegisterDomain, SizedDomain,
-    SpecializeByConditional, TryToBitvec, TryToInterval,
+    AbstractDomain, AbstractIdentifier, HasTop, Interval, IntervalDomain, RegisterDomain,
+    SizedDomain, SpecializeByConditional, TryToBitvec, TryToInterval,
 };
 use crate::intermediate_representation::*;
 use crate::prelude::*;
@@ -127,6 +127,23 @@ impl<T: RegisterDomain> DataDomain<T> {
         self.contains_top_values = false;
     }
 
+    /// If the domain contains `Top` values, replace them with the given target plus offset.
+    ///
+    /// The target is merged into the relative values of the domain and the `Top` flag is cleared,
+    /// i.e. the values of unknown origin are represented by the given target afterwards.
+    /// If the domain does not contain `Top` values, it is left unchanged.
+    pub fn replace_top_with(&mut self, target: AbstractIdentifier, offset: T) {
+        if !self.contains_top_values {
+            return;
+        }
+        let offset = match self.relative_values.get(&target) {
+            Some(old_offset) => old_offset.merge(&offset),
+            None => offset,
+        };
+        self.relative_values.insert(target, offset);
+        self.contains_top_values = false;
+    }
+
     /// Return a new value representing a variable plus an offset,
     /// where the variable is represented by the given abstract ID.
     pub fn from_target(id: AbstractIdentifier, offset: T) -> Self {
@@ -153,6 +170,29 @@ impl<T: RegisterDomain> DataDomain<T> {
             .collect();
     }
 
+    /// Returns `true` if `self` contains an absolute value
+    /// but no relative or `Top` values.
+    pub fn is_pure_absolute(&self) -> bool {
+        self.absolute_value.is_some()
+            && self.relative_values.is_empty()
+            && !self.contains_top_values
+    }
+
+    /// Returns `true` if `self` contains relative values
+    /// but no absolute or `Top` values.
+    pub fn is_pure_relative(&self) -> bool {
+        !self.relative_values.is_empty()
+            && self.absolute_value.is_none()
+            && !self.contains_top_values
+    }
+
+    /// Returns `true` if `self` contains relative values
+    /// together with absolute or `Top` values.
+    pub fn is_mixed(&self) -> bool {
+        !self.relative_values.is_empty()
+            && (self.absolute_value.is_some() || self.contains_top_values)
+    }
+
     /// Return the contained absolute value
     /// only if `self` contains no other (relative or `Top`) values.
     pub fn get_if_absolute_value(&self) -> Option<&T> {
@@ -185,6 +225,82 @@ impl<T: RegisterDomain> DataDomain<T> {
             None
         }
     }
+
+    /// Return whether `self` and `other` may point to the same memory object,
+    /// i.e. whether they share a relative target ID (regardless of the offsets to the target).
+    ///
+    /// The check is conservative: If one of the values contains `Top`,
+    /// then it may point to any object and `true` is returned.
+    /// Absolute values are not considered to alias with any other value.
+    pub fn may_alias(&self, other: &Self) -> bool {
+        self.contains_top_values
+            || other.contains_top_values
+            || self
+                .relative_values
+                .keys()
+                .any(|id| other.relative_values.contains_key(id))
+    }
+}
+
+impl<T: RegisterDomain + TryToBitvec> DataDomain<T> {
+    /// Returns `true` if `self` definitely represents the given value,
+    /// i.e. if it contains a single absolute value equal to `value` and no relative or `Top` values.
+    ///
+    /// Note that values of different bytesizes are never equal.
+    pub fn is_exactly(&self, value: &Bitvector) -> bool {
+        matches!(self.try_to_bitvec(), Ok(bitvec) if bitvec == *value)
+    }
+}
+
+impl<T: RegisterDomain + TryToInterval> DataDomain<T> {
+    /// Return the absolute component of `self` projected into an [`IntervalDomain`].
+    ///
+    /// Returns `None` if `self` has no absolute component.
+    /// If the absolute component is not representable as a bounded interval,
+    /// then an interval domain representing `Top` is returned.
+    /// Note that widening hints of the absolute component are not kept.
+    pub fn get_absolute_value_as_interval(&self) -> Option<IntervalDomain> {
+        self.absolute_value.as_ref().map(|value| {
+            value
+                .try_to_interval()
+                .map(IntervalDomain::from)
+                .unwrap_or_else(|_| IntervalDomain::new_top(value.bytesize()))
+        })
+    }
+
+    /// Return a list of all absolute values that `self` may represent.
+    ///
+    /// Relative values contained in `self` are ignored.
+    /// Returns `None` if `self` may contain `Top` values,
+    /// if the absolute value is unbounded
+    /// or if the absolute value represents more than `max_count` values.
+    pub fn get_absolute_value_candidates(&self, max_count: u64) -> Option<Vec<Bitvector>> {
+        if self.contains_top_values {
+            return None;
+        }
+        let absolute_value = match &self.absolute_value {
+            Some(value) => value,
+            None => return Some(Vec::new()),
+        };
+        let interval = absolute_value.try_to_interval().ok()?;
+        if interval.stride == 0 || interval.start == interval.end {
+            return (max_count >= 1).then(|| vec![interval.start]);
+        }
+        let count = ((interval.end.clone() - &interval.start).try_to_u64().ok()? / interval.stride)
+            .checked_add(1)?;
+        if count > max_count {
+            return None;
+        }
+        let stride = Bitvector::from_u64(interval.stride).into_resize_unsigned(interval.bytesize());
+        let mut candidates = Vec::new();
+        let mut value = interval.start;
+        for _ in 0..count {
+            let next_value = value.clone() + &stride;
+            candidates.push(value);
+            value = next_value;
+        }
+        Some(candidates)
+    }
 }
 
 impl<T: RegisterDomain + Display> DataDomain<T> {
@@ -272,6 +388,65 @@ mod tests {
         );
     }
 
+    #[test]
+    fn replace_top_with() {
+        let mut data = DataDomain::from_target(new_id("Rax"), bv(1));
+        data.replace_top_with(new_id("Rbx"), bv(2));
+        assert_eq!(data, DataDomain::from_target(new_id("Rax"), bv(1)));
+
+        data.set_contains_top_flag();
+        data.replace_top_with(new_id("Rbx"), bv(2));
+        assert!(!data.contains_top());
+        assert_eq!(data.relative_values.len(), 2);
+        assert_eq!(*data.relative_values.get(&new_id("Rbx")).unwrap(), bv(2));
+
+        data.set_contains_top_flag();
+        data.replace_top_with(new_id("Rax"), bv(3));
+        assert!(!data.contains_top());
+        assert_eq!(
+            *data.relative_values.get(&new_id("Rax")).unwrap(),
+            BitvectorDomain::new_top(ByteSize::new(8))
+        );
+    }
+
+    #[test]
+    fn is_exactly() {
+        let value = Bitvector::from_i64(42);
+        let data: DataDomain<BitvectorDomain> = bv(42).into();
+        assert!(data.is_exactly(&value));
+        assert!(!data.is_exactly(&Bitvector::from_i64(41)));
+        assert!(!data.is_exactly(&Bitvector::from_i32(42)));
+
+        let mut data_with_top = data.clone();
+        data_with_top.set_contains_top_flag();
+        assert!(!data_with_top.is_exactly(&value));
+        let mut data_with_target = data;
+        data_with_target.insert_relative_value(new_id("Rax"), bv(0));
+        assert!(!data_with_target.is_exactly(&value));
+        assert!(!DataDomain::<BitvectorDomain>::new_top(ByteSize::new(8)).is_exactly(&value));
+        assert!(!DataDomain::from(BitvectorDomain::new_top(ByteSize::new(8))).is_exactly(&value));
+    }
+
+    #[test]
+    fn may_alias() {
+        let rax_pointer = DataDomain::from_target(new_id("Rax"), bv(0));
+        let mut targets = BTreeMap::new();
+        targets.insert(new_id("Rax"), bv(8));
+        targets.insert(new_id("Rbx"), bv(0));
+        let rax_or_rbx_pointer = DataDomain::mock_from_target_map(targets);
+        let rbx_pointer = DataDomain::from_target(new_id("Rbx"), bv(0));
+        let absolute_value: DataDomain<BitvectorDomain> = bv(42).into();
+        assert!(rax_pointer.may_alias(&rax_or_rbx_pointer));
+        assert!(!rax_pointer.may_alias(&rbx_pointer));
+        assert!(!rax_pointer.may_alias(&absolute_value));
+        assert!(!absolute_value.may_alias(&absolute_value));
+
+        let mut rbx_pointer_or_top = rbx_pointer;
+        rbx_pointer_or_top.set_contains_top_flag();
+        assert!(rax_pointer.may_alias(&rbx_pointer_or_top));
+        assert!(absolute_value.may_alias(&DataDomain::new_top(ByteSize::new(8))));
+    }
+
     #[test]
     fn remove_ids() {
         let mut targets = BTreeMap::new();
@@ -295,4 +470,92 @@ mod tests {
         data.remove_ids(&ids_to_remove);
         assert_eq!(data, bv(42).into());
     }
+
+    #[test]
+    fn value_classification() {
+        let absolute: DataDomain<BitvectorDomain> = bv(42).into();
+        assert!(absolute.is_pure_absolute());
+        assert!(!absolute.is_pure_relative());
+        assert!(!absolute.is_mixed());
+
+        let mut relative = DataDomain::from_target(new_id("Rax"), bv(0));
+        assert!(!relative.is_pure_absolute());
+        assert!(relative.is_pure_relative());
+        assert!(!relative.is_mixed());
+
+        relative.set_contains_top_flag();
+        assert!(!relative.is_pure_relative());
+        assert!(relative.is_mixed());
+        let mut mixed = absolute.merge(&DataDomain::from_target(new_id("Rax"), bv(0)));
+        assert!(mixed.is_mixed());
+
+        let mut top = DataDomain::<BitvectorDomain>::new_top(ByteSize::new(8));
+        assert!(!top.is_pure_absolute());
+        assert!(!top.is_pure_relative());
+        assert!(!top.is_mixed());
+        top.set_absolute_value(Some(bv(1)));
+        assert!(!top.is_pure_absolute());
+        mixed.set_relative_values(BTreeMap::new());
+        assert!(mixed.is_pure_absolute());
+    }
+
+    #[test]
+    fn absolute_value_candidates() {
+        let data: DataDomain<BitvectorDomain> = bv(42).into();
+        assert_eq!(
+            data.get_absolute_value_candidates(10),
+            Some(vec![Bitvector::from_i64(42)])
+        );
+        let mut data: DataDomain<IntervalDomain> =
+            IntervalDomain::mock(0x1000, 0x1010).with_stride(8).into();
+        data.insert_relative_value(new_id("Rax"), IntervalDomain::mock(0, 0));
+        assert_eq!(
+            data.get_absolute_value_candidates(3),
+            Some(vec![
+                Bitvector::from_i64(0x1000),
+                Bitvector::from_i64(0x1008),
+                Bitvector::from_i64(0x1010)
+            ])
+        );
+        assert_eq!(data.get_absolute_value_candidates(2), None);
+        let data =
+            DataDomain::<IntervalDomain>::from_target(new_id("Rax"), IntervalDomain::mock(0, 0));
+        assert_eq!(data.get_absolute_value_candidates(2), Some(Vec::new()));
+        let data = DataDomain::<BitvectorDomain>::new_top(ByteSize::new(8));
+        assert_eq!(data.get_absolute_value_candidates(2), None);
+        let mut data: DataDomain<BitvectorDomain> = bv(42).into();
+        data.set_contains_top_flag();
+        assert_eq!(data.get_absolute_value_candidates(2), None);
+        // The number of values of the interval does not fit into a `u64`.
+        let data: DataDomain<IntervalDomain> = IntervalDomain::new(
+            Bitvector::from_u64(0).into_resize_unsigned(ByteSize::new(16)),
+            Bitvector::from_u64(u64::MAX).into_resize_unsigned(ByteSize::new(16)),
+        )
+        .into();
+        assert_eq!(data.get_absolute_value_candidates(u64::MAX), None);
+    }
+
+    #[test]
+    fn absolute_value_as_interval() {
+        let mut data: DataDomain<BitvectorDomain> = bv(42).into();
+        data.insert_relative_value(new_id("Rax"), bv(0));
+        assert_eq!(
+            data.get_absolute_value_as_interval(),
+            Some(IntervalDomain::mock(42, 42))
+        );
+        let data: DataDomain<BitvectorDomain> = BitvectorDomain::new_top(ByteSize::new(8)).into();
+        assert_eq!(
+            data.get_absolute_value_as_interval(),
+            Some(IntervalDomain::new_top(ByteSize::new(8)))
+        );
+        let data: DataDomain<IntervalDomain> =
+            IntervalDomain::mock(0x1000, 0x1010).with_stride(8).into();
+        assert_eq!(
+            data.get_absolute_value_as_interval(),
+            Some(IntervalDomain::mock(0x1000, 0x1010).with_stride(8))
+        );
+        let data =
+            DataDomain::<IntervalDomain>::from_target(new_id("Rax"), IntervalDomain::mock(0, 0));
+        assert_eq!(data.get_absolute_value_as_interval(), None);
+    }
 }
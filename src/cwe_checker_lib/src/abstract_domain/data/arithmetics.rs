@@ -82,6 +82,19 @@ impl<T: RegisterDomain> DataDomain<T> {
         }
     }
 
+    /// Compute the offset difference `self - other` of two pointers into the same memory object.
+    ///
+    /// If both `self` and `other` are relative to the same unique abstract ID
+    /// (without any absolute or `Top` values), the result is the absolute difference of their offsets.
+    /// Otherwise the result is `Top`.
+    /// The byte size of the result is the byte size of `self`.
+    pub fn sub_pointers(&self, other: &Self) -> Self {
+        match self.compute_sub_if_offset_through_pointer_subtraction(other) {
+            Some(difference) if difference.relative_values.is_empty() => difference,
+            _ => Self::new_top(self.bytesize()),
+        }
+    }
+
     /// Compute `self - rhs`.
     fn compute_sub(&self, rhs: &Self) -> Self {
         if self.is_empty() || rhs.is_empty() {
@@ -299,6 +312,31 @@ mod tests {
         assert_eq!(diff.contains_top_values, false);
     }
 
+    #[test]
+    fn offset_difference() {
+        let pointer1 = new_pointer("Rax", 10);
+        let pointer2 = new_pointer("Rax", 4);
+        assert_eq!(pointer1.sub_pointers(&pointer2), bv(6).into());
+        assert_eq!(pointer2.sub_pointers(&pointer1), bv(-6).into());
+
+        let other_pointer = new_pointer("Rbx", 4);
+        assert_eq!(
+            pointer1.sub_pointers(&other_pointer),
+            Data::new_top(ByteSize::new(8))
+        );
+        assert_eq!(
+            pointer1.sub_pointers(&new_value(4)),
+            Data::new_top(ByteSize::new(8))
+        );
+
+        let mut pointer_with_top = pointer2.clone();
+        pointer_with_top.set_contains_top_flag();
+        assert_eq!(
+            pointer1.sub_pointers(&pointer_with_top),
+            Data::new_top(ByteSize::new(8))
+        );
+    }
+
     #[test]
     fn data_register_domain() {
         use BinOpType::*;
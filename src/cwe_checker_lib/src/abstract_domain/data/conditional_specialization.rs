@@ -161,4 +161,30 @@ mod tests {
         let data_absolute_val = IntervalDomain::mock(100, 100).into();
         assert!(data_right.intersect(&data_absolute_val).is_err());
     }
+
+    #[test]
+    fn intersect_bitvector_values() {
+        let bv = |value: i64| BitvectorDomain::Value(Bitvector::from_i64(value));
+        let mut targets = BTreeMap::new();
+        targets.insert(new_id("Rax"), bv(0));
+        targets.insert(new_id("Rbx"), bv(8));
+        let mut data_left = DataDomain::mock_from_target_map(targets);
+        data_left.set_absolute_value(Some(bv(1)));
+        let mut targets = BTreeMap::new();
+        targets.insert(new_id("Rax"), bv(0));
+        targets.insert(new_id("Rbx"), bv(4));
+        let mut data_right = DataDomain::mock_from_target_map(targets);
+        data_right.set_absolute_value(Some(bv(1)));
+        // Only targets with equal offsets are kept.
+        let intersection = data_left.clone().intersect(&data_right).unwrap();
+        assert_eq!(
+            intersection,
+            DataDomain::from_target(new_id("Rax"), bv(0)).merge(&bv(1).into())
+        );
+        // Intersecting with a value containing `Top` elements is the identity operation.
+        let data_top = DataDomain::new_top(ByteSize::new(8));
+        assert_eq!(data_left.clone().intersect(&data_top).unwrap(), data_left);
+        // Empty intersection
+        assert!(data_left.intersect(&bv(2).into()).is_err());
+    }
 }
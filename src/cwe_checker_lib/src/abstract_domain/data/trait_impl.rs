@@ -28,18 +28,19 @@ impl<T: RegisterDomain> HasTop for DataDomain<T> {
     }
 }
 
-impl<T: RegisterDomain> AbstractDomain for DataDomain<T> {
-    // Merge `self` with `other`.
-    fn merge(&self, other: &Self) -> Self {
+impl<T: RegisterDomain> DataDomain<T> {
+    /// Combine `self` with `other` by combining the offsets of common relative values
+    /// and the absolute values with the given function.
+    fn combine_with(&self, other: &Self, combine: impl Fn(&T, &T) -> T) -> Self {
         let mut relative_values = self.relative_values.clone();
         for (id, offset_other) in other.relative_values.iter() {
             relative_values
                 .entry(id.clone())
-                .and_modify(|offset| *offset = offset.merge(offset_other))
+                .and_modify(|offset| *offset = combine(offset, offset_other))
                 .or_insert_with(|| offset_other.clone());
         }
         let absolute_value = match (&self.absolute_value, &other.absolute_value) {
-            (Some(left), Some(right)) => Some(left.merge(right)),
+            (Some(left), Some(right)) => Some(combine(left, right)),
             (Some(val), None) | (None, Some(val)) => Some(val.clone()),
             (None, None) => None,
         };
@@ -50,6 +51,18 @@ impl<T: RegisterDomain> AbstractDomain for DataDomain<T> {
             contains_top_values: self.contains_top_values || other.contains_top_values,
         }
     }
+}
+
+impl<T: RegisterDomain> AbstractDomain for DataDomain<T> {
+    // Merge `self` with `other`.
+    fn merge(&self, other: &Self) -> Self {
+        self.combine_with(other, T::merge)
+    }
+
+    /// Widen `self` with `other` by widening the offsets of common relative values and the absolute values.
+    fn widen(&self, other: &Self) -> Self {
+        self.combine_with(other, T::widen)
+    }
 
     /// Return whether the element represents a top element or not.
     ///
@@ -165,4 +178,28 @@ mod tests {
             Some(&bv(10))
         );
     }
+
+    #[test]
+    fn data_widen() {
+        let mut value: DataDomain<IntervalDomain> = IntervalDomain::mock(0, 3).into();
+        value.set_relative_values(BTreeMap::from_iter([(
+            new_id("RAX"),
+            IntervalDomain::mock(0, 0),
+        )]));
+        let mut other_value: DataDomain<IntervalDomain> = IntervalDomain::mock(0, 4).into();
+        other_value.set_relative_values(BTreeMap::from_iter([(
+            new_id("RAX"),
+            IntervalDomain::mock(-8, 0),
+        )]));
+        let widened = value.widen(&other_value);
+        assert_eq!(
+            widened.get_absolute_value(),
+            Some(&IntervalDomain::mock(0, 3).widen(&IntervalDomain::mock(0, 4)))
+        );
+        assert_eq!(
+            widened.get_relative_values().get(&new_id("RAX")),
+            Some(&IntervalDomain::mock(0, 0).widen(&IntervalDomain::mock(-8, 0)))
+        );
+        assert_eq!(value.widen(&value), value);
+    }
 }
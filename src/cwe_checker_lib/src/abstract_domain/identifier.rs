@@ -26,6 +26,10 @@ use std::sync::Arc;
 /// The time identifier is given by a `Tid`.
 /// If it is the `Tid` of a basic block, then it describes the point in time *before* execution of the first instruction in the block.
 /// If it is the `Tid` of a `Def` or `Jmp`, then it describes the point in time *after* the execution of the `Def` or `Jmp`.
+///
+/// Return value identifiers (see [`AbstractIdentifier::new_return_value`]) represent the value
+/// that a call returned in a register.
+/// They are distinct from the identifier of the value of the same register after the call.
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Hash, Clone, PartialOrd, Ord, Deref)]
 #[deref(forward)]
 pub struct AbstractIdentifier(Arc<AbstractIdentifierData>);
@@ -35,12 +39,20 @@ pub struct AbstractIdentifier(Arc<AbstractIdentifierData>);
 pub struct AbstractIdentifierData {
     time: Tid,
     location: AbstractLocation,
+    /// Is `true` if the identifier represents the value returned by the call at `time`
+    /// in the register given by `location`.
+    #[serde(default)]
+    is_return_value: bool,
 }
 
 impl AbstractIdentifier {
     /// Create a new abstract identifier.
     pub fn new(time: Tid, location: AbstractLocation) -> AbstractIdentifier {
-        AbstractIdentifier(Arc::new(AbstractIdentifierData { time, location }))
+        AbstractIdentifier(Arc::new(AbstractIdentifierData {
+            time,
+            location,
+            is_return_value: false,
+        }))
     }
 
     /// Create a new abstract identifier where the abstract location is a register.
@@ -49,9 +61,27 @@ impl AbstractIdentifier {
         AbstractIdentifier(Arc::new(AbstractIdentifierData {
             time,
             location: AbstractLocation::from_var(variable).unwrap(),
+            is_return_value: false,
+        }))
+    }
+
+    /// Create a new abstract identifier representing the value
+    /// that the call with the given TID returned in the given register.
+    /// Panics if the register is a temporary register.
+    pub fn new_return_value(call_tid: Tid, return_register: &Variable) -> AbstractIdentifier {
+        AbstractIdentifier(Arc::new(AbstractIdentifierData {
+            time: call_tid,
+            location: AbstractLocation::from_var(return_register).unwrap(),
+            is_return_value: true,
         }))
     }
 
+    /// Returns `true` if the identifier represents the return value of a call.
+    /// See [`AbstractIdentifier::new_return_value`].
+    pub fn is_return_value(&self) -> bool {
+        self.is_return_value
+    }
+
     /// Get the register associated to the abstract location.
     /// Panics if the abstract location is a memory location and not a register.
     pub fn unwrap_register(&self) -> &Variable {
@@ -74,7 +104,11 @@ impl AbstractIdentifier {
 
 impl std::fmt::Display for AbstractIdentifier {
     fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(formatter, "{} @ {}", self.0.time, self.0.location)
+        if self.0.is_return_value {
+            write!(formatter, "{} @ {} (return)", self.0.time, self.0.location)
+        } else {
+            write!(formatter, "{} @ {}", self.0.time, self.0.location)
+        }
     }
 }
 
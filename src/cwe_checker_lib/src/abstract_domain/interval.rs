@@ -267,6 +267,52 @@ impl IntervalDomain {
             && max.checked_sge(&self.interval.end).unwrap()
     }
 
+    /// Narrow the interval to values (signed) less than or equal to `max`.
+    ///
+    /// In contrast to [`SpecializeByConditional::add_signed_less_equal_bound`]
+    /// the bound is always applied to the interval itself.
+    /// Thus clamping an unbounded interval (e.g. `Top`) yields the half-bounded interval `[signed_min, max]`.
+    /// If `max` is greater than the interval end, it is also used as the new upper widening hint.
+    /// Returns an error if no value of the interval is less than or equal to `max`.
+    pub fn clamp_upper(&self, max: &Bitvector) -> Result<IntervalDomain, Error> {
+        let bound = IntervalDomain::new(
+            Bitvector::signed_min_value(self.bytesize().into()),
+            max.clone(),
+        );
+        let mut clamped = self.clone().intersect(&bound)?;
+        // Widening hints beyond the new bound are replaced by the bound itself.
+        if let Some(hint) = &clamped.widening_upper_bound {
+            if hint.checked_sgt(max).unwrap() {
+                clamped.widening_upper_bound = None;
+            }
+        }
+        clamped.update_widening_upper_bound(&Some(max.clone()));
+        Ok(clamped)
+    }
+
+    /// Narrow the interval to values (signed) greater than or equal to `min`.
+    ///
+    /// In contrast to [`SpecializeByConditional::add_signed_greater_equal_bound`]
+    /// the bound is always applied to the interval itself.
+    /// Thus clamping an unbounded interval (e.g. `Top`) yields the half-bounded interval `[min, signed_max]`.
+    /// If `min` is less than the interval start, it is also used as the new lower widening hint.
+    /// Returns an error if no value of the interval is greater than or equal to `min`.
+    pub fn clamp_lower(&self, min: &Bitvector) -> Result<IntervalDomain, Error> {
+        let bound = IntervalDomain::new(
+            min.clone(),
+            Bitvector::signed_max_value(self.bytesize().into()),
+        );
+        let mut clamped = self.clone().intersect(&bound)?;
+        // Widening hints beyond the new bound are replaced by the bound itself.
+        if let Some(hint) = &clamped.widening_lower_bound {
+            if hint.checked_slt(min).unwrap() {
+                clamped.widening_lower_bound = None;
+            }
+        }
+        clamped.update_widening_lower_bound(&Some(min.clone()));
+        Ok(clamped)
+    }
+
     /// Truncate the bitvectors in the interval domain
     /// by removing the least significant bytes lower than the `low_byte` from them.
     ///
@@ -512,6 +558,44 @@ impl AbstractDomain for IntervalDomain {
         self.signed_merge_and_widen(other)
     }
 
+    /// Merge `self` with `other` and move every bound of `self` that grew
+    /// to the signed minimum or maximum value respectively.
+    /// Widening hints are ignored, since the widening should stabilize immediately.
+    fn widen(&self, other: &IntervalDomain) -> IntervalDomain {
+        let mut widened_domain = self.signed_merge(other);
+        let bytesize = self.bytesize();
+        let mut has_been_widened = false;
+        if widened_domain
+            .interval
+            .start
+            .checked_slt(&self.interval.start)
+            .unwrap()
+        {
+            widened_domain.interval.start = Bitvector::signed_min_value(bytesize.into());
+            widened_domain.interval.adjust_start_to_value_in_stride();
+            widened_domain.widening_lower_bound = None;
+            has_been_widened = true;
+        }
+        if widened_domain
+            .interval
+            .end
+            .checked_sgt(&self.interval.end)
+            .unwrap()
+        {
+            widened_domain.interval.end = Bitvector::signed_max_value(bytesize.into());
+            widened_domain.interval.adjust_end_to_value_in_stride();
+            widened_domain.widening_upper_bound = None;
+            has_been_widened = true;
+        }
+        if has_been_widened {
+            widened_domain.widening_delay = (widened_domain.interval.end.clone()
+                - &widened_domain.interval.start)
+                .try_to_u64()
+                .unwrap_or(0);
+        }
+        widened_domain
+    }
+
     /// Return `true` if the interval spans all possible values.
     fn is_top(&self) -> bool {
         self.interval.is_top()
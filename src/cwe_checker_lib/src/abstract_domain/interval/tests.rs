@@ -129,6 +129,31 @@ fn signed_merge() {
     assert_eq!(var, expected_result);
 }
 
+#[test]
+fn widen() {
+    let a = IntervalDomain::mock(0, 3);
+    let b = IntervalDomain::mock(0, 4);
+    let widened = a.widen(&b);
+    assert_eq!(widened.interval.start, Bitvector::from_i64(0));
+    assert_eq!(widened.interval.end, Bitvector::from_i64(i64::MAX));
+    let widened = b.widen(&IntervalDomain::mock(-1, 2));
+    assert_eq!(widened.interval.start, Bitvector::from_i64(i64::MIN));
+    assert_eq!(widened.interval.end, Bitvector::from_i64(4));
+    // Widening hints are ignored
+    let a = IntervalDomain::mock_with_bounds(Some(-10), 0, 3, Some(10));
+    let widened = a.widen(&IntervalDomain::mock(-1, 4));
+    assert!(widened.is_top());
+    // Do not widen if the bounds did not grow.
+    assert_eq!(b.widen(&a).interval, IntervalDomain::mock(0, 4).interval);
+    // Keep the stride when widening
+    let a = IntervalDomain::new(Bitvector::from_i8(0), Bitvector::from_i8(8)).with_stride(4);
+    let b = IntervalDomain::new(Bitvector::from_i8(4), Bitvector::from_i8(12)).with_stride(4);
+    let widened = a.widen(&b);
+    assert_eq!(widened.interval.start, Bitvector::from_i8(0));
+    assert_eq!(widened.interval.end, Bitvector::from_i8(124));
+    assert_eq!(widened.interval.stride, 4);
+}
+
 #[test]
 fn cast_zero_and_signed_extend() {
     // Zero extend
@@ -604,6 +629,41 @@ fn intersection() {
     assert!(interval1.intersect(&IntervalDomain::mock(50, 55)).is_err());
 }
 
+#[test]
+fn clamp_bounds() {
+    let top = IntervalDomain::new_top(ByteSize::new(8));
+    assert_eq!(
+        top.clamp_upper(&Bitvector::from_i64(10)).unwrap(),
+        IntervalDomain::mock(i64::MIN, 10)
+    );
+    assert_eq!(
+        top.clamp_lower(&Bitvector::from_i64(0)).unwrap(),
+        IntervalDomain::mock(0, i64::MAX)
+    );
+    let clamped = top
+        .clamp_lower(&Bitvector::from_i64(0))
+        .unwrap()
+        .clamp_upper(&Bitvector::from_i64(255))
+        .unwrap();
+    assert_eq!(clamped, IntervalDomain::mock(0, 255));
+
+    let interval = IntervalDomain::mock_with_bounds(Some(-100), -10, 10, Some(100));
+    assert_eq!(
+        interval.clamp_upper(&Bitvector::from_i64(50)).unwrap(),
+        IntervalDomain::mock_with_bounds(Some(-100), -10, 10, Some(50))
+    );
+    assert_eq!(
+        interval.clamp_upper(&Bitvector::from_i64(5)).unwrap(),
+        IntervalDomain::mock_with_bounds(Some(-100), -10, 5, None)
+    );
+    assert_eq!(
+        interval.clamp_lower(&Bitvector::from_i64(-5)).unwrap(),
+        IntervalDomain::mock_with_bounds(None, -5, 10, Some(100))
+    );
+    assert!(interval.clamp_upper(&Bitvector::from_i64(-11)).is_err());
+    assert!(interval.clamp_lower(&Bitvector::from_i64(11)).is_err());
+}
+
 #[test]
 fn fits_into_size() {
     let interval = IntervalDomain::mock_with_bounds(Some(-300), -10, 10, Some(100));
@@ -49,7 +49,18 @@ impl<T: AbstractDomain + SizedDomain + HasTop + std::fmt::Debug> AbstractDomain
         if self == other {
             self.clone()
         } else {
-            self.merge_inner(other)
+            self.merge_inner(other, T::merge)
+        }
+    }
+
+    /// Widen two memory regions.
+    /// Works like [`merge`](Self::merge), except that values at the same position and with the same size
+    /// get widened via their widening function.
+    fn widen(&self, other: &Self) -> Self {
+        if self == other {
+            self.clone()
+        } else {
+            self.merge_inner(other, T::widen)
         }
     }
 
@@ -261,12 +272,13 @@ impl<T: AbstractDomain + SizedDomain + HasTop + std::fmt::Debug> MemRegion<T> {
 
     /// Merge two memory regions.
     ///
-    /// Values at the same position and with the same size get merged via their merge function.
+    /// Values at the same position and with the same size get combined via the given `combine` function,
+    /// i.e. the merge or the widening function of the values.
     /// Values intersecting other values but with not exactly matching position or size are not added to the merged region.
     /// Values that do not intersect a value from the other `MemRegion`
     /// are merged with `Top` before adding them.
     /// This can only add elements to the merged domain if the `Top` value is not a maximal element of the abstract domain.
-    fn merge_inner(&self, other: &MemRegion<T>) -> MemRegion<T> {
+    fn merge_inner(&self, other: &MemRegion<T>, combine: fn(&T, &T) -> T) -> MemRegion<T> {
         assert_eq!(self.inner.address_bytesize, other.inner.address_bytesize);
 
         let mut zipped: BTreeMap<i64, (Option<&T>, Option<&T>)> = BTreeMap::new();
@@ -292,11 +304,11 @@ impl<T: AbstractDomain + SizedDomain + HasTop + std::fmt::Debug> MemRegion<T> {
                 if let Some((next_index, _)) = zipped.range((index + 1)..).next() {
                     if *next_index >= elem_range_end {
                         // The element does not overlap a subsequent element
-                        if let Some(merged) = merge_or_merge_with_top(*left, *right) {
+                        if let Some(merged) = merge_or_merge_with_top(*left, *right, combine) {
                             merged_values.insert(*index, merged);
                         }
                     }
-                } else if let Some(merged) = merge_or_merge_with_top(*left, *right) {
+                } else if let Some(merged) = merge_or_merge_with_top(*left, *right, combine) {
                     merged_values.insert(*index, merged);
                 }
             }
@@ -340,7 +352,7 @@ impl<T: AbstractDomain + SizedDomain + HasTop + std::fmt::Debug> MemRegion<T> {
     }
 }
 
-/// Helper function that either merges `left` and `right`
+/// Helper function that either combines `left` and `right` with the given `combine` function
 /// or, if one of them is `None`, merges the other with a `Top` value.
 /// Furthermore, if `left` and `right` have different byte sizes
 /// or the merge operation returns a `Top` value, then `None` is returned.
@@ -348,11 +360,12 @@ impl<T: AbstractDomain + SizedDomain + HasTop + std::fmt::Debug> MemRegion<T> {
 fn merge_or_merge_with_top<T: AbstractDomain + SizedDomain>(
     left: Option<&T>,
     right: Option<&T>,
+    combine: fn(&T, &T) -> T,
 ) -> Option<T> {
     match (left, right) {
         (Some(elem_left), Some(elem_right)) => {
             if elem_left.bytesize() == elem_right.bytesize() {
-                let merged = elem_left.merge(elem_right);
+                let merged = combine(elem_left, elem_right);
                 if !merged.is_top() {
                     return Some(merged);
                 }
@@ -40,6 +40,19 @@ pub trait AbstractDomain: Sized + Eq + Clone {
     #[must_use]
     fn merge(&self, other: &Self) -> Self;
 
+    /// Return an upper bound for the two inputs `self` and `other`
+    /// such that repeated widening of growing values is guaranteed to stabilize after a few iterations.
+    ///
+    /// Fixpoint computations may use widening instead of merging for nodes
+    /// whose values did not stabilize after a certain number of iterations.
+    /// Here `self` is the old value and `other` the new value.
+    /// The default implementation just merges the two values,
+    /// which is sufficient for domains without infinite ascending chains (like the [`BitvectorDomain`]).
+    #[must_use]
+    fn widen(&self, other: &Self) -> Self {
+        self.merge(other)
+    }
+
     /// Returns whether the element represents the top element (i.e. maximal with respect to the partial order) or not.
     /// If a domain has no maximal element, this function should always return false.
     fn is_top(&self) -> bool;
@@ -153,6 +153,7 @@ fn backward_fixpoint() {
         calling_conventions: BTreeMap::new(),
         register_set: BTreeSet::new(),
         datatype_properties: DatatypeProperties::mock(),
+        format_string_functions: FormatStringFunctions::default(),
     };
 
     let mock_con = Context::new(&project);
@@ -58,6 +58,13 @@ pub trait Context {
     /// This function describes how to merge two values
     fn merge(&self, val1: &Self::NodeValue, val2: &Self::NodeValue) -> Self::NodeValue;
 
+    /// This function describes how to widen the old value `val1` of a node with a new value `val2`.
+    /// It is used instead of `merge` for nodes whose value changed more often than the widening threshold of the computation.
+    /// The default implementation just merges the two values.
+    fn widen(&self, val1: &Self::NodeValue, val2: &Self::NodeValue) -> Self::NodeValue {
+        self.merge(val1, val2)
+    }
+
     /// This function describes how the value at the end node of an edge is computed from the value at the start node of the edge.
     /// The function can return None to indicate that no end value gets generated through this edge.
     /// E.g. In a control flow graph, if the edge cannot be taken for the given start value, this function should return None.
@@ -94,6 +101,11 @@ pub struct Computation<T: Context> {
     worklist: BTreeSet<usize>,
     /// The internal map containing all known node values.
     node_values: FnvHashMap<NodeIndex, T::NodeValue>,
+    /// Number of value changes of a node after which `widen` is used instead of `merge` for the node.
+    /// If set to `None`, then `widen` is never used.
+    widening_threshold: Option<u64>,
+    /// Counts how often the value of each node was changed by merging it with a new value.
+    node_update_counts: FnvHashMap<NodeIndex, u64>,
 }
 
 impl<T: Context> Computation<T> {
@@ -138,6 +150,8 @@ impl<T: Context> Computation<T> {
             priority_to_node_list: priority_sorted_nodes,
             worklist,
             node_values,
+            widening_threshold: None,
+            node_update_counts: FnvHashMap::default(),
         }
     }
 
@@ -178,11 +192,35 @@ impl<T: Context> Computation<T> {
         self.worklist.insert(self.node_priority_list[node.index()]);
     }
 
+    /// Set the number of value changes of a node after which its values get widened instead of merged.
+    ///
+    /// Widening guarantees that the values of nodes in loops with monotonically growing values stabilize quickly.
+    /// Note that the widening operation is usually less exact than the merge operation.
+    pub fn set_widening_threshold(&mut self, threshold: u64) {
+        self.widening_threshold = Some(threshold);
+    }
+
+    /// Set the value of a node without marking the node as not yet stabilized.
+    ///
+    /// Can be used to restore node values of a previous fixpoint computation
+    /// that are known to be unaffected by changes to the fixpoint problem.
+    pub fn restore_stabilized_node_value(&mut self, node: NodeIndex, value: T::NodeValue) {
+        self.node_values.insert(node, value);
+    }
+
     /// Merge the value at a node with some new value.
+    /// If the value of the node changed more often than the widening threshold, the values get widened instead.
     fn merge_node_value(&mut self, node: NodeIndex, value: T::NodeValue) {
         if let Some(old_value) = self.node_values.get(&node) {
-            let merged_value = self.fp_context.merge(&value, old_value);
+            let update_count = self.node_update_counts.entry(node).or_insert(0);
+            let merged_value = match self.widening_threshold {
+                Some(threshold) if *update_count >= threshold => {
+                    self.fp_context.widen(old_value, &value)
+                }
+                _ => self.fp_context.merge(&value, old_value),
+            };
             if merged_value != *old_value {
+                *update_count += 1;
                 self.set_node_value(node, merged_value);
             }
         } else {
@@ -332,6 +370,60 @@ mod tests {
         assert_eq!(0, *solution.get_node_value(NodeIndex::new(5)).unwrap());
     }
 
+    struct WideningFPContext {
+        graph: DiGraph<(), u64>,
+    }
+
+    impl Context for WideningFPContext {
+        type EdgeLabel = u64;
+        type NodeLabel = ();
+        type NodeValue = u64;
+
+        fn get_graph(&self) -> &DiGraph<(), u64> {
+            &self.graph
+        }
+
+        fn merge(&self, val1: &Self::NodeValue, val2: &Self::NodeValue) -> Self::NodeValue {
+            std::cmp::max(*val1, *val2)
+        }
+
+        fn widen(&self, val1: &Self::NodeValue, val2: &Self::NodeValue) -> Self::NodeValue {
+            if val2 > val1 {
+                u64::MAX
+            } else {
+                *val1
+            }
+        }
+
+        fn update_edge(&self, value: &Self::NodeValue, edge: EdgeIndex) -> Option<Self::NodeValue> {
+            Some(value.saturating_add(*self.graph.edge_weight(edge).unwrap()))
+        }
+    }
+
+    #[test]
+    fn fixpoint_with_widening() {
+        let mut graph: DiGraph<(), u64> = DiGraph::new();
+        graph.add_node(());
+        graph.add_node(());
+        graph.add_edge(NodeIndex::new(0), NodeIndex::new(1), 1);
+        graph.add_edge(NodeIndex::new(1), NodeIndex::new(0), 1);
+
+        let mut solution = Computation::new(WideningFPContext { graph }, None);
+        solution.set_node_value(NodeIndex::new(0), 0);
+        solution.set_widening_threshold(3);
+        solution.compute_with_max_steps(10);
+
+        assert!(solution.has_stabilized());
+        assert_eq!(
+            u64::MAX,
+            *solution.get_node_value(NodeIndex::new(0)).unwrap()
+        );
+        assert_eq!(
+            u64::MAX,
+            *solution.get_node_value(NodeIndex::new(1)).unwrap()
+        );
+    }
+
     #[test]
     fn fixpoint_with_default_value() {
         let mut graph: DiGraph<(), u64> = DiGraph::new();
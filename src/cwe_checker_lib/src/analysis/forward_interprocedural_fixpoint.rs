@@ -36,6 +36,13 @@ pub trait Context<'a> {
     /// Merge two node values.
     fn merge(&self, value1: &Self::Value, value2: &Self::Value) -> Self::Value;
 
+    /// Widen the old node value `value1` with the new node value `value2`.
+    /// Only used by computations with a widening threshold.
+    /// The default implementation just merges the two values.
+    fn widen(&self, value1: &Self::Value, value2: &Self::Value) -> Self::Value {
+        self.merge(value1, value2)
+    }
+
     /// Transition function for `Def` terms.
     /// The transition function for a basic block is computed
     /// by iteratively applying this function to the starting value for each `Def` term in the basic block.
@@ -105,23 +112,17 @@ impl<'a, T: Context<'a>> GeneralizedContext<'a, T> {
     pub fn get_context(&self) -> &T {
         &self.context
     }
-}
 
-impl<'a, T: Context<'a>> GeneralFPContext for GeneralizedContext<'a, T> {
-    type EdgeLabel = Edge<'a>;
-    type NodeLabel = Node<'a>;
-    type NodeValue = NodeValue<T::Value>;
-
-    /// Get a reference to the underlying graph.
-    fn get_graph(&self) -> &Graph<'a> {
-        self.context.get_graph()
-    }
-
-    /// Merge two values using the merge function from the interprocedural context object.
-    fn merge(&self, val1: &Self::NodeValue, val2: &Self::NodeValue) -> Self::NodeValue {
+    /// Combine two node values using the given function for combining the inner values.
+    fn combine_node_values(
+        &self,
+        val1: &NodeValue<T::Value>,
+        val2: &NodeValue<T::Value>,
+        combine: impl Fn(&T::Value, &T::Value) -> T::Value,
+    ) -> NodeValue<T::Value> {
         use NodeValue::*;
         match (val1, val2) {
-            (Value(value1), Value(value2)) => Value(self.context.merge(value1, value2)),
+            (Value(value1), Value(value2)) => Value(combine(value1, value2)),
             (
                 CallFlowCombinator {
                     call_stub: call1,
@@ -132,14 +133,33 @@ impl<'a, T: Context<'a>> GeneralFPContext for GeneralizedContext<'a, T> {
                     interprocedural_flow: return2,
                 },
             ) => CallFlowCombinator {
-                call_stub: merge_option(call1, call2, |v1, v2| self.context.merge(v1, v2)),
-                interprocedural_flow: merge_option(return1, return2, |v1, v2| {
-                    self.context.merge(v1, v2)
-                }),
+                call_stub: merge_option(call1, call2, &combine),
+                interprocedural_flow: merge_option(return1, return2, &combine),
             },
             _ => panic!("Malformed CFG in fixpoint computation"),
         }
     }
+}
+
+impl<'a, T: Context<'a>> GeneralFPContext for GeneralizedContext<'a, T> {
+    type EdgeLabel = Edge<'a>;
+    type NodeLabel = Node<'a>;
+    type NodeValue = NodeValue<T::Value>;
+
+    /// Get a reference to the underlying graph.
+    fn get_graph(&self) -> &Graph<'a> {
+        self.context.get_graph()
+    }
+
+    /// Merge two values using the merge function from the interprocedural context object.
+    fn merge(&self, val1: &Self::NodeValue, val2: &Self::NodeValue) -> Self::NodeValue {
+        self.combine_node_values(val1, val2, |v1, v2| self.context.merge(v1, v2))
+    }
+
+    /// Widen two values using the widen function from the interprocedural context object.
+    fn widen(&self, val1: &Self::NodeValue, val2: &Self::NodeValue) -> Self::NodeValue {
+        self.combine_node_values(val1, val2, |v1, v2| self.context.widen(v1, v2))
+    }
 
     /// Forward edge transition function.
     /// Applies the transition functions from the interprocedural context object
@@ -11,6 +11,25 @@ pub struct AccessPattern {
     read: bool,
     /// The variable was used in the computation of a pointer that was dereferenced for writing a value.
     mutably_dereferenced: bool,
+    /// The maximal size in bytes with which the value of the variable was accessed.
+    max_access_size: Option<ByteSize>,
+    /// The minimal number of calls through which a recorded access was propagated from a callee.
+    /// Is `Some(0)` if the variable was accessed directly and `None` if no access was recorded.
+    #[serde(default)]
+    propagation_depth: Option<usize>,
+    /// The variable was accessed on all paths to the current location
+    /// and not only on some of them.
+    #[serde(default)]
+    accessed_on_all_paths: bool,
+    /// The value of the variable was accessed with different sizes.
+    #[serde(default)]
+    mixed_access_sizes: bool,
+    /// The variable was used as an operand of a comparison, e.g. in a check for `NULL`.
+    #[serde(default)]
+    compared: bool,
+    /// The variable was accessed in some other way than as an operand of a comparison.
+    #[serde(default)]
+    accessed_outside_comparisons: bool,
 }
 
 impl AccessPattern {
@@ -20,22 +39,118 @@ impl AccessPattern {
             dereferenced: false,
             read: false,
             mutably_dereferenced: false,
+            max_access_size: None,
+            propagation_depth: None,
+            accessed_on_all_paths: false,
+            mixed_access_sizes: false,
+            compared: false,
+            accessed_outside_comparisons: false,
         }
     }
 
+    /// Generate a new `AccessPattern` object with all access flags set
+    /// for an access that was propagated through the given number of calls.
+    /// Since the access may not have happened, it is not marked as an access on all paths.
+    pub fn new_unknown_access(propagation_depth: usize) -> Self {
+        Self {
+            dereferenced: true,
+            read: true,
+            mutably_dereferenced: true,
+            max_access_size: None,
+            propagation_depth: Some(propagation_depth),
+            accessed_on_all_paths: false,
+            mixed_access_sizes: false,
+            compared: false,
+            accessed_outside_comparisons: true,
+        }
+    }
+
+    /// Record a direct access to the variable on the current path.
+    fn record_direct_access(&mut self) {
+        self.propagation_depth = Some(0);
+        self.accessed_on_all_paths = true;
+    }
+
     /// Set the access flag for immutable pointer dereference.
     pub fn set_dereference_flag(&mut self) {
         self.dereferenced = true;
+        self.accessed_outside_comparisons = true;
+        self.record_direct_access();
     }
 
     /// Set the access flag for read access.
     pub fn set_read_flag(&mut self) {
         self.read = true;
+        self.accessed_outside_comparisons = true;
+        self.record_direct_access();
+    }
+
+    /// Set the access flag for read access
+    /// without changing whether the variable counts as accessed on all paths
+    /// (see [`is_accessed_on_all_paths`](Self::is_accessed_on_all_paths)).
+    pub fn set_read_flag_on_unknown_paths(&mut self) {
+        let accessed_on_all_paths = self.accessed_on_all_paths;
+        self.set_read_flag();
+        self.accessed_on_all_paths = accessed_on_all_paths;
+    }
+
+    /// Set the access flag for read access as an operand of a comparison.
+    /// In contrast to [`set_read_flag`](Self::set_read_flag) the variable may still be classified as only compared
+    /// (see [`is_compared_only`](Self::is_compared_only)).
+    pub fn set_compared_flag(&mut self) {
+        self.read = true;
+        self.compared = true;
+        self.record_direct_access();
     }
 
     /// Set the access flag for pointer dereference (with write access to the target of the pointer).
     pub fn set_mutably_dereferenced_flag(&mut self) {
         self.mutably_dereferenced = true;
+        self.accessed_outside_comparisons = true;
+        self.record_direct_access();
+    }
+
+    /// Record an access to the variable with the given size.
+    /// Only the maximal access size over all recorded accesses is kept.
+    pub fn add_access_size(&mut self, size: ByteSize) {
+        if matches!(self.max_access_size, Some(max_size) if max_size != size) {
+            self.mixed_access_sizes = true;
+        }
+        self.max_access_size = std::cmp::max(self.max_access_size, Some(size));
+    }
+
+    /// Returns true if all recorded accesses to the variable have the same size.
+    pub fn has_consistent_access_sizes(&self) -> bool {
+        !self.mixed_access_sizes
+    }
+
+    /// Returns true if the variable was accessed on all paths to the current location.
+    pub fn is_accessed_on_all_paths(&self) -> bool {
+        self.accessed_on_all_paths
+    }
+
+    /// Merge `self` with the access pattern of accesses
+    /// that happen on the same paths as the accesses recorded in `self`,
+    /// e.g. the parameter accesses of a callee at a call site.
+    ///
+    /// In contrast to [`merge`](AbstractDomain::merge), which merges the access patterns of different paths,
+    /// the variable is accessed on all paths if it is accessed on all paths in at least one of the input objects.
+    pub fn merge_on_same_paths(&self, other: &Self) -> Self {
+        let mut merged = self.merge(other);
+        merged.accessed_on_all_paths = self.accessed_on_all_paths || other.accessed_on_all_paths;
+        merged
+    }
+
+    /// Mark the variable as not accessed on all paths,
+    /// e.g. because the variable is not known on some paths.
+    pub fn unset_accessed_on_all_paths_flag(&mut self) {
+        self.accessed_on_all_paths = false;
+    }
+
+    /// Get the maximal size with which the value of the variable was accessed.
+    /// Returns `None` if no access with a known size was recorded.
+    pub fn get_max_access_size(&self) -> Option<ByteSize> {
+        self.max_access_size
     }
 
     /// Set all access flags to indicate that any kind of access to the variable may have occured.
@@ -43,6 +158,23 @@ impl AccessPattern {
         self.read = true;
         self.dereferenced = true;
         self.mutably_dereferenced = true;
+        self.accessed_outside_comparisons = true;
+        self.record_direct_access();
+    }
+
+    /// Get the minimal number of calls through which a recorded access was propagated from a callee.
+    /// Returns `Some(0)` if the variable was accessed directly and `None` if no access was recorded.
+    pub fn get_propagation_depth(&self) -> Option<usize> {
+        self.propagation_depth
+    }
+
+    /// Return the access pattern resulting from propagating `self` from a callee to its caller,
+    /// i.e. with the propagation depth increased by one.
+    pub fn propagated_through_call(&self) -> Self {
+        AccessPattern {
+            propagation_depth: self.propagation_depth.map(|depth| depth + 1),
+            ..*self
+        }
     }
 
     /// Returns true if any of the access flags is set.
@@ -50,15 +182,64 @@ impl AccessPattern {
         self.read || self.dereferenced || self.mutably_dereferenced
     }
 
+    /// Returns true if the read access flag is set.
+    pub fn is_read(&self) -> bool {
+        self.read
+    }
+
     /// Returns true if the dereferenced or mutably dereferenced access flag is set.
     pub fn is_dereferenced(&self) -> bool {
         self.dereferenced || self.mutably_dereferenced
     }
 
+    /// Returns true if the variable was only used as an operand of comparisons,
+    /// e.g. a pointer parameter that is only checked for being `NULL`.
+    pub fn is_compared_only(&self) -> bool {
+        self.compared && !self.accessed_outside_comparisons
+    }
+
     /// Returns true if the mutably dereferenced access flag is set.
     pub fn is_mutably_dereferenced(&self) -> bool {
         self.mutably_dereferenced
     }
+
+    /// Return how the target of the variable was accessed if the variable is interpreted as a pointer.
+    ///
+    /// If the pointer was used both for reading and for writing,
+    /// then [`PointerAccess::Mutated`] is returned.
+    pub fn get_pointer_access(&self) -> PointerAccess {
+        if self.mutably_dereferenced {
+            PointerAccess::Mutated
+        } else if self.dereferenced {
+            PointerAccess::ReadOnly
+        } else if self.is_compared_only() {
+            PointerAccess::ComparedOnly
+        } else {
+            PointerAccess::NotDereferenced
+        }
+    }
+
+    /// Generate a JSON-representation of the access flags.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "read": self.read,
+            "dereferenced": self.dereferenced,
+            "mutably_dereferenced": self.mutably_dereferenced,
+        })
+    }
+}
+
+/// Describes how the target of a pointer was accessed through the pointer.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub enum PointerAccess {
+    /// The pointer was only used in comparisons, e.g. in a check for `NULL`.
+    ComparedOnly,
+    /// The pointer was never dereferenced.
+    NotDereferenced,
+    /// The pointer was only dereferenced for reading values.
+    ReadOnly,
+    /// The pointer was dereferenced for writing values (and possibly for reading values).
+    Mutated,
 }
 
 impl Default for AccessPattern {
@@ -70,11 +251,30 @@ impl Default for AccessPattern {
 impl AbstractDomain for AccessPattern {
     /// An access flag in the merged `AccessPattern` object is set
     /// if it is set in at least one of the input objects.
+    /// The maximal access size of the merged object is the maximum of the access sizes of the input objects
+    /// and the propagation depth is the minimum of the propagation depths of accesses recorded in the input objects.
+    /// The input objects are assumed to represent different paths,
+    /// so that the variable is only accessed on all paths if this holds for both input objects.
     fn merge(&self, other: &Self) -> Self {
         AccessPattern {
             dereferenced: self.dereferenced || other.dereferenced,
             read: self.read || other.read,
             mutably_dereferenced: self.mutably_dereferenced || other.mutably_dereferenced,
+            max_access_size: std::cmp::max(self.max_access_size, other.max_access_size),
+            propagation_depth: match (self.propagation_depth, other.propagation_depth) {
+                (Some(left), Some(right)) => Some(std::cmp::min(left, right)),
+                (left, right) => left.or(right),
+            },
+            accessed_on_all_paths: self.accessed_on_all_paths && other.accessed_on_all_paths,
+            mixed_access_sizes: self.mixed_access_sizes
+                || other.mixed_access_sizes
+                || matches!(
+                    (self.max_access_size, other.max_access_size),
+                    (Some(left), Some(right)) if left != right
+                ),
+            compared: self.compared || other.compared,
+            accessed_outside_comparisons: self.accessed_outside_comparisons
+                || other.accessed_outside_comparisons,
         }
     }
 
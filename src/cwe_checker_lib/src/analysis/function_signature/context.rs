@@ -1,4 +1,7 @@
-use crate::abstract_domain::{AbstractDomain, AbstractIdentifier, BitvectorDomain, DataDomain};
+use crate::abstract_domain::{
+    AbstractDomain, AbstractIdentifier, BitvectorDomain, DataDomain, RegisterDomain, SizedDomain,
+    TryToBitvec,
+};
 use crate::{
     analysis::{forward_interprocedural_fixpoint, graph::Graph},
     intermediate_representation::Project,
@@ -10,12 +13,149 @@ use super::*;
 pub struct Context<'a> {
     graph: &'a Graph<'a>,
     project: &'a Project,
+    /// If set, constant arguments are propagated from the call sites to the called functions.
+    propagate_known_arguments: bool,
+    /// If set, calls that cannot be handled by a calling convention are not treated as dead ends.
+    /// Instead, all registers except the callee-saved registers of the standard calling convention are cleared.
+    clobber_unknown_calls: bool,
+    /// If set, loads of the stack canary are not treated as parameter accesses.
+    /// This is set if the project contains the `__stack_chk_fail` symbol,
+    /// i.e. if the binary was compiled with stack protection.
+    ignore_stack_canary_accesses: bool,
+    /// If set, parameter accesses are only propagated from callees to callers
+    /// if they were propagated through less than the given number of calls before.
+    max_parameter_propagation_depth: Option<usize>,
+    /// If set, the calling conventions of calls are resolved through this function
+    /// before falling back to the calling conventions known to the project.
+    calling_convention_resolver: Option<CallingConventionResolver<'a>>,
 }
 
 impl<'a> Context<'a> {
-    /// Generate a new context object.
-    pub fn new(project: &'a Project, graph: &'a Graph<'a>) -> Self {
-        Context { graph, project }
+    /// Generate a new context object with the given options (see [`SignatureOptions`]).
+    ///
+    /// The option for provenance tracking is ignored by the context,
+    /// since it only affects the states at the function entry nodes.
+    ///
+    /// If the project contains the `__stack_chk_fail` symbol,
+    /// then loads of the stack canary (see [`is_stack_canary_address`]) are ignored for the parameter access patterns.
+    pub fn new(project: &'a Project, graph: &'a Graph<'a>, options: SignatureOptions<'a>) -> Self {
+        let ignore_stack_canary_accesses = project
+            .program
+            .term
+            .extern_symbols
+            .values()
+            .any(|symbol| symbol.name == "__stack_chk_fail");
+        Context {
+            graph,
+            project,
+            propagate_known_arguments: options.propagate_known_arguments,
+            clobber_unknown_calls: options.clobber_unknown_calls,
+            ignore_stack_canary_accesses,
+            max_parameter_propagation_depth: options.max_propagation_depth,
+            calling_convention_resolver: options.calling_convention_resolver,
+        }
+    }
+
+    /// Get the calling convention of the given call from the calling convention resolver
+    /// or return `default` if the resolver does not know the calling convention of the call.
+    fn resolve_calling_convention(
+        &self,
+        call_tid: &Tid,
+        default: Option<&'a CallingConvention>,
+    ) -> Option<&'a CallingConvention> {
+        self.calling_convention_resolver
+            .as_ref()
+            .and_then(|resolver| resolver(call_tid))
+            .or(default)
+    }
+
+    /// Compute the state after a call to the given extern symbol.
+    /// Returns `None` if the extern symbol does not return to the caller.
+    fn handle_extern_symbol_call(
+        &self,
+        mut state: State,
+        call: &Term<Jmp>,
+        extern_symbol: &ExternSymbol,
+    ) -> Option<State> {
+        let cconv = self
+            .resolve_calling_convention(
+                &call.tid,
+                Some(self.project.get_calling_convention(extern_symbol)),
+            )
+            .unwrap();
+        state.handle_extern_symbol(call, extern_symbol, cconv);
+        if extern_symbol.no_return {
+            None
+        } else {
+            Some(state)
+        }
+    }
+
+    /// Remove the callee parameters whose accesses must not be propagated to the caller
+    /// because their propagation depth would exceed the maximal propagation depth.
+    pub fn limit_parameter_propagation(
+        &self,
+        mut callee_params: Vec<(Arg, AccessPattern)>,
+    ) -> Vec<(Arg, AccessPattern)> {
+        if let Some(max_depth) = self.max_parameter_propagation_depth {
+            callee_params.retain(|(_, access_pattern)| {
+                matches!(access_pattern.get_propagation_depth(), Some(depth) if depth < max_depth)
+            });
+        }
+        callee_params
+    }
+
+    /// Generate the entry state of the called function for the given call site.
+    ///
+    /// The values of integer parameter registers that are constant at the call site
+    /// are recorded as known arguments of the callee (see [`State::get_known_argument`]).
+    /// If several parameter registers point to the same memory object at the call site,
+    /// then the known values of all but the first of these parameters are their offsets
+    /// relative to the first parameter.
+    /// The known arguments are not added to the register values of the callee,
+    /// since they would then end up in the return values of the callee
+    /// and mark return values that are just a parameter as containing unknown values.
+    /// After merging the entry states of all call sites,
+    /// an argument is known if and only if the same value was passed at all call sites.
+    fn generate_callee_entry_state(
+        &self,
+        caller_state: &State,
+        callee: &Term<Sub>,
+        calling_convention: &CallingConvention,
+    ) -> State {
+        let mut callee_state = generate_entry_state(self.project, callee);
+        if caller_state.is_provenance_tracking_enabled() {
+            callee_state.enable_provenance_tracking();
+        }
+        // Maps the targets of pointer arguments to the first parameter pointing to them.
+        let mut pointer_targets: HashMap<
+            AbstractIdentifier,
+            (AbstractIdentifier, BitvectorDomain),
+        > = HashMap::new();
+        for register in &calling_convention.integer_parameter_register {
+            let value = caller_state.get_register(register);
+            let param_id = AbstractIdentifier::new_from_var(callee.tid.clone(), register);
+            if let Some(constant) = value.get_if_absolute_value() {
+                callee_state.set_known_argument(param_id, constant.clone().into());
+            } else if let Some((target, offset)) = value.get_if_unique_target() {
+                if offset.try_to_bitvec().is_err() {
+                    continue;
+                }
+                match pointer_targets.get(target) {
+                    Some((base_param_id, base_offset)) => callee_state.set_known_argument(
+                        param_id,
+                        DataDomain::from_target(
+                            base_param_id.clone(),
+                            offset.bin_op(BinOpType::IntSub, base_offset),
+                        ),
+                    ),
+                    None => {
+                        pointer_targets.insert(target.clone(), (param_id, offset.clone()));
+                    }
+                }
+            }
+        }
+        callee_state
     }
 
     /// Compute the return values of a call and return them (without adding them to the caller state).
@@ -26,6 +166,14 @@ impl<'a> Context<'a> {
     /// i.e. values for which the origin is not known or not expressible in the abstract IDs known to the caller,
     /// then a call- and register-specific abstract ID is added to the corresponding return value.
     /// This ID is not added to the tracked IDs of the caller state.
+    ///
+    /// For float return registers that are only the lower bytes of a larger (vector) register
+    /// the returned value has the size of the float return expression
+    /// and not the size of the corresponding base register.
+    ///
+    /// If the callee does not modify any float return register,
+    /// then the float return values are not reconstructed from the callee state.
+    /// Instead, the float return registers keep their values from the caller state.
     fn compute_return_values_of_call<'cconv>(
         &self,
         caller_state: &mut State,
@@ -43,7 +191,42 @@ impl<'a> Context<'a> {
             );
             return_value_list.push((return_register, return_value));
         }
+        let float_return_registers = calling_convention
+            .float_return_register
+            .iter()
+            .flat_map(|return_expr| return_expr.input_vars());
+        if float_return_registers
+            .clone()
+            .all(|register| callee_state.is_register_unmodified(register))
+        {
+            // Cheap path for callees that do not use floating point values.
+            for register in float_return_registers {
+                return_value_list.push((register, caller_state.get_register(register)));
+            }
+            return return_value_list;
+        }
         for return_expr in &calling_convention.float_return_register {
+            if let Expression::Subpiece {
+                low_byte,
+                size,
+                arg,
+            } = return_expr
+            {
+                match &**arg {
+                    Expression::Var(return_register) if *low_byte == ByteSize::new(0) => {
+                        let return_value = self.compute_return_register_value_of_call(
+                            caller_state,
+                            callee_state,
+                            return_register,
+                            call,
+                        );
+                        return_value_list
+                            .push((return_register, truncate_return_value(&return_value, *size)));
+                        continue;
+                    }
+                    _ => (),
+                }
+            }
             for return_register in return_expr.input_vars() {
                 let return_value = self.compute_return_register_value_of_call(
                     caller_state,
@@ -57,6 +240,31 @@ impl<'a> Context<'a> {
         return_value_list
     }
 
+    /// Compute the value of a composite return value that spans the first two integer return registers
+    /// of the calling convention, e.g. a 128-bit value returned in `RDX:RAX` on x86-64.
+    ///
+    /// The `return_value_list` has to be the list computed by [`Context::compute_return_values_of_call`].
+    /// Returns `None` if the calling convention does not declare at least two integer return registers.
+    fn compute_composite_return_value_of_call(
+        &self,
+        return_value_list: &[(&Variable, DataDomain<BitvectorDomain>)],
+        calling_convention: &CallingConvention,
+    ) -> Option<DataDomain<BitvectorDomain>> {
+        let low_register = calling_convention.integer_return_register.first()?;
+        let high_register = calling_convention.integer_return_register.get(1)?;
+        let get_value = |register: &Variable| {
+            return_value_list
+                .iter()
+                .find(|(var, _)| *var == register)
+                .map(|(_, value)| value.clone())
+                .unwrap_or_else(|| DataDomain::new_top(register.size))
+        };
+        Some(merge_register_pair_values(
+            &get_value(low_register),
+            &get_value(high_register),
+        ))
+    }
+
     /// Compute the return value for the given register.
     ///
     /// The return value contains the IDs of all possible input IDs of the call that it may reference.
@@ -73,16 +281,18 @@ impl<'a> Context<'a> {
         let mut return_value: DataDomain<BitvectorDomain> =
             DataDomain::new_empty(return_register.size);
         // For absolute or Top-values originating in the callee the Top-flag of the return value is set.
-        if callee_value.contains_top() || callee_value.get_absolute_value().is_some() {
+        if !callee_value.is_pure_relative() {
             return_value.set_contains_top_flag();
         }
         // For every relative value in the callee we check whether it is relative a parameter to the callee.
         // If yes, we can compute it relative to the value of the parameter at the callsite and add the result to the return value.
         // Else we just set the Top-flag of the return value to indicate some value originating in the callee.
+        // The call sites recorded in the callee for the IDs not expressible in the IDs of the caller.
+        let mut unknown_value_provenance = BTreeSet::new();
         for (callee_id, callee_offset) in callee_value.get_relative_values() {
             if let Some(param_arg) = callee_state.get_arg_corresponding_to_id(callee_id) {
                 let param_value = caller_state.eval_parameter_arg(&param_arg);
-                if param_value.contains_top() || param_value.get_absolute_value().is_some() {
+                if !param_value.is_pure_relative() {
                     return_value.set_contains_top_flag()
                 }
                 for (param_id, param_offset) in param_value.get_relative_values() {
@@ -90,26 +300,154 @@ impl<'a> Context<'a> {
                         param_id.clone(),
                         param_offset.clone() + callee_offset.clone(),
                     );
+                    caller_state.add_provenance_of_return_value(
+                        &value,
+                        &call.tid,
+                        callee_state.get_provenance(callee_id),
+                    );
                     return_value = return_value.merge(&value);
                 }
             } else {
                 return_value.set_contains_top_flag();
+                if let Some(call_sites) = callee_state.get_provenance(callee_id) {
+                    unknown_value_provenance.extend(call_sites.iter().cloned());
+                }
             }
         }
         // If the Top-flag of the return value was set we replace it with an ID representing the return register
         // to indicate where the unknown value originated from.
         if return_value.contains_top() {
-            let id = AbstractIdentifier::new_from_var(call.tid.clone(), return_register);
-            let value =
-                DataDomain::from_target(id, Bitvector::zero(return_register.size.into()).into());
-            return_value = return_value.merge(&value);
-            return_value.unset_contains_top_flag();
+            let id = AbstractIdentifier::new_return_value(call.tid.clone(), return_register);
+            let offset: BitvectorDomain = Bitvector::zero(return_register.size.into()).into();
+            caller_state.add_provenance_of_return_value(
+                &DataDomain::from_target(id.clone(), offset.clone()),
+                &call.tid,
+                Some(&unknown_value_provenance),
+            );
+            return_value.replace_top_with(id, offset);
         }
 
         return_value
     }
 }
 
+/// Truncate the given return value to its lowest `size` bytes.
+///
+/// In contrast to [`RegisterDomain::subpiece`] relative values are kept with truncated offsets,
+/// since the truncated value still represents the lower bytes of the same value.
+fn truncate_return_value(
+    value: &DataDomain<BitvectorDomain>,
+    size: ByteSize,
+) -> DataDomain<BitvectorDomain> {
+    if value.bytesize() == size {
+        return value.clone();
+    }
+    let mut truncated_value = DataDomain::new_empty(size);
+    truncated_value.set_relative_values(
+        value
+            .get_relative_values()
+            .iter()
+            .map(|(id, offset)| (id.clone(), offset.subpiece(ByteSize::new(0), size)))
+            .collect(),
+    );
+    truncated_value.set_absolute_value(
+        value
+            .get_absolute_value()
+            .map(|absolute_value| absolute_value.subpiece(ByteSize::new(0), size)),
+    );
+    if value.contains_top() {
+        truncated_value.set_contains_top_flag();
+    }
+    truncated_value
+}
+
+/// Returns `true` if the assignment computes the result of a comparison or (an intermediate value of) a condition flag.
+///
+/// Comparisons include the carry and borrow checks used to compute condition flags.
+/// Since e.g. the x86 `CMP` and `TEST` instructions are lowered to subtractions resp. bitwise ANDs
+/// into temporary registers (followed by bit counts for the parity flag),
+/// such assignments to temporary registers also count as condition flag computations.
+/// Other uses of the temporary register still mark the values referenced by it as read.
+fn is_comparison(var: &Variable, value: &Expression) -> bool {
+    match value {
+        Expression::BinOp { op, .. } => match op {
+            BinOpType::IntEqual
+            | BinOpType::IntNotEqual
+            | BinOpType::IntLess
+            | BinOpType::IntSLess
+            | BinOpType::IntLessEqual
+            | BinOpType::IntSLessEqual
+            | BinOpType::IntCarry
+            | BinOpType::IntSCarry
+            | BinOpType::IntSBorrow => true,
+            BinOpType::IntSub | BinOpType::IntAnd => var.is_temp,
+            _ => false,
+        },
+        Expression::Cast {
+            op: CastOpType::PopCount,
+            ..
+        } => var.is_temp,
+        _ => false,
+    }
+}
+
+/// Returns `true` if the given address expression points to the stack canary of stack-protected functions,
+/// i.e. if it is a constant offset relative to the `FS` or `GS` segment register (e.g. `FS_OFFSET + 0x28` on x86-64).
+fn is_stack_canary_address(address: &Expression) -> bool {
+    let is_segment_register =
+        |var: &Variable| matches!(var.name.as_str(), "FS_OFFSET" | "GS_OFFSET");
+    match address {
+        Expression::Var(var) => is_segment_register(var),
+        Expression::BinOp {
+            op: BinOpType::IntAdd,
+            lhs,
+            rhs,
+        } => match (lhs.as_ref(), rhs.as_ref()) {
+            (Expression::Var(var), Expression::Const(_))
+            | (Expression::Const(_), Expression::Var(var)) => is_segment_register(var),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Merge the values of a register pair into one value spanning both registers.
+///
+/// Relative values of the low register are kept with their offsets zero-extended to the combined size.
+/// Since the high register contains the most significant bytes of the combined value,
+/// relative values of the high register are only kept with unknown offsets.
+/// Thus if the high register value is `Top`, the combined value is still partially known
+/// through the value of the low register.
+fn merge_register_pair_values(
+    low_value: &DataDomain<BitvectorDomain>,
+    high_value: &DataDomain<BitvectorDomain>,
+) -> DataDomain<BitvectorDomain> {
+    let size = low_value.bytesize() + high_value.bytesize();
+    if let (Some(low), Some(high)) = (
+        low_value.get_if_absolute_value(),
+        high_value.get_if_absolute_value(),
+    ) {
+        return high.bin_op(BinOpType::Piece, low).into();
+    }
+    let mut relative_values: BTreeMap<AbstractIdentifier, BitvectorDomain> = low_value
+        .get_relative_values()
+        .iter()
+        .map(|(id, offset)| (id.clone(), offset.cast(CastOpType::IntZExt, size)))
+        .collect();
+    for id in high_value.get_relative_values().keys() {
+        relative_values.insert(id.clone(), BitvectorDomain::new_top(size));
+    }
+    let mut merged_value = DataDomain::new_empty(size);
+    merged_value.set_relative_values(relative_values);
+    if low_value.get_absolute_value().is_some() || high_value.get_absolute_value().is_some() {
+        merged_value.set_absolute_value(Some(BitvectorDomain::new_top(size)));
+    }
+    if low_value.contains_top() || high_value.contains_top() {
+        merged_value.set_contains_top_flag();
+    }
+    merged_value
+}
+
 impl<'a> forward_interprocedural_fixpoint::Context<'a> for Context<'a> {
     type Value = State;
 
@@ -124,14 +462,32 @@ impl<'a> forward_interprocedural_fixpoint::Context<'a> for Context<'a> {
     fn update_def(&self, state: &State, def: &Term<Def>) -> Option<State> {
         let mut new_state = state.clone();
         match &def.term {
+            Def::Assign { var, value } if is_comparison(var, value) => {
+                new_state.set_compared_flag_for_input_ids_of_expression(value);
+                new_state.set_register(var, state.eval(value));
+            }
             Def::Assign { var, value } => {
                 new_state.set_read_flag_for_input_ids_of_expression(value);
                 new_state.set_register(var, state.eval(value));
             }
+            Def::Load { var, address }
+                if self.ignore_stack_canary_accesses && is_stack_canary_address(address) =>
+            {
+                // The canary value is only compared to its copy on the stack before returning.
+                // Since the loaded value contains no abstract IDs, these comparisons flag no parameters.
+                new_state.set_register(var, DataDomain::new_top(var.size));
+            }
             Def::Load { var, address } => {
                 new_state.set_deref_flag_for_input_ids_of_expression(address);
-                let value = new_state.load_value(new_state.eval(address), var.size);
-                new_state.set_register(var, value);
+                let address_value = new_state.eval(address);
+                if address_value.is_top() {
+                    // Fast path for loads through completely unknown pointers.
+                    new_state.set_register(var, DataDomain::new_top(var.size));
+                } else {
+                    new_state.set_variadic_flag_for_stack_walk(&address_value);
+                    let value = new_state.load_value(address_value, var.size);
+                    new_state.set_register(var, value);
+                }
             }
             Def::Store { address, value } => {
                 new_state.set_mutable_deref_flag_for_input_ids_of_expression(address);
@@ -164,7 +520,7 @@ impl<'a> forward_interprocedural_fixpoint::Context<'a> for Context<'a> {
                 new_state.set_read_flag_for_input_ids_of_expression(address);
             }
             Jmp::CBranch { condition, .. } => {
-                new_state.set_read_flag_for_input_ids_of_expression(condition);
+                new_state.set_compared_flag_for_input_ids_of_expression(condition);
             }
             _ => (),
         }
@@ -173,13 +529,21 @@ impl<'a> forward_interprocedural_fixpoint::Context<'a> for Context<'a> {
 
     fn update_call(
         &self,
-        _state: &State,
-        _call: &Term<Jmp>,
-        _target: &crate::analysis::graph::Node,
-        _calling_convention: &Option<String>,
+        state: &State,
+        call: &Term<Jmp>,
+        target: &crate::analysis::graph::Node,
+        calling_convention: &Option<String>,
     ) -> Option<State> {
-        // No knowledge is transferred from the caller to the callee.
-        None
+        if !self.propagate_known_arguments {
+            // No knowledge is transferred from the caller to the callee.
+            return None;
+        }
+        let calling_convention = self.resolve_calling_convention(
+            &call.tid,
+            self.project
+                .get_specific_calling_convention(calling_convention),
+        )?;
+        Some(self.generate_callee_entry_state(state, target.get_sub(), calling_convention))
     }
 
     fn update_call_stub(&self, state: &State, call: &Term<Jmp>) -> Option<State> {
@@ -187,41 +551,81 @@ impl<'a> forward_interprocedural_fixpoint::Context<'a> for Context<'a> {
         match &call.term {
             Jmp::CallInd { target, .. } => {
                 new_state.set_read_flag_for_input_ids_of_expression(target);
-                if let Some(cconv) = self.project.get_standard_calling_convention() {
+                // Calls through stubs of extern symbols (e.g. PLT stubs) are handled like direct calls.
+                if let Some(extern_symbol) = state
+                    .eval(target)
+                    .get_if_absolute_value()
+                    .and_then(|address| address.try_to_bitvec().ok())
+                    .and_then(|address| self.project.get_extern_symbol_at_address(&address))
+                {
+                    return self.handle_extern_symbol_call(new_state, call, extern_symbol);
+                }
+                if let Some(cconv) = self.resolve_calling_convention(
+                    &call.tid,
+                    self.project.get_standard_calling_convention(),
+                ) {
                     new_state.handle_unknown_function_stub(call, cconv);
                     return Some(new_state);
                 }
             }
             Jmp::Call { target, .. } => {
                 if let Some(extern_symbol) = self.project.program.term.extern_symbols.get(target) {
-                    let cconv = self.project.get_calling_convention(extern_symbol);
-                    new_state.handle_extern_symbol(call, extern_symbol, cconv);
-                    if !extern_symbol.no_return {
+                    return self.handle_extern_symbol_call(new_state, call, extern_symbol);
+                } else {
+                    // Use the calling convention of the target function if it is known.
+                    let cconv_name = self
+                        .project
+                        .program
+                        .term
+                        .subs
+                        .get(target)
+                        .and_then(|sub| sub.term.calling_convention.clone());
+                    if let Some(cconv) = self.resolve_calling_convention(
+                        &call.tid,
+                        self.project.get_specific_calling_convention(&cconv_name),
+                    ) {
+                        new_state.handle_unknown_function_stub(call, cconv);
                         return Some(new_state);
                     }
-                } else if let Some(cconv) = self.project.get_standard_calling_convention() {
-                    new_state.handle_unknown_function_stub(call, cconv);
-                    return Some(new_state);
                 }
             }
             _ => (),
         }
+        if self.clobber_unknown_calls {
+            // Only the callee-saved registers of the standard calling convention survive the call.
+            let callee_saved = self
+                .project
+                .get_standard_calling_convention()
+                .map(|cconv| cconv.callee_saved_register.as_slice())
+                .unwrap_or_default();
+            new_state.clear_non_callee_saved_register(callee_saved);
+            return Some(new_state);
+        }
         // The call could not be properly handled, so we treat it as a dead end in the control flow graph.
         None
     }
 
+    /// Compute the state after the call from the state at one of the return sites of the callee.
+    ///
+    /// The graph contains a separate `CallReturn` node for each return site of the callee.
+    /// The states computed for these nodes are merged at the return site of the caller,
+    /// so that the caller sees the merge of the return values of all return sites of the callee.
     fn update_return(
         &self,
         state: Option<&State>,
         state_before_call: Option<&State>,
         call_term: &Term<Jmp>,
         _return_term: &Term<Jmp>,
-        _calling_convention: &Option<String>,
+        calling_convention: &Option<String>,
     ) -> Option<State> {
         if state.is_none() || state_before_call.is_none() {
             return None;
         }
-        let calling_convention = match self.project.get_standard_calling_convention() {
+        let calling_convention = match self.resolve_calling_convention(
+            &call_term.tid,
+            self.project
+                .get_specific_calling_convention(calling_convention),
+        ) {
             Some(cconv) => cconv,
             None => return None,
         };
@@ -229,8 +633,9 @@ impl<'a> forward_interprocedural_fixpoint::Context<'a> for Context<'a> {
         let callee_state = state.unwrap();
         let mut new_state = old_state.clone();
         // Merge parameter access patterns with the access patterns from the callee.
-        let parameters = callee_state.get_params_of_current_function();
-        new_state.merge_parameter_access(&parameters);
+        let parameters =
+            self.limit_parameter_propagation(callee_state.get_params_of_current_function());
+        new_state.merge_parameter_access(&parameters, callee_state.is_variadic());
         // Compute values for return register (but do not add them to `new_state` yet)
         let return_value_list = self.compute_return_values_of_call(
             &mut new_state,
@@ -238,12 +643,35 @@ impl<'a> forward_interprocedural_fixpoint::Context<'a> for Context<'a> {
             calling_convention,
             call_term,
         );
+        // Values spanning both registers of the return register pair are only meaningful
+        // if the callee writes to both registers.
+        let composite_return_value = match &calling_convention.integer_return_register[..] {
+            [low_register, high_register, ..]
+                if !callee_state.is_register_unmodified(low_register)
+                    && !callee_state.is_register_unmodified(high_register) =>
+            {
+                self.compute_composite_return_value_of_call(&return_value_list, calling_convention)
+                    .map(|value| (high_register, low_register, value))
+            }
+            _ => None,
+        };
         // From now on the operations on new_state are allowed to modify register values.
         // Only retain callee-saved register from the caller register values.
         new_state.clear_non_callee_saved_register(&calling_convention.callee_saved_register);
         // Now we can insert the return values into the state
         for (var, value) in return_value_list {
-            new_state.set_register(var, value);
+            if callee_state.is_register_unmodified(var) {
+                // The callee does not write to the register, so it still contains the value from before the call.
+                new_state.set_register(var, old_state.get_register(var));
+            } else if value.bytesize() == var.size {
+                new_state.set_register(var, value);
+            } else {
+                // Float return values may only occupy the lower bytes of the register.
+                new_state.set_register(var, value.cast(CastOpType::IntZExt, var.size));
+            }
+        }
+        if let Some((high_register, low_register, value)) = composite_return_value {
+            new_state.set_register_pair(high_register, low_register, value);
         }
         Some(new_state)
     }
@@ -253,10 +681,24 @@ impl<'a> forward_interprocedural_fixpoint::Context<'a> for Context<'a> {
         state: &State,
         condition: &Expression,
         _block_before_condition: &Term<Blk>,
-        _is_true: bool,
+        is_true: bool,
     ) -> Option<State> {
         let mut new_state = state.clone();
-        new_state.set_read_flag_for_input_ids_of_expression(condition);
+        new_state.set_compared_flag_for_input_ids_of_expression(condition);
+        new_state
+            .specialize_by_equality_comparison(condition, is_true)
+            .ok()?;
+        // Branches that contradict the arguments known at all call sites are unreachable.
+        if new_state.eval(condition).try_to_bitvec().is_err() {
+            if let Ok(condition_value) = new_state
+                .eval_with_known_arguments(condition)
+                .try_to_bitvec()
+            {
+                if condition_value.is_zero() == is_true {
+                    return None;
+                }
+            }
+        }
         Some(new_state)
     }
 }
@@ -1,4 +1,5 @@
 use super::*;
+use crate::analysis::forward_interprocedural_fixpoint::Context as _;
 use std::collections::HashSet;
 
 #[test]
@@ -7,7 +8,7 @@ fn test_compute_return_values_of_call() {
     let cconv = CallingConvention::mock_x64();
     let graph = crate::analysis::graph::get_program_cfg(&project.program, HashSet::new());
 
-    let context = Context::new(&project, &graph);
+    let context = Context::new(&project, &graph, SignatureOptions::default());
 
     let mut caller_state = State::mock_x64("caller");
     let mut callee_state = State::mock_x64("callee");
@@ -18,15 +19,27 @@ fn test_compute_return_values_of_call() {
             return_: Some(Tid::new("return_tid")),
         },
     };
-    // Test returning a value of unknown origin (since RAX does not contain a reference to the input register).
+    // Test a return register that is not modified by the callee. The value of the caller is kept.
     let return_values =
         context.compute_return_values_of_call(&mut caller_state, &callee_state, &cconv, &call);
     let expected_val = DataDomain::from_target(
-        AbstractIdentifier::new_from_var(Tid::new("call_tid"), &Variable::mock("RAX", 8)),
+        AbstractIdentifier::new_from_var(Tid::new("caller"), &Variable::mock("RAX", 8)),
         Bitvector::from_i64(0).into(),
     );
     assert_eq!(return_values.iter().len(), 3);
     assert_eq!(return_values[0], (&Variable::mock("RAX", 8), expected_val));
+    // Test returning a value of unknown origin (since RAX does not contain a reference to the input register).
+    callee_state.set_register(
+        &Variable::mock("RAX", 8),
+        DataDomain::new_top(ByteSize::new(8)),
+    );
+    let return_values =
+        context.compute_return_values_of_call(&mut caller_state, &callee_state, &cconv, &call);
+    let expected_val = DataDomain::from_target(
+        AbstractIdentifier::new_return_value(Tid::new("call_tid"), &Variable::mock("RAX", 8)),
+        Bitvector::from_i64(0).into(),
+    );
+    assert_eq!(return_values[0], (&Variable::mock("RAX", 8), expected_val));
     // Test returning a known value.
     let param_ref = DataDomain::from_target(
         AbstractIdentifier::new_from_var(Tid::new("callee"), &Variable::mock("RDI", 8)),
@@ -42,3 +55,703 @@ fn test_compute_return_values_of_call() {
     assert_eq!(return_values.iter().len(), 3);
     assert_eq!(return_values[0], (&Variable::mock("RAX", 8), expected_val));
 }
+
+#[test]
+fn test_compute_float_return_value_of_call() {
+    let project = Project::mock_empty();
+    let mut cconv = CallingConvention::mock_x64();
+    let zmm0 = Variable::mock("ZMM0", 64);
+    cconv.float_return_register =
+        vec![Expression::Var(zmm0.clone()).subpiece(ByteSize::new(0), ByteSize::new(4))];
+    let graph = crate::analysis::graph::get_program_cfg(&project.program, HashSet::new());
+    let context = Context::new(&project, &graph, SignatureOptions::default());
+
+    let mut caller_state = State::mock_x64("caller");
+    let mut callee_state = State::mock_x64("callee");
+    let call = Term {
+        tid: Tid::new("call_tid"),
+        term: Jmp::Call {
+            target: Tid::new("callee"),
+            return_: Some(Tid::new("return_tid")),
+        },
+    };
+    // Test returning a float value of unknown origin.
+    callee_state.set_register(&zmm0, DataDomain::new_top(ByteSize::new(64)));
+    let return_values =
+        context.compute_return_values_of_call(&mut caller_state, &callee_state, &cconv, &call);
+    let expected_val = DataDomain::from_target(
+        AbstractIdentifier::new_return_value(Tid::new("call_tid"), &zmm0),
+        Bitvector::zero(ByteSize::new(4).into()).into(),
+    );
+    assert_eq!(return_values.len(), 3);
+    assert_eq!(return_values[2], (&zmm0, expected_val));
+    assert_eq!(return_values[2].1.bytesize(), ByteSize::new(4));
+    // Test returning the float parameter with an unknown value added to it.
+    let mut param_value = DataDomain::from_target(
+        AbstractIdentifier::new_from_var(Tid::new("callee"), &zmm0),
+        Bitvector::zero(ByteSize::new(64).into()).into(),
+    );
+    param_value.set_contains_top_flag();
+    callee_state.set_register(&zmm0, param_value);
+    let return_values =
+        context.compute_return_values_of_call(&mut caller_state, &callee_state, &cconv, &call);
+    let mut expected_val = DataDomain::from_target(
+        AbstractIdentifier::new_from_var(Tid::new("caller"), &zmm0),
+        Bitvector::zero(ByteSize::new(4).into()).into(),
+    );
+    expected_val.insert_relative_value(
+        AbstractIdentifier::new_return_value(Tid::new("call_tid"), &zmm0),
+        Bitvector::zero(ByteSize::new(4).into()).into(),
+    );
+    assert_eq!(return_values[2], (&zmm0, expected_val));
+    // If the callee does not touch the float return register,
+    // then the whole register keeps its value from the caller.
+    callee_state.set_register(
+        &zmm0,
+        DataDomain::from_target(
+            AbstractIdentifier::new_from_var(Tid::new("callee"), &zmm0),
+            Bitvector::zero(ByteSize::new(64).into()).into(),
+        ),
+    );
+    let return_values =
+        context.compute_return_values_of_call(&mut caller_state, &callee_state, &cconv, &call);
+    assert_eq!(return_values[2], (&zmm0, caller_state.get_register(&zmm0)));
+    assert_eq!(return_values[2].1.bytesize(), ByteSize::new(64));
+}
+
+#[test]
+fn test_compute_composite_return_value_of_call() {
+    let project = Project::mock_empty();
+    let cconv = CallingConvention::mock_x64();
+    let graph = crate::analysis::graph::get_program_cfg(&project.program, HashSet::new());
+    let context = Context::new(&project, &graph, SignatureOptions::default());
+    let rax = Variable::mock("RAX", 8);
+    let rdx = Variable::mock("RDX", 8);
+    let param_id = AbstractIdentifier::new_from_var(Tid::new("caller"), &Variable::mock("RDI", 8));
+
+    // Both registers contain known absolute values.
+    let return_values = vec![
+        (&rax, Bitvector::from_u64(1).into()),
+        (&rdx, Bitvector::from_u64(2).into()),
+    ];
+    let expected_value: DataDomain<BitvectorDomain> = Bitvector::from_u64(2)
+        .bin_op(BinOpType::Piece, &Bitvector::from_u64(1))
+        .unwrap()
+        .into();
+    assert_eq!(
+        context.compute_composite_return_value_of_call(&return_values, &cconv),
+        Some(expected_value)
+    );
+
+    // The high register is `Top`, but the low register points to a parameter.
+    let return_values = vec![
+        (
+            &rax,
+            DataDomain::from_target(param_id.clone(), Bitvector::from_i64(4).into()),
+        ),
+        (&rdx, DataDomain::new_top(ByteSize::new(8))),
+    ];
+    let composite_value = context
+        .compute_composite_return_value_of_call(&return_values, &cconv)
+        .unwrap();
+    assert_eq!(composite_value.bytesize(), ByteSize::new(16));
+    assert!(composite_value.contains_top());
+    assert_eq!(
+        composite_value.get_relative_values().get(&param_id),
+        Some(
+            &Bitvector::from_i64(4)
+                .into_resize_unsigned(ByteSize::new(16))
+                .into()
+        )
+    );
+
+    // A calling convention with only one integer return register has no composite return value.
+    let mut cconv = cconv;
+    cconv.integer_return_register = vec![rax.clone()];
+    assert_eq!(
+        context.compute_composite_return_value_of_call(&return_values, &cconv),
+        None
+    );
+}
+
+#[test]
+fn test_update_return_with_composite_return_value() {
+    let project = Project::mock_x64();
+    let graph = crate::analysis::graph::get_program_cfg(&project.program, HashSet::new());
+    let context = Context::new(&project, &graph, SignatureOptions::default());
+    let rax = Variable::mock("RAX", 8);
+    let rdx = Variable::mock("RDX", 8);
+    let register_pair = Expression::BinOp {
+        op: BinOpType::Piece,
+        lhs: Box::new(Expression::Var(rdx.clone())),
+        rhs: Box::new(Expression::Var(rax.clone())),
+    };
+    let caller_state = State::mock_x64("caller");
+    let mut callee_state = State::mock_x64("callee");
+    let call = Term {
+        tid: Tid::new("call_tid"),
+        term: Jmp::Call {
+            target: Tid::new("callee"),
+            return_: Some(Tid::new("return_tid")),
+        },
+    };
+    let return_term = Term {
+        tid: Tid::new("return"),
+        term: Jmp::Return(Expression::Const(Bitvector::zero(64.into()))),
+    };
+
+    // The callee only writes to RAX, so no composite return value is computed.
+    let param_id = AbstractIdentifier::new_from_var(Tid::new("callee"), &Variable::mock("RDI", 8));
+    callee_state.set_register(
+        &rax,
+        DataDomain::from_target(param_id, Bitvector::from_i64(4).into()),
+    );
+    let new_state = context
+        .update_return(
+            Some(&callee_state),
+            Some(&caller_state),
+            &call,
+            &return_term,
+            &None,
+        )
+        .unwrap();
+    assert_eq!(
+        new_state.eval(&register_pair),
+        new_state
+            .get_register(&rdx)
+            .bin_op(BinOpType::Piece, &new_state.get_register(&rax))
+    );
+
+    // If the callee writes to both registers, the composite value keeps the pointer in the low register.
+    callee_state.set_register(&rdx, Bitvector::from_u64(0).into());
+    let new_state = context
+        .update_return(
+            Some(&callee_state),
+            Some(&caller_state),
+            &call,
+            &return_term,
+            &None,
+        )
+        .unwrap();
+    let caller_param_id =
+        AbstractIdentifier::new_from_var(Tid::new("caller"), &Variable::mock("RDI", 8));
+    let composite_value = new_state.eval(&register_pair);
+    assert_eq!(composite_value.bytesize(), ByteSize::new(16));
+    assert_eq!(
+        composite_value.get_relative_values().get(&caller_param_id),
+        Some(
+            &Bitvector::from_i64(4)
+                .into_resize_unsigned(ByteSize::new(16))
+                .into()
+        )
+    );
+    // Overwriting one of the registers invalidates the composite value.
+    let mut new_state = new_state;
+    new_state.set_register(&rdx, Bitvector::from_u64(1).into());
+    assert!(new_state
+        .eval(&register_pair)
+        .get_relative_values()
+        .is_empty());
+}
+
+#[test]
+fn test_update_return_with_specific_calling_convention() {
+    let mut project = Project::mock_empty();
+    let mut fastcall = CallingConvention::mock_x64();
+    fastcall.name = "__fastcall".to_string();
+    fastcall.integer_return_register = vec![Variable::mock("RCX", 8)];
+    fastcall.float_return_register = vec![];
+    project
+        .calling_conventions
+        .insert("__stdcall".to_string(), CallingConvention::mock_x64());
+    project
+        .calling_conventions
+        .insert("__fastcall".to_string(), fastcall);
+    let graph = crate::analysis::graph::get_program_cfg(&project.program, HashSet::new());
+    let context = Context::new(&project, &graph, SignatureOptions::default());
+
+    let caller_state = State::mock_x64("caller");
+    let callee_state = State::mock_x64("callee");
+    let call = Term {
+        tid: Tid::new("call_tid"),
+        term: Jmp::Call {
+            target: Tid::new("callee"),
+            return_: Some(Tid::new("return_tid")),
+        },
+    };
+    let return_term = Term {
+        tid: Tid::new("return"),
+        term: Jmp::Return(Expression::Const(Bitvector::zero(64.into()))),
+    };
+
+    // The named calling convention is used.
+    // Since RCX is also a parameter register, the callee returns the input value of the caller in it.
+    let new_state = context
+        .update_return(
+            Some(&callee_state),
+            Some(&caller_state),
+            &call,
+            &return_term,
+            &Some("__fastcall".to_string()),
+        )
+        .unwrap();
+    assert_eq!(
+        new_state.get_register(&Variable::mock("RCX", 8)),
+        DataDomain::from_target(
+            AbstractIdentifier::new_from_var(Tid::new("caller"), &Variable::mock("RCX", 8)),
+            Bitvector::from_i64(0).into(),
+        )
+    );
+    assert!(new_state.get_register(&Variable::mock("RAX", 8)).is_top());
+
+    // Without a named calling convention the standard calling convention is used.
+    // Since the callee does not modify RAX, the caller keeps its value of RAX.
+    let new_state = context
+        .update_return(
+            Some(&callee_state),
+            Some(&caller_state),
+            &call,
+            &return_term,
+            &None,
+        )
+        .unwrap();
+    assert_eq!(
+        new_state.get_register(&Variable::mock("RAX", 8)),
+        DataDomain::from_target(
+            AbstractIdentifier::new_from_var(Tid::new("caller"), &Variable::mock("RAX", 8)),
+            Bitvector::from_i64(0).into(),
+        )
+    );
+    assert!(new_state.get_register(&Variable::mock("RCX", 8)).is_top());
+}
+
+#[test]
+fn test_update_return_with_calling_convention_resolver() {
+    let project = Project::mock_x64();
+    let mut fastcall = CallingConvention::mock_x64();
+    fastcall.integer_return_register = vec![Variable::mock("RCX", 8)];
+    fastcall.float_return_register = vec![];
+    let graph = crate::analysis::graph::get_program_cfg(&project.program, HashSet::new());
+    let resolver: CallingConventionResolver = Rc::new(|call_tid: &Tid| {
+        if call_tid == &Tid::new("resolved_call") {
+            Some(&fastcall)
+        } else {
+            None
+        }
+    });
+    let context = Context::new(
+        &project,
+        &graph,
+        SignatureOptions {
+            calling_convention_resolver: Some(resolver),
+            ..SignatureOptions::default()
+        },
+    );
+
+    let caller_state = State::mock_x64("caller");
+    let callee_state = State::mock_x64("callee");
+    let call = |tid: &str| Term {
+        tid: Tid::new(tid),
+        term: Jmp::Call {
+            target: Tid::new("callee"),
+            return_: Some(Tid::new("return_tid")),
+        },
+    };
+    let return_term = Term {
+        tid: Tid::new("return"),
+        term: Jmp::Return(Expression::Const(Bitvector::zero(64.into()))),
+    };
+    let rcx_input = DataDomain::from_target(
+        AbstractIdentifier::new_from_var(Tid::new("caller"), &Variable::mock("RCX", 8)),
+        Bitvector::from_i64(0).into(),
+    );
+
+    // The calling convention returned by the resolver takes precedence over the project calling convention.
+    let new_state = context
+        .update_return(
+            Some(&callee_state),
+            Some(&caller_state),
+            &call("resolved_call"),
+            &return_term,
+            &None,
+        )
+        .unwrap();
+    assert_eq!(new_state.get_register(&Variable::mock("RCX", 8)), rcx_input);
+    assert!(new_state.get_register(&Variable::mock("RAX", 8)).is_top());
+
+    // For other calls the calling convention of the project is used.
+    let new_state = context
+        .update_return(
+            Some(&callee_state),
+            Some(&caller_state),
+            &call("other_call"),
+            &return_term,
+            &None,
+        )
+        .unwrap();
+    assert!(new_state.get_register(&Variable::mock("RCX", 8)).is_top());
+}
+
+#[test]
+fn test_update_call_with_known_arguments() {
+    let mut project = Project::mock_empty();
+    project
+        .calling_conventions
+        .insert("__stdcall".to_string(), CallingConvention::mock_x64());
+    let graph = crate::analysis::graph::get_program_cfg(&project.program, HashSet::new());
+    let callee = Sub::mock("callee");
+    let block = Blk::mock();
+    let target = Node::BlkStart(&block, &callee);
+    let call = Term {
+        tid: Tid::new("call_tid"),
+        term: Jmp::Call {
+            target: Tid::new("callee"),
+            return_: Some(Tid::new("return_tid")),
+        },
+    };
+    let rdi = Variable::mock("RDI", 8);
+    let rsi = Variable::mock("RSI", 8);
+    let mut caller_state = State::mock_x64("caller");
+    caller_state.set_register(&rdi, Bitvector::from_i64(5).into());
+
+    // By default no knowledge is transferred to the callee.
+    let context = Context::new(&project, &graph, SignatureOptions::default());
+    assert!(context
+        .update_call(&caller_state, &call, &target, &None)
+        .is_none());
+
+    let context = Context::new(
+        &project,
+        &graph,
+        SignatureOptions {
+            propagate_known_arguments: true,
+            ..SignatureOptions::default()
+        },
+    );
+    let callee_state = context
+        .update_call(&caller_state, &call, &target, &None)
+        .unwrap();
+    let rdi_id = AbstractIdentifier::new_from_var(Tid::new("callee"), &rdi);
+    let rsi_id = AbstractIdentifier::new_from_var(Tid::new("callee"), &rsi);
+    assert_eq!(
+        callee_state.get_known_argument(&rdi_id),
+        Some(&Bitvector::from_i64(5).into())
+    );
+    // RSI is not a constant at the call site.
+    assert_eq!(callee_state.get_known_argument(&rsi_id), None);
+    // The register values themselves are not changed by known arguments.
+    assert_eq!(
+        callee_state.get_register(&rdi),
+        DataDomain::from_target(rdi_id.clone(), Bitvector::from_i64(0).into())
+    );
+
+    // Returning a parameter does not generate a return value ID, even if the argument is known.
+    let mut return_state = callee_state.clone();
+    let rax = Variable::mock("RAX", 8);
+    return_state.set_register(&rax, return_state.get_register(&rdi));
+    let mut state_after_call = State::mock_x64("caller");
+    let cconv = CallingConvention::mock_x64();
+    let return_values =
+        context.compute_return_values_of_call(&mut state_after_call, &return_state, &cconv, &call);
+    assert_eq!(
+        return_values[0],
+        (
+            &rax,
+            DataDomain::from_target(
+                AbstractIdentifier::new_from_var(Tid::new("caller"), &rdi),
+                Bitvector::from_i64(0).into()
+            )
+        )
+    );
+
+    // Branches contradicting the known arguments are unreachable.
+    let condition = Expression::BinOp {
+        op: BinOpType::IntEqual,
+        lhs: Box::new(Expression::Var(rdi.clone())),
+        rhs: Box::new(Expression::const_from_i64(5)),
+    };
+    assert!(context
+        .specialize_conditional(&callee_state, &condition, &Blk::mock(), true)
+        .is_some());
+    assert!(context
+        .specialize_conditional(&callee_state, &condition, &Blk::mock(), false)
+        .is_none());
+
+    // If different constants are passed at different call sites, then the constant is not known in the callee.
+    caller_state.set_register(&rdi, Bitvector::from_i64(7).into());
+    let other_callee_state = context
+        .update_call(&caller_state, &call, &target, &None)
+        .unwrap();
+    let merged_state = callee_state.merge(&other_callee_state);
+    assert_eq!(merged_state.get_known_argument(&rdi_id), None);
+    assert!(context
+        .specialize_conditional(&merged_state, &condition, &Blk::mock(), false)
+        .is_some());
+
+    // Pointers to the same object are known relative to the first parameter pointing to the object.
+    let stack_id = AbstractIdentifier::new_from_var(Tid::new("caller"), &Variable::mock("RSP", 8));
+    caller_state.set_register(
+        &rdi,
+        DataDomain::from_target(stack_id.clone(), Bitvector::from_i64(-16).into()),
+    );
+    caller_state.set_register(
+        &rsi,
+        DataDomain::from_target(stack_id, Bitvector::from_i64(-8).into()),
+    );
+    let callee_state = context
+        .update_call(&caller_state, &call, &target, &None)
+        .unwrap();
+    assert_eq!(callee_state.get_known_argument(&rdi_id), None);
+    assert_eq!(
+        callee_state.get_known_argument(&rsi_id),
+        Some(&DataDomain::from_target(
+            rdi_id,
+            Bitvector::from_i64(8).into()
+        ))
+    );
+    let condition = Expression::BinOp {
+        op: BinOpType::IntEqual,
+        lhs: Box::new(Expression::Var(rsi.clone())),
+        rhs: Box::new(Expression::Var(rdi.clone()).plus_const(8)),
+    };
+    assert!(context
+        .specialize_conditional(&callee_state, &condition, &Blk::mock(), true)
+        .is_some());
+    assert!(context
+        .specialize_conditional(&callee_state, &condition, &Blk::mock(), false)
+        .is_none());
+}
+
+#[test]
+fn test_update_def_load_from_top_address() {
+    let project = Project::mock_empty();
+    let graph = crate::analysis::graph::get_program_cfg(&project.program, HashSet::new());
+    let context = Context::new(&project, &graph, SignatureOptions::default());
+    let mut state = State::mock_x64("func");
+    let rax = Variable::mock("RAX", 8);
+    let rcx = Variable::mock("RCX", 8);
+    state.set_register(&rcx, DataDomain::new_top(ByteSize::new(8)));
+    // `load_value` returns a `Top` value of the requested size for `Top` addresses.
+    assert_eq!(
+        state.load_value(DataDomain::new_top(ByteSize::new(8)), ByteSize::new(4)),
+        DataDomain::new_top(ByteSize::new(4))
+    );
+
+    let load = Def::load("load", rax.clone(), Expression::Var(rcx.clone()));
+    let new_state = context.update_def(&state, &load).unwrap();
+    assert_eq!(
+        new_state.get_register(&rax),
+        DataDomain::new_top(ByteSize::new(8))
+    );
+    assert!(!new_state.is_register_unmodified(&rax));
+    assert!(!new_state.is_variadic());
+
+    let eax = Variable::mock("EAX", 4);
+    let load = Def::load("load", eax.clone(), Expression::Var(rcx));
+    let new_state = context.update_def(&state, &load).unwrap();
+    assert_eq!(new_state.get_register(&eax).bytesize(), ByteSize::new(4));
+    assert!(new_state.get_register(&eax).is_top());
+}
+
+#[test]
+fn test_ignore_stack_canary_loads() {
+    let rax = Variable::mock("RAX", 8);
+    let rdi = Variable::mock("RDI", 8);
+    let fs_offset = Variable::mock("FS_OFFSET", 8);
+    let canary_load = Def::load(
+        "canary_load",
+        rax.clone(),
+        Expression::Var(fs_offset.clone()).plus_const(0x28),
+    );
+    let mut state = State::mock_x64("func");
+    // Simulate a segment register value that is derived from a parameter.
+    state.set_register(&fs_offset, state.get_register(&rdi));
+    let rdi_arg = Arg::from_var(rdi.clone(), None);
+
+    // Without stack protection the load is handled like any other load.
+    let project = Project::mock_x64();
+    let graph = crate::analysis::graph::get_program_cfg(&project.program, HashSet::new());
+    let context = Context::new(&project, &graph, SignatureOptions::default());
+    let new_state = context.update_def(&state, &canary_load).unwrap();
+    let params: HashMap<_, _> = new_state
+        .get_params_of_current_function()
+        .into_iter()
+        .collect();
+    assert_eq!(
+        params[&rdi_arg].get_pointer_access(),
+        PointerAccess::ReadOnly
+    );
+
+    let mut project = Project::mock_x64();
+    let mut stack_chk_fail = ExternSymbol::mock_x64();
+    stack_chk_fail.tid = Tid::new("__stack_chk_fail");
+    stack_chk_fail.name = "__stack_chk_fail".to_string();
+    project
+        .program
+        .term
+        .extern_symbols
+        .insert(stack_chk_fail.tid.clone(), stack_chk_fail);
+    let graph = crate::analysis::graph::get_program_cfg(&project.program, HashSet::new());
+    let context = Context::new(&project, &graph, SignatureOptions::default());
+    let new_state = context.update_def(&state, &canary_load).unwrap();
+    assert!(new_state.get_params_of_current_function().is_empty());
+    assert_eq!(
+        new_state.get_register(&rax),
+        DataDomain::new_top(ByteSize::new(8))
+    );
+    // Loads through other registers are still parameter accesses.
+    let load = Def::load("load", rax.clone(), Expression::Var(rdi).plus_const(0x28));
+    let new_state = context.update_def(&state, &load).unwrap();
+    let params: HashMap<_, _> = new_state
+        .get_params_of_current_function()
+        .into_iter()
+        .collect();
+    assert_eq!(
+        params[&rdi_arg].get_pointer_access(),
+        PointerAccess::ReadOnly
+    );
+}
+
+#[test]
+fn test_compared_only_parameters() {
+    let project = Project::mock_x64();
+    let graph = crate::analysis::graph::get_program_cfg(&project.program, HashSet::new());
+    let context = Context::new(&project, &graph, SignatureOptions::default());
+    let rdi = Variable::mock("RDI", 8);
+    let rsi = Variable::mock("RSI", 8);
+    let zf = Variable::mock("ZF", 1);
+    let state = State::mock_x64("func");
+
+    // RDI is only checked for being NULL.
+    let null_check = Def::assign(
+        "null_check",
+        zf.clone(),
+        Expression::BinOp {
+            op: BinOpType::IntEqual,
+            lhs: Box::new(Expression::Var(rdi.clone())),
+            rhs: Box::new(Expression::const_from_i64(0)),
+        },
+    );
+    let state = context.update_def(&state, &null_check).unwrap();
+    // RSI is only used in a branch condition.
+    let state = context
+        .specialize_conditional(
+            &state,
+            &Expression::Var(rsi.clone()).subpiece(ByteSize::new(0), ByteSize::new(1)),
+            &Blk::mock(),
+            true,
+        )
+        .unwrap();
+    let rdi_arg = Arg::from_var(rdi.clone(), None);
+    let rsi_arg = Arg::from_var(rsi, None);
+    let params: HashMap<_, _> = state.get_params_of_current_function().into_iter().collect();
+    for arg in [&rdi_arg, &rsi_arg] {
+        assert_eq!(
+            params[arg].get_pointer_access(),
+            PointerAccess::ComparedOnly
+        );
+    }
+
+    // Any other use of RDI means that it is not only compared anymore.
+    let read = Def::assign(
+        "read",
+        Variable::mock("RAX", 8),
+        Expression::Var(rdi).plus_const(1),
+    );
+    let state = context.update_def(&state, &read).unwrap();
+    let params: HashMap<_, _> = state.get_params_of_current_function().into_iter().collect();
+    assert_eq!(
+        params[&rdi_arg].get_pointer_access(),
+        PointerAccess::NotDereferenced
+    );
+    assert!(params[&rdi_arg].is_read());
+    assert!(!params[&rdi_arg].is_compared_only());
+    assert!(params[&rsi_arg].is_compared_only());
+}
+
+#[test]
+fn test_update_call_stub_with_clobbering() {
+    let project = Project::mock_empty();
+    let graph = crate::analysis::graph::get_program_cfg(&project.program, HashSet::new());
+    let call = Term {
+        tid: Tid::new("call_tid"),
+        term: Jmp::CallInd {
+            target: Expression::Var(Variable::mock("RAX", 8)),
+            return_: Some(Tid::new("return_tid")),
+        },
+    };
+    let rbx = Variable::mock("RBX", 8);
+    let mut state = State::mock_x64("caller");
+    state.set_register(&rbx, Bitvector::from_i64(5).into());
+
+    // Without a calling convention the call is a dead end by default.
+    let context = Context::new(&project, &graph, SignatureOptions::default());
+    assert!(context.update_call_stub(&state, &call).is_none());
+
+    // In clobbering mode the analysis continues with all registers cleared.
+    let context = Context::new(
+        &project,
+        &graph,
+        SignatureOptions {
+            clobber_unknown_calls: true,
+            ..SignatureOptions::default()
+        },
+    );
+    let new_state = context.update_call_stub(&state, &call).unwrap();
+    assert!(new_state.get_register(&rbx).is_top());
+
+    // Callee-saved registers of the standard calling convention survive the call.
+    let mut project = Project::mock_empty();
+    let mut cconv = CallingConvention::mock_x64();
+    cconv.callee_saved_register = vec![rbx.clone()];
+    project
+        .calling_conventions
+        .insert(cconv.name.clone(), cconv);
+    let call = Term {
+        tid: Tid::new("call_tid"),
+        term: Jmp::Call {
+            target: Tid::new("unknown_callee"),
+            return_: Some(Tid::new("return_tid")),
+        },
+    };
+    let context = Context::new(
+        &project,
+        &graph,
+        SignatureOptions {
+            clobber_unknown_calls: true,
+            ..SignatureOptions::default()
+        },
+    );
+    let new_state = context.update_call_stub(&state, &call).unwrap();
+    assert_eq!(new_state.get_register(&rbx), Bitvector::from_i64(5).into());
+}
+
+#[test]
+fn test_update_call_stub_of_extern_symbol_stub() {
+    let mut project = Project::mock_x64();
+    let mut exit_symbol = ExternSymbol::mock_x64();
+    exit_symbol.addresses = vec!["0x1000".to_string()];
+    exit_symbol.no_return = true;
+    project
+        .program
+        .term
+        .extern_symbols
+        .insert(exit_symbol.tid.clone(), exit_symbol);
+    let graph = crate::analysis::graph::get_program_cfg(&project.program, HashSet::new());
+    let context = Context::new(&project, &graph, SignatureOptions::default());
+    let state = State::mock_x64("caller");
+    let call_to = |target: Expression| Term {
+        tid: Tid::new("call_tid"),
+        term: Jmp::CallInd {
+            target,
+            return_: Some(Tid::new("return_tid")),
+        },
+    };
+
+    // The call through the stub of the non-returning symbol is resolved to the symbol.
+    let call = call_to(Expression::Const(Bitvector::from_u64(0x1000)));
+    assert!(context.update_call_stub(&state, &call).is_none());
+    // Other indirect calls are handled as calls to unknown functions.
+    let call = call_to(Expression::Const(Bitvector::from_u64(0x2000)));
+    assert!(context.update_call_stub(&state, &call).is_some());
+}
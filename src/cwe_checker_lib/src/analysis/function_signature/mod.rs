@@ -14,7 +14,8 @@
 //!   than those in the standard calling convention for parameter passing
 //!   the results of this analysis will be wrong.
 //! * Parameters that are used as input values for variadic functions (e.g. sprintf) may be missed
-//!   since detection of variadic function parameters is not yet implemented for this analysis.
+//!   if they are passed in registers, since only values on the stack behind the known parameters
+//!   of a variadic function are marked as possibly accessed.
 //! * If only a part (e.g. a single byte) of a stack parameter is accessed instead of the whole parameter
 //!   then a duplicate stack parameter may be generated.
 //!   A proper sanitation for this case is not yet implemented,
@@ -23,6 +24,16 @@
 //!   although only a smaller sub-register is the actual parameter in many cases.
 //!   Also, if a function uses sub-registers of floating point registers as local variables,
 //!   the registers may be incorrectly flagged as input parameters.
+//! * The signatures of recursive functions may be imprecise.
+//!   Such functions can be identified with [`get_recursive_functions`](crate::analysis::graph::get_recursive_functions).
+//!
+//! Tail calls, i.e. jumps to the entry block of another function with a balanced stack,
+//! are treated like a call followed by a return for the signature of the jumping function:
+//! The parameter accesses of the called function are merged into the signature of the jumping function
+//! and the return values of the called function are also return values of the jumping function.
+//!
+//! If the binary was compiled with stack protection (i.e. it imports `__stack_chk_fail`),
+//! loads of the stack canary through the `FS` or `GS` segment register are not recorded as parameter accesses.
 
 use crate::abstract_domain::AbstractDomain;
 use crate::analysis::fixpoint::Computation;
@@ -33,56 +44,183 @@ use crate::analysis::interprocedural_fixpoint_generic::NodeValue;
 use crate::intermediate_representation::*;
 use crate::prelude::*;
 use crate::utils::log::LogMessage;
+use petgraph::graph::NodeIndex;
+use petgraph::visit::EdgeRef;
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::collections::HashMap;
+use std::collections::HashSet;
+use std::rc::Rc;
 
 mod context;
 use context::*;
 mod state;
-use state::State;
+pub use state::State;
 mod access_pattern;
-pub use access_pattern::AccessPattern;
+pub use access_pattern::{AccessPattern, PointerAccess};
+mod signature_diff;
+pub use signature_diff::{AccessPatternChange, SignatureDiff};
+
+/// Return the names of registers that are never parameters on the given CPU architecture,
+/// even if a calling convention lists them as parameter registers:
+/// * The thread-local storage base registers `FS_OFFSET` and `GS_OFFSET` on x86.
+/// * The platform register `x18` on AArch64 and the static base register `r9` on 32-bit ARM.
+/// * The global pointer `gp` and the register `t9` containing the address of the called function on MIPS.
+///
+/// Reads of these registers in a function are not recorded as parameter accesses.
+pub fn get_non_parameter_registers(cpu_architecture: &str) -> &'static [&'static str] {
+    let cpu_architecture = cpu_architecture.to_lowercase();
+    if cpu_architecture.starts_with("x86") {
+        &["FS_OFFSET", "GS_OFFSET"]
+    } else if cpu_architecture.starts_with("aarch64") {
+        &["x18", "w18"]
+    } else if cpu_architecture.starts_with("arm") {
+        &["r9"]
+    } else if cpu_architecture.starts_with("mips") {
+        &["gp", "t9"]
+    } else {
+        &[]
+    }
+}
+
+/// Generate the state at the entry point of the given function.
+fn generate_entry_state(project: &Project, sub: &Term<Sub>) -> State {
+    let mut state = State::new(
+        &sub.tid,
+        &project.stack_pointer_register,
+        project
+            .get_specific_calling_convention(&sub.term.calling_convention)
+            .unwrap(),
+    );
+    state.untrack_registers(get_non_parameter_registers(&project.cpu_architecture));
+    // Functions that are known to be variadic, e.g. statically linked `printf`.
+    if project
+        .program
+        .term
+        .extern_symbols
+        .values()
+        .any(|symbol| symbol.has_var_args && symbol.name == sub.term.name)
+    {
+        state.set_variadic_flag();
+    }
+    state
+}
 
 /// Generate the computation object for the fixpoint computation
 /// and set the node values for all function entry nodes.
 fn generate_fixpoint_computation<'a>(
     project: &'a Project,
     graph: &'a Graph,
+    options: SignatureOptions<'a>,
 ) -> Computation<GeneralizedContext<'a, Context<'a>>> {
-    let context = Context::new(project, graph);
+    let track_provenance = options.track_provenance;
+    let context = Context::new(project, graph, options);
     let mut computation = create_computation(context, None);
     // Set the node values for all function entry nodes.
     for node in graph.node_indices() {
-        if let Node::BlkStart(block, sub) = graph[node] {
-            if let Some(entry_block) = sub.term.blocks.get(0) {
-                if entry_block.tid == block.tid {
-                    // The node of a function entry point
-                    computation.set_node_value(
-                        node,
-                        NodeValue::Value(State::new(
-                            &sub.tid,
-                            &project.stack_pointer_register,
-                            project
-                                .get_specific_calling_convention(&sub.term.calling_convention)
-                                .unwrap(),
-                        )),
-                    )
-                }
-            }
+        if is_function_entry_node(&graph[node]) {
+            let state =
+                generate_entry_state_with_options(project, graph[node].get_sub(), track_provenance);
+            computation.set_node_value(node, NodeValue::Value(state))
         }
     }
     computation
 }
 
+/// Generate the entry state of the given function
+/// and enable provenance tracking for it if `track_provenance` is set.
+fn generate_entry_state_with_options(
+    project: &Project,
+    sub: &Term<Sub>,
+    track_provenance: bool,
+) -> State {
+    let mut state = generate_entry_state(project, sub);
+    if track_provenance {
+        state.enable_provenance_tracking();
+    }
+    state
+}
+
+/// Compute the fixpoint where functions called inside the program are specialized to their call sites,
+/// i.e. where the known arguments at all call sites are used to prune unreachable branches
+/// (see [`SignatureOptions::propagate_known_arguments`]).
+///
+/// The entry nodes of functions that are called inside the program get their node values only through the call edges.
+/// All other functions, i.e. program entry points and functions without a reachable call site
+/// (e.g. functions only called from unreachable or recursive functions), get the generic entry state.
+/// Since functions may also be called indirectly or from outside the program,
+/// only the signatures of the functions with generic entry states are valid for all callers.
+/// Their TIDs are returned together with the computation.
+fn compute_specialized_fixpoint<'a>(
+    project: &'a Project,
+    graph: &'a Graph,
+    options: SignatureOptions<'a>,
+) -> (
+    Computation<GeneralizedContext<'a, Context<'a>>>,
+    HashSet<Tid>,
+) {
+    let track_provenance = options.track_provenance;
+    let context = Context::new(project, graph, options);
+    let mut computation = create_computation(context, None);
+    let entry_nodes: Vec<NodeIndex> = graph
+        .node_indices()
+        .filter(|node| is_function_entry_node(&graph[*node]))
+        .collect();
+    let mut generic_functions = HashSet::new();
+    for node in entry_nodes.iter().copied() {
+        let sub = graph[node].get_sub();
+        if project.program.term.entry_points.contains(&sub.tid)
+            || !graph
+                .edges_directed(node, petgraph::Direction::Incoming)
+                .any(|edge| matches!(edge.weight(), Edge::Call(_)))
+        {
+            let state = generate_entry_state_with_options(project, sub, track_provenance);
+            computation.set_node_value(node, NodeValue::Value(state));
+            generic_functions.insert(sub.tid.clone());
+        }
+    }
+    computation.compute_with_max_steps(100);
+    // Functions without a reachable call site get the generic entry state.
+    let unreached_entry_nodes: Vec<NodeIndex> = entry_nodes
+        .into_iter()
+        .filter(|node| computation.get_node_value(*node).is_none())
+        .collect();
+    if !unreached_entry_nodes.is_empty() {
+        for node in unreached_entry_nodes {
+            let sub = graph[node].get_sub();
+            let state = generate_entry_state_with_options(project, sub, track_provenance);
+            computation.set_node_value(node, NodeValue::Value(state));
+            generic_functions.insert(sub.tid.clone());
+        }
+        computation.compute_with_max_steps(100);
+    }
+    (computation, generic_functions)
+}
+
+/// Returns `true` if the given node is the start node of the entry block of its function.
+fn is_function_entry_node(node: &Node) -> bool {
+    match node {
+        Node::BlkStart(block, sub) => sub
+            .term
+            .blocks
+            .first()
+            .map(|entry_block| entry_block.tid == block.tid)
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
 /// Extract the function signatures from the computed fixpoint.
 ///
 /// This function needs to merge the signatures at all nodes corresponding to a function
 /// to ensure that parameter accesses on non-returning execution paths of a function
 /// are also recognized in the function signature.
+/// The parameter accesses of calls to non-returning extern symbols are also merged into the signature,
+/// although the call stub edges of such calls do not propagate any state to the return site.
 fn extract_fn_signatures_from_fixpoint<'a>(
     project: &'a Project,
     graph: &'a Graph,
-    fixpoint: Computation<GeneralizedContext<'a, Context<'a>>>,
+    fixpoint: &Computation<GeneralizedContext<'a, Context<'a>>>,
 ) -> BTreeMap<Tid, FunctionSignature> {
     let mut fn_sig_map: BTreeMap<Tid, FunctionSignature> = project
         .program
@@ -119,9 +257,213 @@ fn extract_fn_signatures_from_fixpoint<'a>(
             }
         }
     }
+    // The control flow ends at calls to non-returning extern symbols,
+    // so the parameter accesses of these calls are not contained in any node value.
+    for (source, call, extern_symbol, _) in get_no_return_extern_calls(project, graph) {
+        if let Some(NodeValue::Value(state)) = fixpoint.get_node_value(source) {
+            let mut state = state.clone();
+            let cconv = project.get_calling_convention(extern_symbol);
+            state.handle_extern_symbol(call, extern_symbol, cconv);
+            let fn_sig = fn_sig_map
+                .get_mut(state.get_current_function_tid())
+                .unwrap();
+            fn_sig.merge_with_fn_sig_of_state(&state);
+        }
+    }
     fn_sig_map
 }
 
+/// Merge the parameter accesses and return values of tail-called functions into the signatures of the jumping functions.
+///
+/// The parameter accesses of the callee are merged into the state at the jump like at a call site.
+/// Since the callee may itself tail-call other functions,
+/// the merging is repeated until the signatures do not change anymore.
+/// The merged signatures are sanitized again.
+/// The TIDs of functions whose signatures could not be sanitized properly are added to `unsanitized_functions`.
+fn merge_tail_call_signatures<'a>(
+    project: &'a Project,
+    graph: &'a Graph,
+    fixpoint: &Computation<GeneralizedContext<'a, Context<'a>>>,
+    fn_sig_map: &mut BTreeMap<Tid, FunctionSignature>,
+    unsanitized_functions: &mut BTreeSet<Tid>,
+) {
+    let tail_calls: Vec<(&State, &Tid)> = get_tail_calls(project, graph, fixpoint)
+        .into_iter()
+        .filter_map(|(source, callee)| match fixpoint.get_node_value(source) {
+            Some(NodeValue::Value(state)) => Some((state, &callee.tid)),
+            _ => None,
+        })
+        .collect();
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for (state, callee_tid) in tail_calls.iter() {
+            let callee_sig = &fn_sig_map[*callee_tid];
+            let callee_params = fixpoint
+                .get_context()
+                .get_context()
+                .limit_parameter_propagation(
+                    callee_sig
+                        .parameters
+                        .iter()
+                        .map(|(arg, access_pattern)| (arg.clone(), *access_pattern))
+                        .collect(),
+                );
+            let callee_return_values = callee_sig.return_values.clone();
+            let mut state = (*state).clone();
+            state.merge_parameter_access(&callee_params, false);
+            let caller_tid = state.get_current_function_tid();
+            let mut caller_sig = fn_sig_map[caller_tid].clone();
+            caller_sig.merge_with_fn_sig_of_state(&state);
+            if caller_sig.sanitize(project).is_err() {
+                unsanitized_functions.insert(caller_tid.clone());
+            }
+            caller_sig.order_parameters(project, caller_tid);
+            for return_value in callee_return_values {
+                if !caller_sig.return_values.contains(&return_value) {
+                    caller_sig.return_values.push(return_value);
+                }
+            }
+            if caller_sig != fn_sig_map[caller_tid] {
+                fn_sig_map.insert(caller_tid.clone(), caller_sig);
+                changed = true;
+            }
+        }
+    }
+}
+
+/// Return all tail calls in the graph.
+///
+/// A tail call is a `Jmp::Branch` or `Jmp::BranchInd` to the entry block of another function
+/// such that the stack pointer at the jump has the same value as at the start of the jumping function,
+/// i.e. the called function returns directly to the caller of the jumping function.
+/// In the graph the target of such a jump is a copy of the entry block belonging to the jumping function.
+/// Copies of blocks generated by the normalization of the project are recognized by the address of the block.
+///
+/// The tail calls are returned as tuples of the source node of the jump edge and the called function.
+fn get_tail_calls<'a>(
+    project: &'a Project,
+    graph: &Graph<'a>,
+    fixpoint: &Computation<GeneralizedContext<'a, Context<'a>>>,
+) -> Vec<(NodeIndex, &'a Term<Sub>)> {
+    let mut entry_block_tid_to_sub: HashMap<&Tid, &'a Term<Sub>> = HashMap::new();
+    let mut entry_block_address_to_sub: HashMap<&str, &'a Term<Sub>> = HashMap::new();
+    for sub in project.program.term.subs.values() {
+        if let Some(entry_block) = sub.term.blocks.first() {
+            entry_block_tid_to_sub.insert(&entry_block.tid, sub);
+            if entry_block.tid.address != "UNKNOWN" {
+                entry_block_address_to_sub.insert(&entry_block.tid.address, sub);
+            }
+        }
+    }
+    graph
+        .edge_references()
+        .filter_map(|edge| {
+            let jump = match edge.weight() {
+                Edge::Jump(jump, _) => jump,
+                _ => return None,
+            };
+            if !matches!(jump.term, Jmp::Branch(_) | Jmp::BranchInd(_)) {
+                return None;
+            }
+            let (target_block, sub) = match graph[edge.target()] {
+                Node::BlkStart(target_block, sub) => (target_block, sub),
+                _ => return None,
+            };
+            let callee = entry_block_tid_to_sub
+                .get(&target_block.tid)
+                .or_else(|| entry_block_address_to_sub.get(target_block.tid.address.as_str()))
+                .filter(|callee| callee.tid != sub.tid)?;
+            let state = match fixpoint.get_node_value(edge.source()) {
+                Some(NodeValue::Value(state)) => state,
+                _ => return None,
+            };
+            let stack_pointer = state.get_register(&project.stack_pointer_register);
+            match state.get_offset_if_exact_stack_pointer(&stack_pointer) {
+                Some(offset) if offset.is_zero() => Some((edge.source(), *callee)),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Return all calls to non-returning extern symbols (e.g. `exit` or `abort`) in the graph.
+///
+/// The calls are returned as tuples of the source node of the corresponding call stub edge,
+/// the call itself, the called extern symbol and the node of the return site of the call.
+fn get_no_return_extern_calls<'a>(
+    project: &'a Project,
+    graph: &Graph<'a>,
+) -> Vec<(NodeIndex, &'a Term<Jmp>, &'a ExternSymbol, NodeIndex)> {
+    graph
+        .edge_references()
+        .filter_map(|edge| match edge.weight() {
+            Edge::ExternCallStub(call) => match &call.term {
+                Jmp::Call { target, .. } => project
+                    .program
+                    .term
+                    .extern_symbols
+                    .get(target)
+                    .filter(|extern_symbol| extern_symbol.no_return)
+                    .map(|extern_symbol| (edge.source(), *call, extern_symbol, edge.target())),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+/// Return the TIDs of the return site blocks of calls to non-returning extern symbols
+/// that are unreachable according to the given node values of the function signature fixpoint,
+/// i.e. return sites that cannot be reached through any other path in the control flow graph.
+pub fn get_unreachable_return_sites_of_no_return_calls(
+    project: &Project,
+    graph: &Graph,
+    node_values: &FixpointNodeValues,
+) -> BTreeSet<Tid> {
+    get_no_return_extern_calls(project, graph)
+        .into_iter()
+        .filter(|(_, _, _, return_node)| !node_values.contains_key(return_node))
+        .filter_map(|(_, _, _, return_node)| match graph[return_node] {
+            Node::BlkStart(block, _) => Some(block.tid.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The version of the JSON format generated by [`serialize_to_json`].
+///
+/// The version is increased whenever the format changes,
+/// so that consumers of the JSON output can detect incompatible changes.
+pub const JSON_SCHEMA_VERSION: u64 = 1;
+
+/// Generate a JSON-representation of the given function signatures.
+///
+/// For each function the parameters together with their access patterns,
+/// the return values and the result values of the function are listed
+/// (see [`FunctionSignature::to_json`]).
+/// The output is stable in the sense that functions and parameters are always ordered the same way.
+pub fn serialize_to_json(
+    project: &Project,
+    fn_sigs: &BTreeMap<Tid, FunctionSignature>,
+) -> serde_json::Value {
+    let functions: serde_json::Map<String, serde_json::Value> = fn_sigs
+        .iter()
+        .map(|(fn_tid, fn_sig)| {
+            let sub = project.program.term.subs.get(fn_tid);
+            let mut fn_json = fn_sig.to_json(&project.stack_pointer_register);
+            fn_json["name"] = sub
+                .map(|sub| serde_json::Value::String(sub.term.name.clone()))
+                .unwrap_or(serde_json::Value::Null);
+            (format!("{}", fn_tid), fn_json)
+        })
+        .collect();
+    serde_json::json!({
+        "schema_version": JSON_SCHEMA_VERSION,
+        "functions": functions,
+    })
+}
+
 /// Compute the function signatures for all functions in the project.
 ///
 /// Returns a map from the function TIDs to their signatures,
@@ -132,30 +474,437 @@ pub fn compute_function_signatures<'a>(
     project: &'a Project,
     graph: &'a Graph,
 ) -> (BTreeMap<Tid, FunctionSignature>, Vec<LogMessage>) {
-    let mut computation = generate_fixpoint_computation(project, graph);
+    let (fn_sig_map, logs, _) =
+        compute_function_signatures_with_options(project, graph, SignatureOptions::default());
+    (fn_sig_map, logs)
+}
+
+/// The node values of a fixpoint computation of the function signature analysis.
+pub type FixpointNodeValues = HashMap<NodeIndex, NodeValue<State>>;
+
+/// A function mapping the TIDs of call instructions to the calling conventions used by the calls.
+/// The calling convention of a call is taken from the project if the function returns `None` for the call.
+pub type CallingConventionResolver<'a> = Rc<dyn Fn(&Tid) -> Option<&'a CallingConvention> + 'a>;
+
+/// Options for the computation of function signatures with [`compute_function_signatures_with_options`].
+///
+/// The default options correspond to the computation done by [`compute_function_signatures`].
+#[derive(Default, Clone)]
+pub struct SignatureOptions<'a> {
+    /// If set, parameter register values that are known at all call sites of a function
+    /// are forwarded to the entry state of the function,
+    /// where they are used to prune branches that are unreachable for the known arguments.
+    /// A parameter value is known if it is the same constant at all call sites
+    /// or if it points to the same memory object as another parameter with the same offset difference.
+    ///
+    /// The signature of a function is still computed from its generic entry state,
+    /// since the function may also be called indirectly or from outside of the program.
+    /// But the signatures of its callers contain only the parameter accesses
+    /// on the paths of the function that are reachable for the known arguments.
+    pub propagate_known_arguments: bool,
+    /// If set, calls for which no calling convention is known do not end the control flow.
+    /// Instead, all registers that are not callee-saved in the standard calling convention are cleared at such calls.
+    pub clobber_unknown_calls: bool,
+    /// If set, the states of the computation record for each abstract ID
+    /// the call sites through whose return values the ID was introduced into the state
+    /// (see [`State::get_provenance`]).
+    /// This is useful for debugging imprecise function signatures, but needs more memory.
+    pub track_provenance: bool,
+    /// If set, parameter accesses are propagated from callees to callers through at most the given number of calls.
+    ///
+    /// For example, with a maximal depth of one the parameters of a function contain the accesses in the function
+    /// and the accesses in its direct callees, but not the accesses in callees of its callees.
+    /// With a maximal depth of zero the signatures only contain the accesses in the functions themselves.
+    /// This trades the precision of the signatures for less analysis time on binaries with deep call chains.
+    pub max_propagation_depth: Option<usize>,
+    /// If set, the calling conventions of calls are determined by this function.
+    ///
+    /// This allows tools with external knowledge about the ABI of a binary to override
+    /// the calling conventions that would be chosen based on the project,
+    /// e.g. to force `stdcall` for calls in some region of a Windows binary.
+    /// For calls where the resolver returns `None` the calling convention known to the project is used.
+    pub calling_convention_resolver: Option<CallingConventionResolver<'a>>,
+}
+
+/// The node values of a fixpoint computation of the function signature analysis
+/// together with the options used for the computation.
+///
+/// It is used to recompute the function signatures after changes to some functions
+/// with [`recompute_function_signatures`].
+pub struct SignatureFixpoint<'a> {
+    node_values: FixpointNodeValues,
+    options: SignatureOptions<'a>,
+}
+
+impl<'a> SignatureFixpoint<'a> {
+    /// Get the node values of the fixpoint computation.
+    pub fn node_values(&self) -> &FixpointNodeValues {
+        &self.node_values
+    }
+
+    /// Get the options used for the fixpoint computation.
+    pub fn options(&self) -> &SignatureOptions<'a> {
+        &self.options
+    }
+}
+
+/// Compute the function signatures for all functions in the project with the given options
+/// and also return the computed fixpoint.
+///
+/// The fixpoint can be used to recompute the function signatures
+/// after changes to some functions with [`recompute_function_signatures`].
+pub fn compute_function_signatures_with_options<'a>(
+    project: &'a Project,
+    graph: &'a Graph,
+    options: SignatureOptions<'a>,
+) -> (
+    BTreeMap<Tid, FunctionSignature>,
+    Vec<LogMessage>,
+    SignatureFixpoint<'a>,
+) {
+    let mut computation =
+        generate_fixpoint_computation(project, graph, get_generic_options(&options));
     computation.compute_with_max_steps(100);
+    extract_results_with_options(project, graph, &computation, options)
+}
+
+/// Return the options for the fixpoint computation with generic entry states for all functions,
+/// i.e. the given options without the propagation of known arguments.
+fn get_generic_options<'a>(options: &SignatureOptions<'a>) -> SignatureOptions<'a> {
+    SignatureOptions {
+        propagate_known_arguments: false,
+        ..options.clone()
+    }
+}
+
+/// Extract the results from the computed fixpoint with generic entry states for all functions.
+///
+/// If known arguments are propagated (see [`SignatureOptions::propagate_known_arguments`]),
+/// then the fixpoint specialized to the known arguments is computed
+/// and the signatures of all functions with generic entry states in it are taken from the specialized fixpoint.
+fn extract_results_with_options<'a>(
+    project: &'a Project,
+    graph: &'a Graph,
+    computation: &Computation<GeneralizedContext<'a, Context<'a>>>,
+    options: SignatureOptions<'a>,
+) -> (
+    BTreeMap<Tid, FunctionSignature>,
+    Vec<LogMessage>,
+    SignatureFixpoint<'a>,
+) {
+    let (mut fn_sigs, logs, node_values) =
+        extract_results_from_fixpoint(project, graph, computation);
+    if options.propagate_known_arguments {
+        let (specialized_computation, generic_functions) =
+            compute_specialized_fixpoint(project, graph, options.clone());
+        let (specialized_fn_sigs, _, _) =
+            extract_results_from_fixpoint(project, graph, &specialized_computation);
+        for (fn_tid, fn_sig) in specialized_fn_sigs {
+            if generic_functions.contains(&fn_tid) {
+                fn_sigs.insert(fn_tid, fn_sig);
+            }
+        }
+    }
+    (
+        fn_sigs,
+        logs,
+        SignatureFixpoint {
+            node_values,
+            options,
+        },
+    )
+}
+
+/// Recompute the function signatures after the functions with the given TIDs have changed.
+///
+/// The computation uses the same options as the computation of the prior fixpoint.
+/// Since the states of a function only depend on the function itself and its callees,
+/// only the node values of the changed functions and their (transitive) callers are recomputed.
+/// The values of all other nodes are restored from the prior fixpoint.
+/// The result is the same as for a full recomputation of the function signatures.
+///
+/// If known arguments are propagated (see [`SignatureOptions::propagate_known_arguments`]),
+/// the fixpoint specialized to the known arguments is always recomputed completely,
+/// since the entry states of functions also depend on their callers in it.
+///
+/// The graph has to have the same node indices as the graph used for the previous computation,
+/// i.e. only the contents of the blocks of the changed functions may differ.
+pub fn recompute_function_signatures<'a>(
+    project: &'a Project,
+    graph: &'a Graph,
+    prior_fixpoint: &SignatureFixpoint<'a>,
+    changed_functions: &HashSet<Tid>,
+) -> (
+    BTreeMap<Tid, FunctionSignature>,
+    Vec<LogMessage>,
+    SignatureFixpoint<'a>,
+) {
+    let affected_functions = get_changed_functions_and_their_callers(graph, changed_functions);
+    let options = prior_fixpoint.options.clone();
+    let track_provenance = options.track_provenance;
+    let context = Context::new(project, graph, get_generic_options(&options));
+    let mut computation = create_computation(context, None);
+    let is_affected =
+        |node: NodeIndex| affected_functions.contains(&get_function_of_node(&graph[node]).tid);
+    for node in graph.node_indices() {
+        if is_affected(node) {
+            if is_function_entry_node(&graph[node]) {
+                let state = generate_entry_state_with_options(
+                    project,
+                    graph[node].get_sub(),
+                    track_provenance,
+                );
+                computation.set_node_value(node, NodeValue::Value(state));
+            }
+        } else if let Some(value) = prior_fixpoint.node_values.get(&node) {
+            if graph.neighbors(node).any(is_affected) {
+                // The node has to be processed again to update its affected successors.
+                computation.set_node_value(node, value.clone());
+            } else {
+                computation.restore_stabilized_node_value(node, value.clone());
+            }
+        }
+    }
+    computation.compute_with_max_steps(100);
+    extract_results_with_options(project, graph, &computation, options)
+}
+
+/// Return the TIDs of the given functions together with the TIDs of all functions that (transitively) call them.
+fn get_changed_functions_and_their_callers(
+    graph: &Graph,
+    changed_functions: &HashSet<Tid>,
+) -> HashSet<Tid> {
+    let mut callers: HashMap<&Tid, Vec<&Tid>> = HashMap::new();
+    for node in graph.node_weights() {
+        if let Node::CallSource {
+            source: (_, caller),
+            target: (_, callee),
+        } = node
+        {
+            callers.entry(&callee.tid).or_default().push(&caller.tid);
+        }
+    }
+    let mut affected_functions = HashSet::new();
+    let mut stack: Vec<&Tid> = changed_functions.iter().collect();
+    while let Some(fn_tid) = stack.pop() {
+        if affected_functions.insert(fn_tid.clone()) {
+            stack.extend(callers.get(fn_tid).into_iter().flatten().copied());
+        }
+    }
+    affected_functions
+}
+
+/// Return the function to which the state at the given node belongs.
+/// For the artificial nodes at call sites this is the calling function.
+fn get_function_of_node<'a>(node: &Node<'a>) -> &'a Term<Sub> {
+    match node {
+        Node::BlkStart(_, sub) | Node::BlkEnd(_, sub) => sub,
+        Node::CallSource {
+            source: (_, sub), ..
+        }
+        | Node::CallReturn { call: (_, sub), .. } => sub,
+    }
+}
+
+/// Return the states at all return sites of functions
+/// together with the function and its calling convention.
+fn get_return_site_states<'a, 'b>(
+    project: &'a Project,
+    graph: &'a Graph,
+    fixpoint: &'b Computation<GeneralizedContext<'a, Context<'a>>>,
+) -> Vec<(&'a Term<Sub>, &'b State, &'a CallingConvention)> {
+    let mut return_site_states = Vec::new();
+    for node in graph.node_indices() {
+        let sub = match graph[node] {
+            Node::BlkEnd(block, sub)
+                if block
+                    .term
+                    .jmps
+                    .iter()
+                    .any(|jmp| matches!(jmp.term, Jmp::Return(_))) =>
+            {
+                sub
+            }
+            _ => continue,
+        };
+        if let (Some(NodeValue::Value(state)), Some(cconv)) = (
+            fixpoint.get_node_value(node),
+            project.get_specific_calling_convention(&sub.term.calling_convention),
+        ) {
+            return_site_states.push((sub, state, cconv));
+        }
+    }
+    return_site_states
+}
+
+/// Return the return registers of each function that are not modified by the function at any of its return sites,
+/// i.e. that contain the same value at each return site as at the function start.
+///
+/// Functions without a reachable return site are not contained in the result.
+fn get_unmodified_return_registers<'a>(
+    project: &'a Project,
+    graph: &'a Graph,
+    fixpoint: &Computation<GeneralizedContext<'a, Context<'a>>>,
+) -> BTreeMap<Tid, BTreeSet<Variable>> {
+    let mut unmodified_registers: BTreeMap<Tid, BTreeSet<Variable>> = BTreeMap::new();
+    for (sub, state, cconv) in get_return_site_states(project, graph, fixpoint) {
+        let unmodified_at_return_site: BTreeSet<Variable> = cconv
+            .get_all_return_register()
+            .into_iter()
+            .filter(|register| state.is_register_unmodified(register))
+            .cloned()
+            .collect();
+        unmodified_registers
+            .entry(sub.tid.clone())
+            .and_modify(|registers| {
+                registers.retain(|register| unmodified_at_return_site.contains(register))
+            })
+            .or_insert(unmodified_at_return_site);
+    }
+    unmodified_registers
+}
+
+/// Return the return registers of each function that contain a value other than `Top`
+/// and other than their value at the function start at some return site of the function.
+///
+/// Return registers that are only clobbered by the function (e.g. by calls to extern functions)
+/// contain `Top` at all return sites and are thus not contained in the result.
+fn get_meaningfully_written_return_registers<'a>(
+    project: &'a Project,
+    graph: &'a Graph,
+    fixpoint: &Computation<GeneralizedContext<'a, Context<'a>>>,
+) -> BTreeMap<Tid, BTreeSet<Variable>> {
+    let mut written_registers: BTreeMap<Tid, BTreeSet<Variable>> = BTreeMap::new();
+    for (sub, state, cconv) in get_return_site_states(project, graph, fixpoint) {
+        written_registers
+            .entry(sub.tid.clone())
+            .or_default()
+            .extend(
+                cconv
+                    .get_all_return_register()
+                    .into_iter()
+                    .filter(|register| {
+                        !state.is_register_unmodified(register)
+                            && !state.get_register(register).is_top()
+                    })
+                    .cloned(),
+            );
+    }
+    written_registers
+}
+
+/// Extract the sanitized function signatures, log messages and node values from the computed fixpoint.
+fn extract_results_from_fixpoint<'a>(
+    project: &'a Project,
+    graph: &'a Graph,
+    computation: &Computation<GeneralizedContext<'a, Context<'a>>>,
+) -> (
+    BTreeMap<Tid, FunctionSignature>,
+    Vec<LogMessage>,
+    FixpointNodeValues,
+) {
     let mut fn_sig_map = extract_fn_signatures_from_fixpoint(project, graph, computation);
+    let unmodified_return_registers = get_unmodified_return_registers(project, graph, computation);
+    let written_return_registers =
+        get_meaningfully_written_return_registers(project, graph, computation);
     // Sanitize the parameters
-    let mut logs = Vec::new();
+    let mut unsanitized_functions = BTreeSet::new();
     for (fn_tid, fn_sig) in fn_sig_map.iter_mut() {
         if fn_sig.sanitize(project).is_err() {
-            logs.push(
-                LogMessage::new_error("Function parameters are not properly sanitized")
-                    .location(fn_tid.clone())
-                    .source("Function Signature Analysis"),
-            );
+            unsanitized_functions.insert(fn_tid.clone());
+        }
+        fn_sig.add_calling_convention_info(project, fn_tid);
+        if let Some(registers) = unmodified_return_registers.get(fn_tid) {
+            fn_sig.remove_unmodified_return_values(registers);
         }
     }
+    // Tail-called functions return directly to the caller of the jumping function.
+    merge_tail_call_signatures(
+        project,
+        graph,
+        computation,
+        &mut fn_sig_map,
+        &mut unsanitized_functions,
+    );
+    let logs = unsanitized_functions
+        .into_iter()
+        .map(|fn_tid| {
+            LogMessage::new_error("Function parameters are not properly sanitized")
+                .location(fn_tid)
+                .source("Function Signature Analysis")
+        })
+        .collect();
+    let mut return_site_states: HashMap<&Tid, Vec<&State>> = HashMap::new();
+    for (sub, state, _) in get_return_site_states(project, graph, computation) {
+        return_site_states.entry(&sub.tid).or_default().push(state);
+    }
+    for (fn_tid, fn_sig) in fn_sig_map.iter_mut() {
+        fn_sig.compute_parameter_confidence(
+            return_site_states
+                .get(fn_tid)
+                .map(|states| states.as_slice())
+                .unwrap_or_default(),
+        );
+        let cconv =
+            project.program.term.subs.get(fn_tid).and_then(|sub| {
+                project.get_specific_calling_convention(&sub.term.calling_convention)
+            });
+        let no_written_registers = BTreeSet::new();
+        fn_sig.select_result_values(
+            cconv,
+            written_return_registers
+                .get(fn_tid)
+                .unwrap_or(&no_written_registers),
+        );
+    }
+    let node_values = computation
+        .node_values()
+        .iter()
+        .map(|(node, value)| (*node, value.clone()))
+        .collect();
 
-    (fn_sig_map, logs)
+    (fn_sig_map, logs, node_values)
+}
+
+/// The confidence that an inferred parameter is a real parameter of a function,
+/// see [`FunctionSignature::get_parameter_confidence`].
+///
+/// The levels are ordered by increasing confidence,
+/// so that parameters can be filtered by comparing their confidence with a threshold.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub enum ParameterConfidence {
+    /// The parameter is only accessed on some paths through the function.
+    Low,
+    /// The parameter is accessed on all paths through the function, but with different sizes.
+    Medium,
+    /// The parameter is accessed on all paths through the function and always with the same size.
+    High,
 }
 
 /// The signature of a function.
-/// Currently only contains information on the parameters of a function and their access patterns.
+/// Contains information on the parameters of a function and their access patterns
+/// and on the return values of the function.
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
 pub struct FunctionSignature {
     /// The parameters of the function together with their access patterns.
     pub parameters: HashMap<Arg, AccessPattern>,
+    /// The return values of the function,
+    /// i.e. the return registers of the calling convention of the function
+    /// that are modified by the function on at least one path to a return site.
+    #[serde(default)]
+    pub return_values: Vec<Arg>,
+    /// The return values that carry the result of the function,
+    /// i.e. at most one integer return register and at most one float return register,
+    /// see [`result_values`](Self::result_values).
+    #[serde(default)]
+    pub result_values: Vec<Arg>,
+    /// The order of the parameters as returned by [`parameters`](Self::parameters).
+    #[serde(default)]
+    parameter_order: Vec<Arg>,
+    /// The confidence levels of the parameters,
+    /// see [`get_parameter_confidence`](Self::get_parameter_confidence).
+    #[serde(default)]
+    parameter_confidence: HashMap<Arg, ParameterConfidence>,
 }
 
 impl FunctionSignature {
@@ -163,7 +912,143 @@ impl FunctionSignature {
     pub fn new() -> Self {
         Self {
             parameters: HashMap::new(),
+            return_values: Vec::new(),
+            result_values: Vec::new(),
+            parameter_order: Vec::new(),
+            parameter_confidence: HashMap::new(),
+        }
+    }
+
+    /// Generate the function signature of the current function of the given state.
+    ///
+    /// The state should be the converged state of the function signature fixpoint at some node of the function.
+    /// Since parameter accesses may happen on any execution path of a function,
+    /// the signatures computed by [`compute_function_signatures`] should be preferred if they are available.
+    ///
+    /// Return registers that still contain their value at the start of the function in the state
+    /// are not return values of the signature.
+    pub fn from_state(state: &State, project: &Project) -> Self {
+        let mut fn_sig = FunctionSignature::new();
+        fn_sig.merge_with_fn_sig_of_state(state);
+        // Sanitation errors only indicate unexpected stack parameters, which are kept in the signature.
+        let _ = fn_sig.sanitize(project);
+        fn_sig.add_calling_convention_info(project, state.get_current_function_tid());
+        fn_sig.compute_parameter_confidence(&[state]);
+        let cconv = project
+            .program
+            .term
+            .subs
+            .get(state.get_current_function_tid())
+            .and_then(|sub| project.get_specific_calling_convention(&sub.term.calling_convention));
+        if let Some(cconv) = cconv {
+            let unmodified_registers: BTreeSet<Variable> = cconv
+                .get_all_return_register()
+                .into_iter()
+                .filter(|register| state.is_register_unmodified(register))
+                .cloned()
+                .collect();
+            fn_sig.remove_unmodified_return_values(&unmodified_registers);
         }
+        fn_sig.select_result_values(cconv, &BTreeSet::new());
+        fn_sig
+    }
+
+    /// Return the parameters of the function together with their access patterns.
+    ///
+    /// Register parameters are ordered as in the calling convention of the function,
+    /// with integer parameter registers before float parameter registers.
+    /// They are followed by the stack parameters ordered by their stack offsets.
+    /// Note that this order does not necessarily correspond to the order of the parameters in the source code
+    /// if both integer and float parameters are present.
+    pub fn parameters(&self) -> Vec<(&Arg, &AccessPattern)> {
+        let mut params: Vec<(&Arg, &AccessPattern)> = self
+            .parameter_order
+            .iter()
+            .filter_map(|arg| self.parameters.get_key_value(arg))
+            .collect();
+        if params.len() < self.parameters.len() {
+            // Parameters that were directly inserted into the parameter map are appended.
+            params.extend(
+                self.parameters
+                    .iter()
+                    .filter(|(arg, _)| !self.parameter_order.contains(arg)),
+            );
+        }
+        params
+    }
+
+    /// Return the parameters of the function together with their access patterns
+    /// in the order in which the given calling convention assigns them,
+    /// i.e. an approximation of the order of the parameters in the source code.
+    ///
+    /// The integer parameter registers come first in the order of the calling convention,
+    /// followed by the float parameter registers in the order of the calling convention
+    /// and the stack parameters ordered by ascending stack offsets.
+    /// Since calling conventions like the System V x86-64 ABI assign integer and float registers independently,
+    /// the order of mixed integer and float parameters in the source code cannot be recovered.
+    pub fn parameters_in_argument_order(
+        &self,
+        calling_convention: &CallingConvention,
+        stack_register: &Variable,
+    ) -> Vec<(&Arg, &AccessPattern)> {
+        let mut params: Vec<(&Arg, &AccessPattern)> = self.parameters.iter().collect();
+        params.sort_by_cached_key(|(arg, _)| {
+            get_parameter_sort_key(arg, Some(calling_convention), stack_register)
+        });
+        params
+    }
+
+    /// Return the return values of the function.
+    pub fn return_values(&self) -> &[Arg] {
+        &self.return_values
+    }
+
+    /// Return the return values that carry the result of the function.
+    ///
+    /// While [`return_values`](Self::return_values) contains all return registers modified by the function,
+    /// the result of a function is usually passed in only one integer and/or one float return register,
+    /// while the other return registers are only clobbered.
+    /// Thus for integer and float return registers each,
+    /// this contains the first return value in the order of the calling convention
+    /// that is not `Top` at some return site of the function.
+    /// If all return values of a kind are `Top` at all return sites (e.g. because they are loaded from unknown memory),
+    /// then the first return value of that kind is used.
+    ///
+    /// If the first two integer return registers both contain a value other than `Top` at some return site,
+    /// then the integer result value is the composite value spanning both registers,
+    /// e.g. `RDX:RAX` for 128-bit values on x86-64.
+    pub fn result_values(&self) -> &[Arg] {
+        &self.result_values
+    }
+
+    /// Returns true if the function does not write to any return register of its calling convention,
+    /// i.e. if the function does not return a value.
+    pub fn returns_void(&self) -> bool {
+        self.return_values.is_empty()
+    }
+
+    /// Return the `index`-th parameter of the function together with its access pattern.
+    /// See [`parameters`](Self::parameters) for the order of the parameters.
+    pub fn get_param(&self, index: usize) -> Option<(&Arg, &AccessPattern)> {
+        self.parameters().get(index).copied()
+    }
+
+    /// Returns true if the value of the `index`-th parameter may be read by the function,
+    /// i.e. if it is used to compute some nontrivial value or stored in some location.
+    /// See [`parameters`](Self::parameters) for the order of the parameters.
+    pub fn reads_param(&self, index: usize) -> bool {
+        self.get_param(index)
+            .map(|(_, access_pattern)| access_pattern.is_read())
+            .unwrap_or(false)
+    }
+
+    /// Returns true if the function may write to the target of the `index`-th parameter
+    /// if the parameter is interpreted as a pointer.
+    /// See [`parameters`](Self::parameters) for the order of the parameters.
+    pub fn writes_through_param(&self, index: usize) -> bool {
+        self.get_param(index)
+            .map(|(_, access_pattern)| access_pattern.is_mutably_dereferenced())
+            .unwrap_or(false)
     }
 
     /// Merge the parameter list of `self` with the given parameter list.
@@ -173,10 +1058,252 @@ impl FunctionSignature {
                 *sig_self = sig_self.merge(sig_new);
             } else {
                 self.parameters.insert(arg.clone(), *sig_new);
+                self.parameter_order.push(arg.clone());
             }
         }
     }
 
+    /// Order the parameters according to the calling convention of the given function
+    /// and set the return values to the return registers of the calling convention.
+    fn add_calling_convention_info(&mut self, project: &Project, fn_tid: &Tid) {
+        self.order_parameters(project, fn_tid);
+        let cconv =
+            project.program.term.subs.get(fn_tid).and_then(|sub| {
+                project.get_specific_calling_convention(&sub.term.calling_convention)
+            });
+        self.return_values = cconv
+            .map(|cconv| {
+                cconv
+                    .integer_return_register
+                    .iter()
+                    .map(|var| Arg::from_var(var.clone(), None))
+                    .chain(
+                        cconv
+                            .float_return_register
+                            .iter()
+                            .map(|expr| Arg::Register {
+                                expr: expr.clone(),
+                                data_type: None,
+                            }),
+                    )
+                    .collect()
+            })
+            .unwrap_or_default();
+    }
+
+    /// Order the parameters according to the calling convention of the given function.
+    fn order_parameters(&mut self, project: &Project, fn_tid: &Tid) {
+        let cconv =
+            project.program.term.subs.get(fn_tid).and_then(|sub| {
+                project.get_specific_calling_convention(&sub.term.calling_convention)
+            });
+        let stack_register = &project.stack_pointer_register;
+        let mut order: Vec<((usize, usize, i64, String), Arg)> = self
+            .parameters
+            .keys()
+            .map(|arg| {
+                (
+                    get_parameter_sort_key(arg, cconv, stack_register),
+                    arg.clone(),
+                )
+            })
+            .collect();
+        order.sort_by(|(left_key, _), (right_key, _)| left_key.cmp(right_key));
+        self.parameter_order = order.into_iter().map(|(_, arg)| arg).collect();
+    }
+
+    /// Remove the return values that only consist of the given registers,
+    /// e.g. because the function does not write to them.
+    fn remove_unmodified_return_values(&mut self, unmodified_registers: &BTreeSet<Variable>) {
+        self.return_values.retain(|arg| match arg {
+            Arg::Register { expr, .. } => !expr
+                .input_vars()
+                .into_iter()
+                .all(|register| unmodified_registers.contains(register)),
+            Arg::Stack { .. } => true,
+        });
+    }
+
+    /// Return the confidence that the given argument is a parameter of the function.
+    ///
+    /// The confidence is high if the parameter is accessed on all paths to the return sites of the function
+    /// and if all accesses to it have the same size.
+    /// Parameters that are only accessed on some paths,
+    /// e.g. only on a rarely taken error path or only on paths not returning to the caller,
+    /// have a low confidence.
+    /// Returns `None` if the argument is not a parameter of the function.
+    pub fn get_parameter_confidence(&self, param: &Arg) -> Option<ParameterConfidence> {
+        if !self.parameters.contains_key(param) {
+            return None;
+        }
+        Some(
+            self.parameter_confidence
+                .get(param)
+                .copied()
+                .unwrap_or(ParameterConfidence::Low),
+        )
+    }
+
+    /// Compute the confidence levels of the parameters of the function
+    /// from the states at the return sites of the function
+    /// (see [`get_parameter_confidence`](Self::get_parameter_confidence)).
+    fn compute_parameter_confidence(&mut self, return_site_states: &[&State]) {
+        let params_at_return_sites: Vec<Vec<(Arg, AccessPattern)>> = return_site_states
+            .iter()
+            .map(|state| state.get_params_of_current_function())
+            .collect();
+        self.parameter_confidence = self
+            .parameters
+            .iter()
+            .map(|(arg, access_pattern)| {
+                let is_accessed_on_all_paths = !params_at_return_sites.is_empty()
+                    && params_at_return_sites.iter().all(|params| {
+                        params.iter().any(|(param, param_access_pattern)| {
+                            param == arg && param_access_pattern.is_accessed_on_all_paths()
+                        })
+                    });
+                let confidence = if !is_accessed_on_all_paths {
+                    ParameterConfidence::Low
+                } else if access_pattern.has_consistent_access_sizes() {
+                    ParameterConfidence::High
+                } else {
+                    ParameterConfidence::Medium
+                };
+                (arg.clone(), confidence)
+            })
+            .collect();
+    }
+
+    /// Select the result values among the return values of the function,
+    /// where `written_registers` are the return registers that contain a value other than `Top` at some return site.
+    /// See [`result_values`](Self::result_values) for the selection rules.
+    fn select_result_values(
+        &mut self,
+        cconv: Option<&CallingConvention>,
+        written_registers: &BTreeSet<Variable>,
+    ) {
+        let cconv = match cconv {
+            Some(cconv) => cconv,
+            None => {
+                self.result_values = Vec::new();
+                return;
+            }
+        };
+        let is_written = |arg: &&Arg| match arg {
+            Arg::Register { expr, .. } => expr
+                .input_vars()
+                .into_iter()
+                .any(|register| written_registers.contains(register)),
+            Arg::Stack { .. } => false,
+        };
+        let is_integer_return_value = |arg: &&Arg| match arg {
+            Arg::Register {
+                expr: Expression::Var(var),
+                ..
+            } => cconv.integer_return_register.contains(var),
+            _ => false,
+        };
+        let is_float_return_value = |arg: &&Arg| match arg {
+            Arg::Register { expr, .. } => cconv.float_return_register.contains(expr),
+            Arg::Stack { .. } => false,
+        };
+        let register_pair_result_value = match &cconv.integer_return_register[..] {
+            [low_register, high_register, ..]
+                if written_registers.contains(low_register)
+                    && written_registers.contains(high_register) =>
+            {
+                Some(Arg::Register {
+                    expr: Expression::BinOp {
+                        op: BinOpType::Piece,
+                        lhs: Box::new(Expression::Var(high_register.clone())),
+                        rhs: Box::new(Expression::Var(low_register.clone())),
+                    },
+                    data_type: None,
+                })
+            }
+            _ => None,
+        };
+        let select = |is_of_kind: &dyn Fn(&&Arg) -> bool| {
+            let mut values_of_kind = self.return_values.iter().filter(is_of_kind);
+            values_of_kind
+                .clone()
+                .find(is_written)
+                .or_else(|| values_of_kind.next())
+                .cloned()
+        };
+        self.result_values = [
+            register_pair_result_value.or_else(|| select(&is_integer_return_value)),
+            select(&is_float_return_value),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+    }
+
+    /// Return whether the target of the given parameter was read or written through the parameter.
+    /// Returns `None` if the argument is not a parameter of the function.
+    pub fn get_pointer_access(&self, param: &Arg) -> Option<PointerAccess> {
+        self.parameters
+            .get(param)
+            .map(|access_pattern| access_pattern.get_pointer_access())
+    }
+
+    /// Generate a JSON-representation of the parameters, return values and result values of the function signature.
+    ///
+    /// Register parameters are listed before stack parameters.
+    /// Register parameters are ordered by their register names and stack parameters by their stack offsets.
+    /// Return values and result values are listed in the order of the calling convention.
+    /// Values spanning several registers are represented by the register names joined with `:`.
+    pub fn to_json(&self, stack_register: &Variable) -> serde_json::Value {
+        let mut params: Vec<((usize, String, i64), serde_json::Value)> = self
+            .parameters
+            .iter()
+            .map(|(arg, access_pattern)| match arg {
+                Arg::Register { expr, .. } => {
+                    let register = get_register_name_of_expression(expr);
+                    let json = serde_json::json!({
+                        "kind": "register",
+                        "register": register,
+                        "size": u64::from(expr.bytesize()),
+                        "access_pattern": access_pattern.to_json(),
+                    });
+                    ((0, register, 0), json)
+                }
+                Arg::Stack { size, .. } => {
+                    let offset = arg
+                        .eval_stack_offset(stack_register)
+                        .ok()
+                        .and_then(|offset| offset.try_to_i64().ok());
+                    let json = serde_json::json!({
+                        "kind": "stack",
+                        "offset": offset,
+                        "size": u64::from(*size),
+                        "access_pattern": access_pattern.to_json(),
+                    });
+                    ((1, String::new(), offset.unwrap_or(i64::MAX)), json)
+                }
+            })
+            .collect();
+        params.sort_by(|(left_key, _), (right_key, _)| left_key.cmp(right_key));
+        let return_values_to_json = |return_values: &[Arg]| {
+            return_values
+                .iter()
+                .filter_map(|arg| match arg {
+                    Arg::Register { expr, .. } => Some(serde_json::json!({
+                        "register": get_register_name_of_expression(expr),
+                        "size": u64::from(expr.bytesize()),
+                    })),
+                    Arg::Stack { .. } => None,
+                })
+                .collect::<Vec<_>>()
+        };
+        serde_json::json!({
+            "parameters": params.into_iter().map(|(_, json)| json).collect::<Vec<_>>(),
+            "return_values": return_values_to_json(&self.return_values),
+            "result_values": return_values_to_json(&self.result_values),
+        })
+    }
+
     /// Merge the function signature with the signature extracted from the given state.
     fn merge_with_fn_sig_of_state(&mut self, state: &State) {
         let params = state.get_params_of_current_function();
@@ -192,20 +1319,24 @@ impl FunctionSignature {
     fn sanitize(&mut self, project: &Project) -> Result<(), Error> {
         match project.cpu_architecture.as_str() {
             "x86" | "x86_32" | "x86_64" => {
-                let return_addr_expr = Expression::Var(project.stack_pointer_register.clone());
-                let return_addr_arg = Arg::Stack {
-                    address: return_addr_expr,
-                    size: project.stack_pointer_register.size,
-                    data_type: None,
-                };
-                self.parameters.remove(&return_addr_arg);
+                // The return address is located at the boundary of the incoming stack frame.
+                // Accesses to it are removed, while accesses below it are left for the error check below.
+                let stack_register = &project.stack_pointer_register;
+                let return_addr_size = u64::from(stack_register.size) as i64;
+                self.parameters.retain(|arg, _| {
+                    !matches!(
+                        arg.eval_stack_offset(stack_register)
+                            .map(|offset| offset.try_to_i64()),
+                        Ok(Ok(offset)) if (0..return_addr_size).contains(&offset)
+                    )
+                });
             }
             _ => (),
         }
         self.check_for_unaligned_stack_params(&project.stack_pointer_register)
     }
 
-    /// Return an error if an unaligned stack parameter
+    /// Return an error if an unaligned stack parameter, a stack parameter below the incoming stack frame
     /// or a stack parameter of different size than the generic pointer size is found.
     fn check_for_unaligned_stack_params(&self, stack_register: &Variable) -> Result<(), Error> {
         for arg in self.parameters.keys() {
@@ -214,6 +1345,9 @@ impl FunctionSignature {
                     return Err(anyhow!("Unexpected stack parameter size"));
                 }
                 if let Ok(offset) = arg.eval_stack_offset(stack_register) {
+                    if offset.try_to_i64()? < 0 {
+                        return Err(anyhow!("Unexpected stack parameter at negative offset"));
+                    }
                     if offset.try_to_u64()? % u64::from(stack_register.size) != 0 {
                         return Err(anyhow!("Unexpected stack parameter alignment"));
                     }
@@ -224,8 +1358,68 @@ impl FunctionSignature {
     }
 }
 
+/// Get the name of the register (or the `:`-joined names of the registers) contained in the given expression.
+fn get_register_name_of_expression(expr: &Expression) -> String {
+    expr.input_vars()
+        .into_iter()
+        .map(|var| var.name.clone())
+        .collect::<Vec<String>>()
+        .join(":")
+}
+
+/// Compute the key by which parameters are ordered in [`FunctionSignature::parameters`]
+/// and [`FunctionSignature::parameters_in_argument_order`].
+fn get_parameter_sort_key(
+    arg: &Arg,
+    cconv: Option<&CallingConvention>,
+    stack_register: &Variable,
+) -> (usize, usize, i64, String) {
+    match arg {
+        Arg::Register { expr, .. } => {
+            let input_vars = expr.input_vars();
+            let name = input_vars
+                .iter()
+                .map(|var| var.name.clone())
+                .collect::<Vec<String>>()
+                .join(":");
+            let integer_index = cconv.and_then(|cconv| {
+                cconv
+                    .integer_parameter_register
+                    .iter()
+                    .position(|register| input_vars.contains(&register))
+            });
+            let float_index = cconv.and_then(|cconv| {
+                cconv
+                    .float_parameter_register
+                    .iter()
+                    .position(|float_expr| {
+                        float_expr
+                            .input_vars()
+                            .iter()
+                            .any(|register| input_vars.contains(register))
+                    })
+            });
+            match (integer_index, float_index) {
+                (Some(index), _) => (0, index, 0, name),
+                (None, Some(index)) => (1, index, 0, name),
+                (None, None) => (2, 0, 0, name),
+            }
+        }
+        Arg::Stack { .. } => {
+            let offset = arg
+                .eval_stack_offset(stack_register)
+                .ok()
+                .and_then(|offset| offset.try_to_i64().ok());
+            (3, 0, offset.unwrap_or(i64::MAX), String::new())
+        }
+    }
+}
+
 impl Default for FunctionSignature {
     fn default() -> Self {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests;
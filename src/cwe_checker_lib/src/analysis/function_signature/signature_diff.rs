@@ -0,0 +1,100 @@
+use super::{AccessPattern, FunctionSignature};
+use crate::intermediate_representation::Arg;
+use crate::prelude::*;
+
+/// The differences between two signatures of the same function,
+/// e.g. of the signatures of a function before and after a patch was applied to a binary.
+///
+/// See [`FunctionSignature::diff`] for how the diff is computed.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default)]
+pub struct SignatureDiff {
+    /// Parameters that are only contained in the new signature.
+    pub added_parameters: Vec<Arg>,
+    /// Parameters that are only contained in the old signature.
+    pub removed_parameters: Vec<Arg>,
+    /// Parameters contained in both signatures whose access patterns differ
+    /// in whether the parameter is read, dereferenced or mutably dereferenced.
+    pub changed_access_patterns: Vec<AccessPatternChange>,
+    /// Return values that are only contained in the new signature.
+    pub added_return_values: Vec<Arg>,
+    /// Return values that are only contained in the old signature.
+    pub removed_return_values: Vec<Arg>,
+}
+
+/// The change of the access pattern of a parameter contained in both signatures of a [`SignatureDiff`].
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct AccessPatternChange {
+    /// The parameter whose access pattern changed.
+    pub parameter: Arg,
+    /// The access pattern of the parameter in the old signature.
+    pub old: AccessPattern,
+    /// The access pattern of the parameter in the new signature.
+    pub new: AccessPattern,
+}
+
+impl SignatureDiff {
+    /// Returns true if both compared signatures are equal.
+    pub fn is_empty(&self) -> bool {
+        self.added_parameters.is_empty()
+            && self.removed_parameters.is_empty()
+            && self.changed_access_patterns.is_empty()
+            && self.added_return_values.is_empty()
+            && self.removed_return_values.is_empty()
+    }
+}
+
+impl FunctionSignature {
+    /// Compute the differences between `self` as the old signature and `other` as the new signature of a function.
+    ///
+    /// Parameters are compared by their locations (register or stack offset), i.e. renaming a parameter
+    /// in the source code does not change the signature.
+    /// Added parameters are listed in the parameter order of the new signature
+    /// and removed and changed parameters in the parameter order of the old signature
+    /// (see [`FunctionSignature::parameters`]).
+    /// Access patterns are only compared by whether the parameter is read, dereferenced or mutably dereferenced,
+    /// i.e. changes in analysis-internal fields like the propagation depth are not reported.
+    pub fn diff(&self, other: &FunctionSignature) -> SignatureDiff {
+        let mut diff = SignatureDiff::default();
+        for (arg, access_pattern) in self.parameters() {
+            match other.parameters.get(arg) {
+                None => diff.removed_parameters.push(arg.clone()),
+                Some(other_access_pattern)
+                    if !has_equal_access_flags(access_pattern, other_access_pattern) =>
+                {
+                    diff.changed_access_patterns.push(AccessPatternChange {
+                        parameter: arg.clone(),
+                        old: *access_pattern,
+                        new: *other_access_pattern,
+                    })
+                }
+                Some(_) => (),
+            }
+        }
+        diff.added_parameters = other
+            .parameters()
+            .into_iter()
+            .filter(|(arg, _)| !self.parameters.contains_key(arg))
+            .map(|(arg, _)| arg.clone())
+            .collect();
+        diff.removed_return_values = self
+            .return_values
+            .iter()
+            .filter(|arg| !other.return_values.contains(arg))
+            .cloned()
+            .collect();
+        diff.added_return_values = other
+            .return_values
+            .iter()
+            .filter(|arg| !self.return_values.contains(arg))
+            .cloned()
+            .collect();
+        diff
+    }
+}
+
+/// Returns true if both access patterns agree in whether the parameter is read, dereferenced or mutably dereferenced.
+fn has_equal_access_flags(pattern: &AccessPattern, other: &AccessPattern) -> bool {
+    pattern.is_read() == other.is_read()
+        && pattern.is_dereferenced() == other.is_dereferenced()
+        && pattern.is_mutably_dereferenced() == other.is_mutably_dereferenced()
+}
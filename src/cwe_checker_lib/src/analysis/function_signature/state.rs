@@ -20,18 +20,38 @@ mod call_handling;
 pub struct State {
     /// Known register values.
     register: DomainMap<Variable, DataDomain<BitvectorDomain>, MergeTopStrategy>,
+    /// Known values spanning two registers, indexed by the `(high, low)` register pair,
+    /// e.g. composite return values of calls.
+    /// An entry is removed as soon as one of its registers is overwritten.
+    register_pairs: DomainMap<(Variable, Variable), DataDomain<BitvectorDomain>, MergeTopStrategy>,
     /// The abstract ID representing the stack of the current function.
     stack_id: AbstractIdentifier,
     /// The content of the current stack frame.
     stack: MemRegion<DataDomain<BitvectorDomain>>,
     /// Maps each tracked ID to an [`AccessPattern`], which tracks known access patterns to the object.
     tracked_ids: DomainMap<AbstractIdentifier, AccessPattern, UnionMergeStrategy>,
+    /// Values of parameters of the current function that are known at all call sites,
+    /// indexed by the abstract IDs of the parameters.
+    /// A known value is either a constant or an exact offset relative to another parameter of the function,
+    /// if both parameters point to the same memory object at all call sites.
+    /// They are kept separate from the register values,
+    /// so that they do not leak into the return values computed for the function.
+    known_arguments: DomainMap<AbstractIdentifier, DataDomain<BitvectorDomain>, MergeTopStrategy>,
+    /// Is set to `true` if the current function may access a variable number of stack parameters,
+    /// e.g. because it walks over its stack parameters like `va_start` and `va_arg` do.
+    is_variadic: bool,
+    /// If provenance tracking is enabled, maps abstract IDs to the TIDs of the call sites
+    /// through whose return values the IDs were introduced into the state.
+    /// Provenance tracking is disabled by default to save memory.
+    provenance: Option<BTreeMap<AbstractIdentifier, BTreeSet<Tid>>>,
 }
 
 impl State {
     /// Generate a new state corresponding to the function start state for the given function TID.
     ///
     /// Only registers that are parameter registers in the given calling convention are added to the tracked IDs.
+    /// The values of the return registers at the function start are also known to the state,
+    /// so that one can detect whether the function writes to them (see [`is_register_unmodified`](Self::is_register_unmodified)).
     pub fn new(
         func_tid: &Tid,
         stack_register: &Variable,
@@ -49,6 +69,14 @@ impl State {
                 tracked_ids.insert(id, AccessPattern::new());
             }
         }
+        // Return registers get their own IDs, but they are not tracked as parameters.
+        for var in calling_convention.get_all_return_register() {
+            if !register_map.contains_key(var) {
+                let id = AbstractIdentifier::new_from_var(func_tid.clone(), var);
+                let value = DataDomain::from_target(id, Bitvector::zero(var.size.into()).into());
+                register_map.insert(var.clone(), value);
+            }
+        }
         // Generate all stack-related objects
         let stack_id = AbstractIdentifier::new_from_var(func_tid.clone(), stack_register);
         let stack_value = DataDomain::from_target(
@@ -60,12 +88,36 @@ impl State {
 
         State {
             register: DomainMap::from(register_map),
+            register_pairs: DomainMap::from(BTreeMap::new()),
             stack_id,
             stack,
             tracked_ids: DomainMap::from(tracked_ids),
+            known_arguments: DomainMap::from(BTreeMap::new()),
+            is_variadic: false,
+            provenance: None,
         }
     }
 
+    /// Stop tracking the values of the given registers at the start of the current function,
+    /// so that accesses to these values are no longer recorded as parameter accesses.
+    pub fn untrack_registers(&mut self, register_names: &[&str]) {
+        let current_fn_tid = self.get_current_function_tid().clone();
+        self.tracked_ids.retain(|id, _| {
+            *id.get_tid() != current_fn_tid
+                || !matches!(id.get_location(), AbstractLocation::Register(var) if register_names.contains(&var.name.as_str()))
+        });
+    }
+
+    /// Returns `true` if the current function may access a variable number of stack parameters.
+    pub fn is_variadic(&self) -> bool {
+        self.is_variadic
+    }
+
+    /// Mark the current function as a function that may access a variable number of stack parameters.
+    pub fn set_variadic_flag(&mut self) {
+        self.is_variadic = true;
+    }
+
     /// Get the value of the given register in the current state.
     pub fn get_register(&self, register: &Variable) -> DataDomain<BitvectorDomain> {
         self.register
@@ -74,8 +126,23 @@ impl State {
             .unwrap_or_else(|| DataDomain::new_top(register.size))
     }
 
+    /// Returns `true` if the value of the given register equals its value at the start of the current function,
+    /// i.e. if the register was not modified by the current function.
+    ///
+    /// Note that this can only be detected for registers whose value at the function start is known to the state,
+    /// i.e. for the parameter and return registers of the calling convention of the function.
+    pub fn is_register_unmodified(&self, register: &Variable) -> bool {
+        let start_value = DataDomain::from_target(
+            AbstractIdentifier::new_from_var(self.get_current_function_tid().clone(), register),
+            Bitvector::zero(register.size.into()).into(),
+        );
+        self.register.get(register) == Some(&start_value)
+    }
+
     /// Set the value of the given register in the current state.
     pub fn set_register(&mut self, register: &Variable, value: DataDomain<BitvectorDomain>) {
+        self.register_pairs
+            .retain(|(high, low), _| high != register && low != register);
         if value.is_top() {
             self.register.remove(register);
         } else {
@@ -83,6 +150,42 @@ impl State {
         }
     }
 
+    /// Get the value spanning the given register pair,
+    /// where the high register contains the most significant bytes of the value.
+    ///
+    /// If no value is known for the pair itself, the value is combined from the values of both registers.
+    pub fn get_register_pair(
+        &self,
+        high_register: &Variable,
+        low_register: &Variable,
+    ) -> DataDomain<BitvectorDomain> {
+        self.register_pairs
+            .get(&(high_register.clone(), low_register.clone()))
+            .cloned()
+            .unwrap_or_else(|| {
+                self.get_register(high_register)
+                    .bin_op(BinOpType::Piece, &self.get_register(low_register))
+            })
+    }
+
+    /// Set the value spanning the given register pair,
+    /// where the high register contains the most significant bytes of the value.
+    ///
+    /// The values of the single registers are not changed by this function.
+    pub fn set_register_pair(
+        &mut self,
+        high_register: &Variable,
+        low_register: &Variable,
+        value: DataDomain<BitvectorDomain>,
+    ) {
+        let key = (high_register.clone(), low_register.clone());
+        if value.is_top() {
+            self.register_pairs.remove(&key);
+        } else {
+            self.register_pairs.insert(key, value);
+        }
+    }
+
     /// Get the TID of the function that this state belongs to.
     pub fn get_current_function_tid(&self) -> &Tid {
         self.stack_id.get_tid()
@@ -91,8 +194,8 @@ impl State {
     /// Load the value at the given address.
     ///
     /// Only constant addresses on the stack are tracked.
-    /// Thus this function will always return a `Top` domain for any address
-    /// that may not be a stack address with constant offset.
+    /// Thus this function will always return a `Top` domain of the given size for any address
+    /// that may not be a stack address with constant offset (including `Top` addresses).
     ///
     /// This function does not set any access flags for input IDs in the address value.
     pub fn load_value(
@@ -207,6 +310,19 @@ impl State {
         }
     }
 
+    /// Set the variadic flag of the state if the given address may point to the stack
+    /// but its offset is not exactly known.
+    ///
+    /// Such accesses happen if a function walks over its stack parameters,
+    /// like `va_arg` does for the variable parameters of variadic functions.
+    pub fn set_variadic_flag_for_stack_walk(&mut self, address: &DataDomain<BitvectorDomain>) {
+        if let Some(offset) = address.get_relative_values().get(&self.stack_id) {
+            if offset.try_to_bitvec().is_err() {
+                self.is_variadic = true;
+            }
+        }
+    }
+
     /// If the address is an exactly known pointer to the stack with a constant offset, then return the offset.
     pub fn get_offset_if_exact_stack_pointer(
         &self,
@@ -220,11 +336,92 @@ impl State {
         None
     }
 
+    /// Get the offset of the current value of the stack register
+    /// relative to its value at the start of the current function.
+    ///
+    /// Returns `None` if the stack register does not exactly point to the stack frame of the current function.
+    /// The returned offset may be `Top` if the stack pointer points to the current stack frame with an unknown offset.
+    pub fn current_stack_offset(&self) -> Option<BitvectorDomain> {
+        let stack_register = self.stack_id.unwrap_register();
+        match self.get_register(stack_register).get_if_unique_target() {
+            Some((target, offset)) if *target == self.stack_id => Some(offset.clone()),
+            _ => None,
+        }
+    }
+
+    /// Get the inferred size of the parameter represented by the given ID,
+    /// i.e. the maximal size with which its value was accessed.
+    ///
+    /// For example, if only the lower 4 bytes of a 64-bit register parameter were accessed,
+    /// then the parameter probably has a 4-byte type like `int` instead of an 8-byte type like `long`.
+    /// Returns `None` if the ID is not tracked or if the parameter was never accessed.
+    pub fn inferred_param_size(&self, id: &AbstractIdentifier) -> Option<ByteSize> {
+        self.tracked_ids
+            .get(id)
+            .filter(|access_pattern| access_pattern.is_accessed())
+            .and_then(|access_pattern| access_pattern.get_max_access_size())
+    }
+
+    /// If the condition is a comparison of a register with a constant for (in)equality,
+    /// then restrict the value of the register to values satisfying the condition
+    /// (if `is_true` is set) or not satisfying the condition (if `is_true` is not set).
+    /// Other conditions are ignored.
+    ///
+    /// If the register value contains relative values,
+    /// then only its absolute component is restricted if the register is known to equal the constant.
+    /// The relative values are kept, since a pointer may still be dereferenced after such a comparison.
+    /// Returns an error if the condition cannot be satisfied.
+    pub fn specialize_by_equality_comparison(
+        &mut self,
+        condition: &Expression,
+        is_true: bool,
+    ) -> Result<(), Error> {
+        if let Expression::BinOp { op, lhs, rhs } = condition {
+            let is_equality = match (op, is_true) {
+                (BinOpType::IntEqual, true) | (BinOpType::IntNotEqual, false) => true,
+                (BinOpType::IntEqual, false) | (BinOpType::IntNotEqual, true) => false,
+                _ => return Ok(()),
+            };
+            let (var, constant) = match (&**lhs, &**rhs) {
+                (Expression::Var(var), Expression::Const(constant))
+                | (Expression::Const(constant), Expression::Var(var))
+                    if var.size == constant.bytesize() =>
+                {
+                    (var, constant)
+                }
+                _ => return Ok(()),
+            };
+            let value = self.get_register(var);
+            let specialized_value = if !is_equality {
+                value.add_not_equal_bound(constant)?
+            } else if value.get_relative_values().is_empty() {
+                value.intersect(&constant.clone().into())?
+            } else {
+                let mut specialized_value = value;
+                let absolute_value = specialized_value
+                    .get_absolute_value()
+                    .and_then(|absolute| absolute.clone().intersect(&constant.clone().into()).ok());
+                specialized_value.set_absolute_value(absolute_value);
+                specialized_value
+            };
+            self.set_register(var, specialized_value);
+        }
+        Ok(())
+    }
+
     /// Evaluate the value of the given expression on the current state.
     pub fn eval(&self, expression: &Expression) -> DataDomain<BitvectorDomain> {
         match expression {
             Expression::Var(var) => self.get_register(var),
             Expression::Const(bitvector) => bitvector.clone().into(),
+            Expression::BinOp {
+                op: BinOpType::Piece,
+                lhs,
+                rhs,
+            } => match (&**lhs, &**rhs) {
+                (Expression::Var(high), Expression::Var(low)) => self.get_register_pair(high, low),
+                _ => self.eval(lhs).bin_op(BinOpType::Piece, &self.eval(rhs)),
+            },
             Expression::BinOp { op, lhs, rhs } => self.eval(lhs).bin_op(*op, &self.eval(rhs)),
             Expression::UnOp { op, arg } => self.eval(arg).un_op(*op),
             Expression::Cast { op, size, arg } => self.eval(arg).cast(*op, *size),
@@ -240,6 +437,82 @@ impl State {
         }
     }
 
+    /// Get the value of the parameter with the given ID if it is known at all call sites.
+    pub fn get_known_argument(
+        &self,
+        param_id: &AbstractIdentifier,
+    ) -> Option<&DataDomain<BitvectorDomain>> {
+        self.known_arguments.get(param_id)
+    }
+
+    /// Set the value of the parameter with the given ID that is known at the call site.
+    ///
+    /// Values that are neither a constant nor an exact offset relative to a unique target are ignored.
+    pub fn set_known_argument(
+        &mut self,
+        param_id: AbstractIdentifier,
+        value: DataDomain<BitvectorDomain>,
+    ) {
+        if is_exact_argument_value(&value) {
+            self.known_arguments.insert(param_id, value);
+        } else {
+            self.known_arguments.remove(&param_id);
+        }
+    }
+
+    /// Evaluate the value of the given expression on the current state,
+    /// where register values that are exactly a parameter plus some offset
+    /// are replaced by the known value of the parameter (see [`get_known_argument`](Self::get_known_argument)).
+    /// Equality comparisons are evaluated through the difference of both sides,
+    /// so that pointers to the same memory object can be compared.
+    ///
+    /// The result must not be written back to the state,
+    /// since the known arguments only hold for the call sites seen so far.
+    pub fn eval_with_known_arguments(
+        &self,
+        expression: &Expression,
+    ) -> DataDomain<BitvectorDomain> {
+        if self.known_arguments.is_empty() {
+            return self.eval(expression);
+        }
+        match expression {
+            Expression::Var(var) => {
+                let value = self.get_register(var);
+                match value.get_if_unique_target() {
+                    Some((param_id, offset)) => match self.get_known_argument(param_id) {
+                        Some(argument) => argument.add_offset(offset),
+                        None => value,
+                    },
+                    None => value,
+                }
+            }
+            Expression::BinOp { op, lhs, rhs }
+                if matches!(op, BinOpType::IntEqual | BinOpType::IntNotEqual) =>
+            {
+                let difference = self
+                    .eval_with_known_arguments(lhs)
+                    .bin_op(BinOpType::IntSub, &self.eval_with_known_arguments(rhs));
+                let zero = Bitvector::zero(difference.bytesize().into()).into();
+                difference.bin_op(*op, &zero)
+            }
+            Expression::BinOp { op, lhs, rhs } => self
+                .eval_with_known_arguments(lhs)
+                .bin_op(*op, &self.eval_with_known_arguments(rhs)),
+            Expression::UnOp { op, arg } => self.eval_with_known_arguments(arg).un_op(*op),
+            Expression::Cast { op, size, arg } => {
+                self.eval_with_known_arguments(arg).cast(*op, *size)
+            }
+            Expression::Subpiece {
+                low_byte,
+                size,
+                arg,
+            } => self
+                .eval_with_known_arguments(arg)
+                .subpiece(*low_byte, *size),
+            Expression::Const(_) | Expression::Unknown { .. } => self.eval(expression),
+        }
+    }
+
     /// Evaluate the value of the given parameter on the current state.
     ///
     /// Note that this may alter the state
@@ -260,59 +533,115 @@ impl State {
         }
     }
 
-    /// If the given expression is not an [`Expression::Var`] set the read flags
-    /// for all IDs that may be referenced when computing the value of the expression.
+    /// If the given expression is not a (partial) copy of a variable (see [`ExpressionKind::Copy`])
+    /// set the read flags for all IDs that may be referenced when computing the value of the expression.
     ///
-    /// [`Expression::Var`] accesses also happen when writing a callee-saved register to the stack.
+    /// Copies of variables also happen when writing a callee-saved register (or one of its sub-registers) to the stack.
     /// This function can be used to prevent accidentially flagging callee-saved registers as input registers.
     pub fn set_read_flag_for_input_ids_of_nontrivial_expression(
         &mut self,
         expression: &Expression,
     ) {
-        match expression {
-            Expression::Var(_) => (),
-            _ => self.set_read_flag_for_input_ids_of_expression(expression),
+        match expression.kind() {
+            ExpressionKind::Copy | ExpressionKind::Constant => (),
+            ExpressionKind::Arithmetic => {
+                self.set_read_flag_for_input_ids_of_expression(expression)
+            }
         }
     }
 
     /// Set the read flag for every ID that may be referenced when computing the value of the expression.
     pub fn set_read_flag_for_input_ids_of_expression(&mut self, expression: &Expression) {
-        for register in expression.input_vars() {
-            for id in self.get_register(register).referenced_ids() {
-                if let Some(object) = self.tracked_ids.get_mut(id) {
-                    object.set_read_flag();
-                }
-            }
-        }
+        self.update_access_patterns_of_input_ids(expression, |object| object.set_read_flag());
+    }
+
+    /// Set the read flag for every ID that may be referenced when computing the value of the expression
+    /// and mark the accesses as accesses by comparisons (see [`AccessPattern::set_compared_flag`]).
+    pub fn set_compared_flag_for_input_ids_of_expression(&mut self, expression: &Expression) {
+        self.update_access_patterns_of_input_ids(expression, |object| object.set_compared_flag());
     }
 
     /// Set the read and dereferenced flag for every ID
     /// that may be referenced when computing the value of the expression.
     pub fn set_deref_flag_for_input_ids_of_expression(&mut self, expression: &Expression) {
-        for register in expression.input_vars() {
-            for id in self.get_register(register).referenced_ids() {
-                if let Some(object) = self.tracked_ids.get_mut(id) {
-                    object.set_read_flag();
-                    object.set_dereference_flag();
-                }
-            }
-        }
+        self.update_access_patterns_of_input_ids(expression, |object| {
+            object.set_read_flag();
+            object.set_dereference_flag();
+        });
     }
 
     /// Set the read and mutably dereferenced flag for every ID
     /// that may be referenced when computing the value of the expression.
     pub fn set_mutable_deref_flag_for_input_ids_of_expression(&mut self, expression: &Expression) {
-        for register in expression.input_vars() {
+        self.update_access_patterns_of_input_ids(expression, |object| {
+            object.set_read_flag();
+            object.set_mutably_dereferenced_flag();
+        });
+    }
+
+    /// Apply the given update function to the access patterns of every ID
+    /// that may be referenced when computing the value of the expression.
+    /// Also record the size with which the registers referencing the IDs are accessed in the expression.
+    fn update_access_patterns_of_input_ids(
+        &mut self,
+        expression: &Expression,
+        update: impl Fn(&mut AccessPattern),
+    ) {
+        for (register, access_size) in get_input_vars_with_access_size(expression) {
             for id in self.get_register(register).referenced_ids() {
                 if let Some(object) = self.tracked_ids.get_mut(id) {
-                    object.set_read_flag();
-                    object.set_mutably_dereferenced_flag();
+                    update(object);
+                    object.add_access_size(access_size);
                 }
             }
         }
     }
 }
 
+/// Return all input variables of the expression together with the size with which they are accessed.
+///
+/// If only the lower bytes of a variable are used (e.g. `EDI` as part of `RDI` on x86),
+/// then the size of the used part of the variable is returned instead of the size of the whole variable.
+fn get_input_vars_with_access_size(expression: &Expression) -> Vec<(&Variable, ByteSize)> {
+    match expression {
+        Expression::Var(var) => vec![(var, var.size)],
+        Expression::Subpiece {
+            low_byte,
+            size,
+            arg,
+        } if *low_byte == ByteSize::new(0) => match arg.as_ref() {
+            Expression::Var(var) => vec![(var, *size)],
+            _ => get_input_vars_with_access_size(arg),
+        },
+        Expression::Const(_) | Expression::Unknown { .. } => Vec::new(),
+        Expression::BinOp { lhs, rhs, .. } => {
+            let mut vars = get_input_vars_with_access_size(lhs);
+            vars.append(&mut get_input_vars_with_access_size(rhs));
+            vars
+        }
+        Expression::UnOp { arg, .. }
+        | Expression::Cast { arg, .. }
+        | Expression::Subpiece { arg, .. } => get_input_vars_with_access_size(arg),
+    }
+}
+
+/// Merge the tracked IDs of two states.
+///
+/// IDs that are only tracked in one of the states (e.g. stack parameters that are only loaded on some paths)
+/// are not accessed on the paths represented by the other state.
+fn merge_tracked_ids(
+    tracked_ids: &DomainMap<AbstractIdentifier, AccessPattern, UnionMergeStrategy>,
+    other_tracked_ids: &DomainMap<AbstractIdentifier, AccessPattern, UnionMergeStrategy>,
+) -> DomainMap<AbstractIdentifier, AccessPattern, UnionMergeStrategy> {
+    let mut merged_ids = tracked_ids.merge(other_tracked_ids);
+    for (id, access_pattern) in merged_ids.iter_mut() {
+        if !tracked_ids.contains_key(id) || !other_tracked_ids.contains_key(id) {
+            access_pattern.unset_accessed_on_all_paths_flag();
+        }
+    }
+    merged_ids
+}
+
 impl AbstractDomain for State {
     /// Merge two states
     fn merge(&self, other: &Self) -> Self {
@@ -320,9 +649,13 @@ impl AbstractDomain for State {
         let stack = self.stack.merge(&other.stack);
         State {
             register: self.register.merge(&other.register),
+            register_pairs: self.register_pairs.merge(&other.register_pairs),
             stack_id,
             stack,
-            tracked_ids: self.tracked_ids.merge(&other.tracked_ids),
+            tracked_ids: merge_tracked_ids(&self.tracked_ids, &other.tracked_ids),
+            known_arguments: merge_known_arguments(&self.known_arguments, &other.known_arguments),
+            is_variadic: self.is_variadic || other.is_variadic,
+            provenance: merge_provenance(&self.provenance, &other.provenance),
         }
     }
 
@@ -332,6 +665,55 @@ impl AbstractDomain for State {
     }
 }
 
+/// Merge two maps of known arguments.
+/// Only arguments that have the same exact value in both maps are known after the merge.
+fn merge_known_arguments(
+    known_arguments: &DomainMap<AbstractIdentifier, DataDomain<BitvectorDomain>, MergeTopStrategy>,
+    other_known_arguments: &DomainMap<
+        AbstractIdentifier,
+        DataDomain<BitvectorDomain>,
+        MergeTopStrategy,
+    >,
+) -> DomainMap<AbstractIdentifier, DataDomain<BitvectorDomain>, MergeTopStrategy> {
+    let mut merged_arguments = known_arguments.merge(other_known_arguments);
+    merged_arguments.retain(|_, value| is_exact_argument_value(value));
+    merged_arguments
+}
+
+/// Returns `true` if the value is either an exact constant
+/// or an exact offset relative to a unique target (without any absolute or `Top` values).
+fn is_exact_argument_value(value: &DataDomain<BitvectorDomain>) -> bool {
+    if let Some(constant) = value.get_if_absolute_value() {
+        constant.try_to_bitvec().is_ok()
+    } else if let Some((_, offset)) = value.get_if_unique_target() {
+        offset.try_to_bitvec().is_ok()
+    } else {
+        false
+    }
+}
+
+/// Merge two provenance maps by taking the union of the call sites for each abstract ID.
+/// The result is `None` only if provenance tracking is disabled for both inputs.
+fn merge_provenance(
+    left: &Option<BTreeMap<AbstractIdentifier, BTreeSet<Tid>>>,
+    right: &Option<BTreeMap<AbstractIdentifier, BTreeSet<Tid>>>,
+) -> Option<BTreeMap<AbstractIdentifier, BTreeSet<Tid>>> {
+    match (left, right) {
+        (None, None) => None,
+        (Some(provenance), None) | (None, Some(provenance)) => Some(provenance.clone()),
+        (Some(left), Some(right)) => {
+            let mut merged = left.clone();
+            for (id, call_sites) in right {
+                merged
+                    .entry(id.clone())
+                    .or_default()
+                    .extend(call_sites.iter().cloned());
+            }
+            Some(merged)
+        }
+    }
+}
+
 impl State {
     /// Generate a compact JSON-representation of the state for pretty printing.
     #[allow(dead_code)]
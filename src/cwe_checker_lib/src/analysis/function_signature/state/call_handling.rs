@@ -5,12 +5,17 @@ impl State {
     ///
     /// Marks every possible input ID as accessed and writes to every return register a value
     /// that may point to any of the input IDs.
+    /// If the extern symbol is variadic, values on the stack behind its known parameters are marked as read,
+    /// since they may be variable parameters of the call.
     pub fn handle_extern_symbol(
         &mut self,
         call: &Term<Jmp>,
         extern_symbol: &ExternSymbol,
         calling_convention: &CallingConvention,
     ) {
+        if extern_symbol.has_var_args {
+            self.set_read_flag_for_trailing_stack_args(&extern_symbol.parameters);
+        }
         let input_ids = self.collect_input_ids_of_call(&extern_symbol.parameters);
         self.clear_non_callee_saved_register(&calling_convention.callee_saved_register);
         self.generate_return_values_for_call(&input_ids, &extern_symbol.return_values, &call.tid);
@@ -83,6 +88,8 @@ impl State {
     /// Delete the content of all non-callee-saved registers from the state.
     pub fn clear_non_callee_saved_register(&mut self, callee_saved: &[Variable]) {
         self.register.retain(|var, _| callee_saved.contains(var));
+        self.register_pairs
+            .retain(|(high, low), _| callee_saved.contains(high) && callee_saved.contains(low));
     }
 
     /// Fill every return register that might be a pointer with a value that may point to any input ID
@@ -112,7 +119,7 @@ impl State {
             {
                 if var.size == generic_pointer_size {
                     let specific_target = DataDomain::from_target(
-                        AbstractIdentifier::new_from_var(call_tid.clone(), var),
+                        AbstractIdentifier::new_return_value(call_tid.clone(), var),
                         Bitvector::zero(var.size.into()).into(),
                     );
                     let output = generic_output.merge(&specific_target);
@@ -122,6 +129,41 @@ impl State {
         }
     }
 
+    /// Return the abstract IDs of all possible parameters of the current function tracked by the state,
+    /// regardless of whether an access to them was recorded yet.
+    ///
+    /// Use [`get_arg_corresponding_to_id`](Self::get_arg_corresponding_to_id)
+    /// to get the argument corresponding to a parameter ID.
+    pub fn parameter_ids(&self) -> BTreeSet<AbstractIdentifier> {
+        self.tracked_ids
+            .keys()
+            .filter(|id| id.get_tid() == self.get_current_function_tid())
+            .cloned()
+            .collect()
+    }
+
+    /// Return the abstract IDs tracked by the state whose values were read,
+    /// i.e. used to compute some nontrivial value, stored in some location or dereferenced.
+    ///
+    /// This can be used to seed taint at the function start for the parameters that the function consumes.
+    pub fn read_ids(&self) -> BTreeSet<AbstractIdentifier> {
+        self.tracked_ids
+            .iter()
+            .filter(|(_, access_pattern)| access_pattern.is_read())
+            .map(|(id, _)| id.clone())
+            .collect()
+    }
+
+    /// Return the abstract IDs tracked by the state
+    /// that were used as pointers for writing to the pointed-to memory.
+    pub fn written_through_ids(&self) -> BTreeSet<AbstractIdentifier> {
+        self.tracked_ids
+            .iter()
+            .filter(|(_, access_pattern)| access_pattern.is_mutably_dereferenced())
+            .map(|(id, _)| id.clone())
+            .collect()
+    }
+
     /// Return a list of parameter arguments and their associated object signatures for the current state.
     ///
     /// A register (or stack position with positive offset) is considered a parameter
@@ -135,8 +177,9 @@ impl State {
                 } else if matches!(id.get_location(), &AbstractLocation::Pointer { .. }) {
                     // This is a stack parameter.
                     // If it was only loaded into a register but otherwise not used, then the read-flag needs to be set.
+                    // Since the load may have happened only on some paths, the paths of the access are not changed.
                     let mut access_pattern = *access_pattern;
-                    access_pattern.set_read_flag();
+                    access_pattern.set_read_flag_on_unknown_paths();
                     params.push((generate_arg_from_abstract_id(id), access_pattern));
                 }
             }
@@ -150,21 +193,43 @@ impl State {
     /// If a parameter is a pointer to the stack frame of self, it is dereferenced
     /// to set the access patterns of the target.
     /// Note that this may create new stack parameter objects for self.
-    pub fn merge_parameter_access(&mut self, params: &[(Arg, AccessPattern)]) {
+    ///
+    /// If `trailing_stack_args_may_be_read` is set (e.g. because the callee is variadic),
+    /// then values on the stack behind the known stack parameters of the callee are marked as read,
+    /// since the callee may access them as variable parameters.
+    ///
+    /// Callee parameters referencing registers that are not tracked as parameters by `self`
+    /// (e.g. the registers returned by [`get_non_parameter_registers`](super::super::get_non_parameter_registers))
+    /// do not add parameters to `self`.
+    pub fn merge_parameter_access(
+        &mut self,
+        params: &[(Arg, AccessPattern)],
+        trailing_stack_args_may_be_read: bool,
+    ) {
+        if trailing_stack_args_may_be_read {
+            let args: Vec<Arg> = params.iter().map(|(arg, _)| arg.clone()).collect();
+            self.set_read_flag_for_trailing_stack_args(&args);
+        }
         for (parameter, call_access_pattern) in params {
             for (id, offset) in self.eval_parameter_arg(parameter).get_relative_values() {
                 if let Some(object) = self.tracked_ids.get_mut(id) {
-                    *object = object.merge(call_access_pattern);
+                    *object =
+                        object.merge_on_same_paths(&call_access_pattern.propagated_through_call());
                 }
                 if *id == self.stack_id && call_access_pattern.is_dereferenced() {
                     if let Ok(offset) = offset.try_to_bitvec() {
                         // We also have to dereference the stack pointer and set the access flags of the pointed-to value
                         let value = self.load_unsized_value_from_stack(offset.clone());
+                        // Since we do not know whether the value itself was also dereferenced in the callee,
+                        // we have to assume some unknown access to the value.
+                        let unknown_access = AccessPattern::new_unknown_access(
+                            call_access_pattern
+                                .get_propagation_depth()
+                                .map_or(1, |depth| depth + 1),
+                        );
                         for id in value.referenced_ids() {
                             if let Some(object) = self.tracked_ids.get_mut(id) {
-                                // Since we do not know whether the value itself was also dereferenced in the callee,
-                                // we have to assume some unknown access to the value.
-                                object.set_unknown_access_flags();
+                                *object = object.merge_on_same_paths(&unknown_access);
                             }
                         }
                     }
@@ -183,6 +248,42 @@ impl State {
         }
     }
 
+    /// Set the read flag for all IDs referenced by values on the stack that may be passed as stack parameters to a callee,
+    /// i.e. values above the current stack pointer that are located behind all known stack parameters of the callee.
+    fn set_read_flag_for_trailing_stack_args(&mut self, known_params: &[Arg]) {
+        let stack_register = self.stack_id.unwrap_register().clone();
+        let stack_pointer = self.get_register(&stack_register);
+        let mut trailing_args_start = match self
+            .get_offset_if_exact_stack_pointer(&stack_pointer)
+            .and_then(|offset| offset.try_to_i64().ok())
+        {
+            Some(offset) => offset,
+            None => return,
+        };
+        for param in known_params {
+            if let Arg::Stack { address, size, .. } = param {
+                if let Some(Ok(offset)) = self
+                    .get_offset_if_exact_stack_pointer(&self.eval(address))
+                    .map(|offset| offset.try_to_i64())
+                {
+                    trailing_args_start =
+                        std::cmp::max(trailing_args_start, offset + u64::from(*size) as i64);
+                }
+            }
+        }
+        let referenced_ids: Vec<AbstractIdentifier> = self
+            .stack
+            .iter()
+            .filter(|(offset, _)| **offset >= trailing_args_start)
+            .flat_map(|(_, value)| value.referenced_ids().cloned())
+            .collect();
+        for id in referenced_ids {
+            if let Some(object) = self.tracked_ids.get_mut(&id) {
+                object.set_read_flag();
+            }
+        }
+    }
+
     /// If the given abstract ID represents a possible parameter of the current function
     /// then return an argument object corresponding to the parameter.
     pub fn get_arg_corresponding_to_id(&self, id: &AbstractIdentifier) -> Option<Arg> {
@@ -192,6 +293,51 @@ impl State {
             None
         }
     }
+
+    /// Enable the tracking of the call sites whose return values introduced abstract IDs into the state.
+    ///
+    /// See [`get_provenance`](Self::get_provenance) for more information.
+    pub fn enable_provenance_tracking(&mut self) {
+        if self.provenance.is_none() {
+            self.provenance = Some(BTreeMap::new());
+        }
+    }
+
+    /// Returns true if provenance tracking is enabled for the state.
+    pub fn is_provenance_tracking_enabled(&self) -> bool {
+        self.provenance.is_some()
+    }
+
+    /// Record that the relative targets of the given value were introduced into the state
+    /// through the return value of the call with the given TID.
+    /// The call sites already recorded for the corresponding `callee_id` in the callee state are also added,
+    /// so that the provenance survives nested calls.
+    ///
+    /// Does nothing if provenance tracking is disabled.
+    pub fn add_provenance_of_return_value(
+        &mut self,
+        value: &DataDomain<BitvectorDomain>,
+        call_tid: &Tid,
+        callee_provenance: Option<&BTreeSet<Tid>>,
+    ) {
+        if let Some(provenance) = self.provenance.as_mut() {
+            for id in value.get_relative_values().keys() {
+                let call_sites = provenance.entry(id.clone()).or_default();
+                call_sites.insert(call_tid.clone());
+                if let Some(callee_call_sites) = callee_provenance {
+                    call_sites.extend(callee_call_sites.iter().cloned());
+                }
+            }
+        }
+    }
+
+    /// Get the TIDs of all call sites through whose return values the given abstract ID was introduced into the state.
+    ///
+    /// Returns `None` if provenance tracking is disabled
+    /// or if the ID was not introduced through the return value of any call.
+    pub fn get_provenance(&self, id: &AbstractIdentifier) -> Option<&BTreeSet<Tid>> {
+        self.provenance.as_ref()?.get(id)
+    }
 }
 
 /// Generate register arguments from a list of registers.
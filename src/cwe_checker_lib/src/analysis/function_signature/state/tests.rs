@@ -1,4 +1,6 @@
 use super::*;
+use crate::analysis::function_signature::PointerAccess;
+use std::collections::HashMap;
 
 impl State {
     /// Generate a mock state for an ARM-32 state.
@@ -151,7 +153,7 @@ fn test_extern_symbol_handling() {
     };
     let param_id = AbstractIdentifier::new_from_var(Tid::new("mock_fn"), &Variable::mock("r0", 4));
     let return_val_id =
-        AbstractIdentifier::new_from_var(Tid::new("call_tid"), &Variable::mock("r0", 4));
+        AbstractIdentifier::new_return_value(Tid::new("call_tid"), &Variable::mock("r0", 4));
     // Test extern symbol handling.
     state.handle_extern_symbol(&call, &extern_symbol, &cconv);
     assert_eq!(
@@ -180,3 +182,351 @@ fn test_extern_symbol_handling() {
         &Bitvector::from_i32(0).into()
     );
 }
+
+#[test]
+fn test_variadic_call_handling() {
+    let mut state = State::mock();
+    let mut extern_symbol = ExternSymbol::mock_arm32();
+    extern_symbol.has_var_args = true;
+    let cconv = CallingConvention::mock_arm32();
+    let call = Term {
+        tid: Tid::new("call_tid"),
+        term: Jmp::Call {
+            target: extern_symbol.tid.clone(),
+            return_: Some(Tid::new("return_tid")),
+        },
+    };
+    let param_id = AbstractIdentifier::new_from_var(Tid::new("mock_fn"), &Variable::mock("r1", 4));
+    // The value of r1 is passed as a variable parameter on the stack.
+    state.write_value(
+        DataDomain::from_target(mock_stack_id(), Bitvector::from_i32(-8).into()),
+        state.get_register(&Variable::mock("r1", 4)),
+    );
+    state.set_register(
+        &Variable::mock("sp", 4),
+        DataDomain::from_target(mock_stack_id(), Bitvector::from_i32(-8).into()),
+    );
+    state.handle_extern_symbol(&call, &extern_symbol, &cconv);
+    assert!(state.tracked_ids.get(&param_id).unwrap().is_accessed());
+    assert!(!state.is_variadic());
+}
+
+#[test]
+fn test_variadic_flag_for_stack_walk() {
+    let mut state = State::mock();
+    state.set_variadic_flag_for_stack_walk(&DataDomain::from_target(
+        mock_stack_id(),
+        Bitvector::from_i32(4).into(),
+    ));
+    assert!(!state.is_variadic());
+    state.set_variadic_flag_for_stack_walk(&DataDomain::from_target(
+        mock_stack_id(),
+        BitvectorDomain::new_top(ByteSize::new(4)),
+    ));
+    assert!(state.is_variadic());
+    // The flag is kept when merging states.
+    assert!(State::mock().merge(&state).is_variadic());
+}
+
+#[test]
+fn test_current_stack_offset() {
+    let mut state = State::mock();
+    let sp = Variable::mock("sp", 4);
+    assert_eq!(
+        state.current_stack_offset(),
+        Some(Bitvector::from_i32(0).into())
+    );
+    state.set_register(
+        &sp,
+        DataDomain::from_target(mock_stack_id(), Bitvector::from_i32(-16).into()),
+    );
+    assert_eq!(
+        state.current_stack_offset(),
+        Some(Bitvector::from_i32(-16).into())
+    );
+    state.set_register(&sp, DataDomain::new_top(ByteSize::new(4)));
+    assert_eq!(state.current_stack_offset(), None);
+}
+
+#[test]
+fn test_read_flag_for_nontrivial_expressions() {
+    let mut state = State::mock();
+    let r0 = Expression::var("r0", 4);
+    let r0_arg = Arg::from_var(Variable::mock("r0", 4), None);
+    // Copies of a register (or of a part of it) do not set the read flag.
+    state.set_read_flag_for_input_ids_of_nontrivial_expression(&r0);
+    state.set_read_flag_for_input_ids_of_nontrivial_expression(
+        &r0.clone().subpiece(ByteSize::new(0), ByteSize::new(2)),
+    );
+    assert!(state.get_params_of_current_function().is_empty());
+    state.set_read_flag_for_input_ids_of_nontrivial_expression(&r0.plus_const(1));
+    let params: HashMap<_, _> = state.get_params_of_current_function().into_iter().collect();
+    assert_eq!(
+        params[&r0_arg].get_pointer_access(),
+        PointerAccess::NotDereferenced
+    );
+}
+
+#[test]
+fn test_pointer_access_of_params() {
+    let mut state = State::mock();
+    let r0 = Variable::mock("r0", 4);
+    let r1 = Variable::mock("r1", 4);
+    let r2 = Variable::mock("r2", 4);
+    // r0 is only read from, r1 is read from and written to, r2 is only used as a value.
+    state.set_deref_flag_for_input_ids_of_expression(&Expression::Var(r0.clone()));
+    state.set_deref_flag_for_input_ids_of_expression(&Expression::Var(r1.clone()));
+    state.set_mutable_deref_flag_for_input_ids_of_expression(&Expression::Var(r1.clone()));
+    state.set_read_flag_for_input_ids_of_expression(&Expression::Var(r2.clone()));
+
+    let params: HashMap<_, _> = state.get_params_of_current_function().into_iter().collect();
+    assert_eq!(params.len(), 3);
+    assert_eq!(
+        params[&Arg::from_var(r0, None)].get_pointer_access(),
+        PointerAccess::ReadOnly
+    );
+    assert_eq!(
+        params[&Arg::from_var(r1, None)].get_pointer_access(),
+        PointerAccess::Mutated
+    );
+    assert_eq!(
+        params[&Arg::from_var(r2, None)].get_pointer_access(),
+        PointerAccess::NotDereferenced
+    );
+    assert!(!params.contains_key(&Arg::from_var(Variable::mock("r3", 4), None)));
+}
+
+#[test]
+fn test_specialize_by_equality_comparison() {
+    let mut state = State::mock_x64("mock_fn");
+    let rdi = Variable::mock("RDI", 8);
+    let rsi = Variable::mock("RSI", 8);
+    let param_rdi_value = state.get_register(&rdi);
+    let eq_condition = Expression::BinOp {
+        op: BinOpType::IntEqual,
+        lhs: Box::new(Expression::Var(rdi.clone())),
+        rhs: Box::new(Expression::const_from_i64(0)),
+    };
+    let neq_condition = Expression::BinOp {
+        op: BinOpType::IntNotEqual,
+        lhs: Box::new(Expression::const_from_i64(0)),
+        rhs: Box::new(Expression::Var(rdi.clone())),
+    };
+    // Relative values are kept on the equality branch.
+    let mut new_state = state.clone();
+    new_state
+        .specialize_by_equality_comparison(&eq_condition, true)
+        .unwrap();
+    assert_eq!(new_state.get_register(&rdi), param_rdi_value);
+    let mut new_state = state.clone();
+    new_state
+        .specialize_by_equality_comparison(&neq_condition, false)
+        .unwrap();
+    assert_eq!(new_state.get_register(&rdi), param_rdi_value);
+    // Dereferencing the register after the comparison is still an access to the parameter.
+    new_state.set_deref_flag_for_input_ids_of_expression(&Expression::Var(rdi.clone()));
+    let params: HashMap<_, _> = new_state
+        .get_params_of_current_function()
+        .into_iter()
+        .collect();
+    assert_eq!(
+        params[&Arg::from_var(rdi.clone(), None)].get_pointer_access(),
+        PointerAccess::ReadOnly
+    );
+    // Only the absolute component is restricted on the equality branch.
+    let mut mixed_value = param_rdi_value.clone();
+    mixed_value.set_absolute_value(Some(BitvectorDomain::new_top(ByteSize::new(8))));
+    let mut new_state = state.clone();
+    new_state.set_register(&rdi, mixed_value);
+    new_state
+        .specialize_by_equality_comparison(&eq_condition, true)
+        .unwrap();
+    let mut expected_value = param_rdi_value.clone();
+    expected_value.set_absolute_value(Some(Bitvector::from_i64(0).into()));
+    assert_eq!(new_state.get_register(&rdi), expected_value);
+    // Relative values are not changed on the inequality branch.
+    let mut new_state = state.clone();
+    new_state
+        .specialize_by_equality_comparison(&eq_condition, false)
+        .unwrap();
+    assert_eq!(new_state.get_register(&rdi), param_rdi_value);
+    // Absolute values get restricted by the condition.
+    state.set_register(&rdi, Bitvector::from_i64(0).into());
+    assert!(state
+        .clone()
+        .specialize_by_equality_comparison(&eq_condition, false)
+        .is_err());
+    assert!(state
+        .clone()
+        .specialize_by_equality_comparison(&eq_condition, true)
+        .is_ok());
+    state.set_register(&rdi, Bitvector::from_i64(5).into());
+    assert!(state
+        .clone()
+        .specialize_by_equality_comparison(&eq_condition, true)
+        .is_err());
+    // Conditions with other structures are ignored.
+    let other_condition = Expression::BinOp {
+        op: BinOpType::IntEqual,
+        lhs: Box::new(Expression::Var(rdi.clone())),
+        rhs: Box::new(Expression::Var(rsi)),
+    };
+    let mut new_state = state.clone();
+    new_state
+        .specialize_by_equality_comparison(&other_condition, true)
+        .unwrap();
+    assert_eq!(new_state, state);
+}
+
+#[test]
+fn test_stack_params_read_on_entry() {
+    let mut state = State::mock_x64("mock_fn");
+    let rsp = Variable::mock("RSP", 8);
+    let stack_arg = |offset: i64| Arg::Stack {
+        address: Expression::Var(rsp.clone()).plus_const(offset),
+        size: ByteSize::new(8),
+        data_type: None,
+    };
+    // Read `[rsp+8]` and `[rsp+16]` on function entry.
+    for offset in [8, 16] {
+        let address = state.eval(&Expression::Var(rsp.clone()).plus_const(offset));
+        let value = state.load_value(address, ByteSize::new(8));
+        state.set_register(&Variable::mock("RAX", 8), value);
+    }
+    let params = state.get_params_of_current_function();
+    assert_eq!(params.len(), 2);
+    for offset in [8, 16] {
+        let (_, access_pattern) = params
+            .iter()
+            .find(|(arg, _)| *arg == stack_arg(offset))
+            .unwrap();
+        assert!(access_pattern.is_accessed());
+    }
+
+    // The stack parameters of the callee are also parameters of a caller
+    // that forwards its own stack parameters to the callee.
+    let mut caller_state = State::mock_x64("caller");
+    caller_state.merge_parameter_access(&params, false);
+    let caller_params = caller_state.get_params_of_current_function();
+    assert_eq!(caller_params.len(), 2);
+    for offset in [8, 16] {
+        assert!(caller_params
+            .iter()
+            .any(|(arg, _)| *arg == stack_arg(offset)));
+    }
+}
+
+#[test]
+fn test_inferred_param_size() {
+    let mut state = State::mock_x64("callee");
+    let rdi_id = AbstractIdentifier::new_from_var(Tid::new("callee"), &Variable::mock("RDI", 8));
+    let rsi_id = AbstractIdentifier::new_from_var(Tid::new("callee"), &Variable::mock("RSI", 8));
+    let unknown_id =
+        AbstractIdentifier::new_from_var(Tid::new("callee"), &Variable::mock("RBX", 8));
+    state.set_read_flag_for_input_ids_of_expression(&Expression::Subpiece {
+        low_byte: ByteSize::new(0),
+        size: ByteSize::new(4),
+        arg: Box::new(Expression::Var(Variable::mock("RDI", 8))),
+    });
+    assert_eq!(state.inferred_param_size(&rdi_id), Some(ByteSize::new(4)));
+    // Parameters that were never accessed have no inferred size.
+    assert_eq!(state.inferred_param_size(&rsi_id), None);
+    assert_eq!(state.inferred_param_size(&unknown_id), None);
+}
+
+#[test]
+fn test_merge_parameter_access_sizes() {
+    let rdi = Variable::mock("RDI", 8);
+    let rdi_arg = Arg::from_var(rdi.clone(), None);
+    // One path of the callee reads only the lower 4 bytes of RDI, the other path reads all 8 bytes.
+    let mut callee_path_one = State::mock_x64("callee");
+    callee_path_one.set_read_flag_for_input_ids_of_expression(&Expression::Subpiece {
+        low_byte: ByteSize::new(0),
+        size: ByteSize::new(4),
+        arg: Box::new(Expression::Var(rdi.clone())),
+    });
+    let mut callee_path_two = State::mock_x64("callee");
+    callee_path_two.set_read_flag_for_input_ids_of_expression(&Expression::Var(rdi.clone()));
+    let access_size_of_rdi = |state: &State| {
+        state
+            .get_params_of_current_function()
+            .into_iter()
+            .find(|(arg, _)| *arg == rdi_arg)
+            .unwrap()
+            .1
+            .get_max_access_size()
+    };
+    assert_eq!(access_size_of_rdi(&callee_path_one), Some(ByteSize::new(4)));
+    assert_eq!(access_size_of_rdi(&callee_path_two), Some(ByteSize::new(8)));
+
+    // Merging both paths keeps the larger access size.
+    let callee_state = callee_path_one.merge(&callee_path_two);
+    assert_eq!(access_size_of_rdi(&callee_state), Some(ByteSize::new(8)));
+    assert_eq!(
+        access_size_of_rdi(&callee_path_two.merge(&callee_path_one)),
+        Some(ByteSize::new(8))
+    );
+
+    // The access size is forwarded to the caller.
+    let mut caller_state = State::mock_x64("caller");
+    caller_state.merge_parameter_access(&callee_state.get_params_of_current_function(), false);
+    assert_eq!(access_size_of_rdi(&caller_state), Some(ByteSize::new(8)));
+}
+
+#[test]
+fn test_parameter_ids() {
+    let mut state = State::mock();
+    let extern_symbol = ExternSymbol::mock_arm32();
+    let cconv = CallingConvention::mock_arm32();
+    let call = Term {
+        tid: Tid::new("call_tid"),
+        term: Jmp::Call {
+            target: extern_symbol.tid.clone(),
+            return_: Some(Tid::new("return_tid")),
+        },
+    };
+    let register_param_ids: BTreeSet<AbstractIdentifier> =
+        CallingConvention::mock_standard_arm_32()
+            .get_all_parameter_register()
+            .into_iter()
+            .map(|var| AbstractIdentifier::new_from_var(Tid::new("mock_fn"), var))
+            .collect();
+    assert_eq!(state.parameter_ids(), register_param_ids);
+
+    // Stack parameters are added when they are accessed.
+    let address = state.eval(&Expression::Var(Variable::mock("sp", 4)).plus_const(4));
+    state.load_value(address, ByteSize::new(4));
+    let param_ids = state.parameter_ids();
+    assert_eq!(param_ids.len(), register_param_ids.len() + 1);
+    assert!(param_ids.contains(&mock_stack_param_id(4, 4)));
+    for id in param_ids.iter() {
+        assert!(state.get_arg_corresponding_to_id(id).is_some());
+    }
+
+    // Return values of calls are not parameters.
+    state.handle_extern_symbol(&call, &extern_symbol, &cconv);
+    assert_eq!(state.parameter_ids(), param_ids);
+}
+
+#[test]
+fn test_read_and_written_through_ids() {
+    let mut state = State::mock();
+    let id = |name: &str| {
+        AbstractIdentifier::new_from_var(Tid::new("mock_fn"), &Variable::mock(name, 4))
+    };
+    assert!(state.read_ids().is_empty());
+    assert!(state.written_through_ids().is_empty());
+    // r0 is dereferenced for reading, r1 is written through and r2 is only used as a value.
+    state.set_deref_flag_for_input_ids_of_expression(&Expression::Var(Variable::mock("r0", 4)));
+    state.set_mutable_deref_flag_for_input_ids_of_expression(&Expression::Var(Variable::mock(
+        "r1", 4,
+    )));
+    state.set_read_flag_for_input_ids_of_expression(&Expression::Var(Variable::mock("r2", 4)));
+    // Dereferencing a pointer also reads its value.
+    assert_eq!(
+        state.read_ids(),
+        BTreeSet::from([id("r0"), id("r1"), id("r2")])
+    );
+    assert_eq!(state.written_through_ids(), BTreeSet::from([id("r1")]));
+    assert!(!state.read_ids().contains(&id("r3")));
+}
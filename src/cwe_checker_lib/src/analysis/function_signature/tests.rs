@@ -0,0 +1,1177 @@
+use super::*;
+use crate::abstract_domain::AbstractIdentifier;
+
+#[test]
+fn test_serialize_to_json() {
+    let mut project = Project::mock_x64();
+    let sub = Sub::mock("func");
+    project.program.term.subs.insert(sub.tid.clone(), sub);
+
+    let mut read_pattern = AccessPattern::new();
+    read_pattern.set_read_flag();
+    let mut deref_pattern = read_pattern;
+    deref_pattern.set_mutably_dereferenced_flag();
+    let mut fn_sig = FunctionSignature::new();
+    fn_sig.parameters.insert(
+        Arg::Stack {
+            address: Expression::Var(Variable::mock("RSP", 8)).plus_const(8),
+            size: ByteSize::new(8),
+            data_type: None,
+        },
+        read_pattern,
+    );
+    fn_sig
+        .parameters
+        .insert(Arg::from_var(Variable::mock("RSI", 8), None), deref_pattern);
+    fn_sig
+        .parameters
+        .insert(Arg::from_var(Variable::mock("RDI", 8), None), read_pattern);
+    let rax_arg = Arg::from_var(Variable::mock("RAX", 8), None);
+    let zmm0_arg = Arg::Register {
+        expr: Expression::Var(Variable::mock("ZMM0", 64))
+            .subpiece(ByteSize::new(0), ByteSize::new(8)),
+        data_type: None,
+    };
+    fn_sig.return_values = vec![rax_arg.clone(), zmm0_arg];
+    fn_sig.result_values = vec![rax_arg];
+    let fn_sigs = BTreeMap::from([(Tid::new("func"), fn_sig)]);
+
+    let expected = serde_json::json!({
+        "schema_version": JSON_SCHEMA_VERSION,
+        "functions": {
+            "func": {
+                "name": "func",
+                "parameters": [
+                    {
+                        "kind": "register",
+                        "register": "RDI",
+                        "size": 8,
+                        "access_pattern": {"read": true, "dereferenced": false, "mutably_dereferenced": false},
+                    },
+                    {
+                        "kind": "register",
+                        "register": "RSI",
+                        "size": 8,
+                        "access_pattern": {"read": true, "dereferenced": false, "mutably_dereferenced": true},
+                    },
+                    {
+                        "kind": "stack",
+                        "offset": 8,
+                        "size": 8,
+                        "access_pattern": {"read": true, "dereferenced": false, "mutably_dereferenced": false},
+                    },
+                ],
+                "return_values": [
+                    {"register": "RAX", "size": 8},
+                    {"register": "ZMM0", "size": 8},
+                ],
+                "result_values": [{"register": "RAX", "size": 8}],
+            }
+        }
+    });
+    assert_eq!(serialize_to_json(&project, &fn_sigs), expected);
+}
+
+#[test]
+fn test_json_round_trip_of_return_values() {
+    let mut project = Project::mock_x64();
+    let sub = Sub::mock("func");
+    project.program.term.subs.insert(sub.tid.clone(), sub);
+    let mut fn_sig = FunctionSignature::new();
+    let rax_arg = Arg::from_var(Variable::mock("RAX", 8), None);
+    let rdx_rax_arg = Arg::Register {
+        expr: Expression::BinOp {
+            op: BinOpType::Piece,
+            lhs: Box::new(Expression::Var(Variable::mock("RDX", 8))),
+            rhs: Box::new(Expression::Var(Variable::mock("RAX", 8))),
+        },
+        data_type: None,
+    };
+    fn_sig.return_values = vec![rax_arg, rdx_rax_arg.clone()];
+    fn_sig.result_values = vec![rdx_rax_arg];
+    let fn_sigs = BTreeMap::from([(Tid::new("func"), fn_sig)]);
+
+    let json_string = serde_json::to_string(&serialize_to_json(&project, &fn_sigs)).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&json_string).unwrap();
+    // Changes to the format pinned here require increasing the schema version.
+    assert_eq!(json["schema_version"], 1);
+    assert_eq!(
+        json["functions"]["func"]["return_values"],
+        serde_json::json!([
+            {"register": "RAX", "size": 8},
+            {"register": "RDX:RAX", "size": 16},
+        ])
+    );
+    assert_eq!(
+        json["functions"]["func"]["result_values"],
+        serde_json::json!([{"register": "RDX:RAX", "size": 16}])
+    );
+}
+
+#[test]
+fn test_sanitize_removes_return_address_accesses() {
+    let project = Project::mock_empty();
+    let stack_arg = |offset: i64, size: u64| Arg::Stack {
+        address: Expression::Var(Variable::mock("RSP", 8)).plus_const(offset),
+        size: ByteSize::new(size),
+        data_type: None,
+    };
+    let mut fn_sig = FunctionSignature::new();
+    for arg in [
+        stack_arg(0, 8),
+        stack_arg(4, 4),
+        stack_arg(8, 8),
+        stack_arg(16, 8),
+    ] {
+        fn_sig.parameters.insert(arg, AccessPattern::new());
+    }
+    fn_sig.sanitize(&project).unwrap();
+    assert_eq!(fn_sig.parameters.len(), 2);
+    assert!(fn_sig.parameters.contains_key(&stack_arg(8, 8)));
+    assert!(fn_sig.parameters.contains_key(&stack_arg(16, 8)));
+    // Accesses below the return address are not removed, but reported as errors.
+    fn_sig
+        .parameters
+        .insert(stack_arg(-8, 8), AccessPattern::new());
+    assert!(fn_sig.sanitize(&project).is_err());
+    assert!(fn_sig.parameters.contains_key(&stack_arg(-8, 8)));
+}
+
+#[test]
+fn test_function_signature_from_state() {
+    let mut project = Project::mock_x64();
+    let sub = Sub::mock("func");
+    project.program.term.subs.insert(sub.tid.clone(), sub);
+    let cconv = project.get_standard_calling_convention().unwrap();
+    let mut state = State::new(&Tid::new("func"), &project.stack_pointer_register, cconv);
+    state.set_read_flag_for_input_ids_of_expression(&Expression::Var(Variable::mock("RCX", 8)));
+    state.set_mutable_deref_flag_for_input_ids_of_expression(&Expression::Var(Variable::mock(
+        "RSI", 8,
+    )));
+    state.set_read_flag_for_input_ids_of_expression(&Expression::Var(Variable::mock("RDI", 8)));
+    // The function never touches its return registers.
+    assert!(FunctionSignature::from_state(&state, &project).returns_void());
+    state.set_register(&Variable::mock("RAX", 8), Bitvector::from_u64(0).into());
+
+    let fn_sig = FunctionSignature::from_state(&state, &project);
+    // The parameters are ordered as in the calling convention.
+    let params: Vec<Arg> = fn_sig
+        .parameters()
+        .into_iter()
+        .map(|(arg, _)| arg.clone())
+        .collect();
+    assert_eq!(
+        params,
+        vec![
+            Arg::from_var(Variable::mock("RDI", 8), None),
+            Arg::from_var(Variable::mock("RSI", 8), None),
+            Arg::from_var(Variable::mock("RCX", 8), None),
+        ]
+    );
+    assert!(fn_sig.reads_param(0));
+    assert!(!fn_sig.writes_through_param(0));
+    assert!(fn_sig.reads_param(1));
+    assert!(fn_sig.writes_through_param(1));
+    assert!(fn_sig.reads_param(2));
+    assert!(!fn_sig.reads_param(3));
+    assert!(!fn_sig.writes_through_param(3));
+    // Only the modified return register is a return value.
+    assert!(!fn_sig.returns_void());
+    assert_eq!(
+        fn_sig.return_values(),
+        &[Arg::from_var(Variable::mock("RAX", 8), None)]
+    );
+}
+
+#[test]
+fn test_non_parameter_registers() {
+    let mut project = Project::mock_arm32();
+    for cconv in project.calling_conventions.values_mut() {
+        cconv
+            .integer_parameter_register
+            .push(Variable::mock("r9", 4));
+    }
+    let sub = Sub::mock("func");
+    let mut state = generate_entry_state(&project, &sub);
+    state.set_read_flag_for_input_ids_of_expression(&Expression::Var(Variable::mock("r0", 4)));
+    state.set_read_flag_for_input_ids_of_expression(&Expression::Var(Variable::mock("r9", 4)));
+    let params: Vec<Arg> = state
+        .get_params_of_current_function()
+        .into_iter()
+        .map(|(arg, _)| arg)
+        .collect();
+    assert_eq!(params, vec![Arg::from_var(Variable::mock("r0", 4), None)]);
+
+    assert_eq!(get_non_parameter_registers("aarch64"), &["x18", "w18"]);
+    assert!(get_non_parameter_registers("MIPS_32").contains(&"gp"));
+    assert!(get_non_parameter_registers("PowerPC").is_empty());
+}
+
+#[test]
+fn test_parameters_in_argument_order() {
+    let cconv = CallingConvention::mock_x64();
+    let rsp = Variable::mock("RSP", 8);
+    let rdi = Arg::from_var(Variable::mock("RDI", 8), None);
+    let rsi = Arg::from_var(Variable::mock("RSI", 8), None);
+    let zmm0 = Arg::from_var(Variable::mock("ZMM0", 64), None);
+    let zmm1 = Arg::from_var(Variable::mock("ZMM1", 64), None);
+    let stack_param = Arg::Stack {
+        address: Expression::Var(rsp.clone()).plus_const(8),
+        size: ByteSize::new(8),
+        data_type: None,
+    };
+    let second_stack_param = Arg::Stack {
+        address: Expression::Var(rsp.clone()).plus_const(16),
+        size: ByteSize::new(8),
+        data_type: None,
+    };
+    let mut fn_sig = FunctionSignature::new();
+    for arg in [&second_stack_param, &stack_param, &zmm1, &rsi, &zmm0, &rdi] {
+        fn_sig.parameters.insert(arg.clone(), AccessPattern::new());
+    }
+    let params: Vec<Arg> = fn_sig
+        .parameters_in_argument_order(&cconv, &rsp)
+        .into_iter()
+        .map(|(arg, _)| arg.clone())
+        .collect();
+    // Integer registers come first, followed by float registers and stack parameters.
+    assert_eq!(
+        params,
+        vec![rdi, rsi, zmm0, zmm1, stack_param, second_stack_param]
+    );
+}
+
+#[test]
+fn test_function_signature_diff() {
+    let mut project = Project::mock_x64();
+    let sub = Sub::mock("func");
+    project.program.term.subs.insert(sub.tid.clone(), sub);
+    let cconv = project.get_standard_calling_convention().unwrap();
+    let mut old_state = State::new(&Tid::new("func"), &project.stack_pointer_register, cconv);
+    old_state.set_read_flag_for_input_ids_of_expression(&Expression::Var(Variable::mock("RDI", 8)));
+    old_state.set_read_flag_for_input_ids_of_expression(&Expression::Var(Variable::mock("RSI", 8)));
+    let mut new_state = State::new(&Tid::new("func"), &project.stack_pointer_register, cconv);
+    new_state.set_read_flag_for_input_ids_of_expression(&Expression::Var(Variable::mock("RDI", 8)));
+    new_state.set_mutable_deref_flag_for_input_ids_of_expression(&Expression::Var(Variable::mock(
+        "RDX", 8,
+    )));
+    for state in [&mut old_state, &mut new_state] {
+        state.set_register(&Variable::mock("RAX", 8), Bitvector::from_u64(0).into());
+    }
+    let mut old_sig = FunctionSignature::from_state(&old_state, &project);
+    let new_sig = FunctionSignature::from_state(&new_state, &project);
+
+    assert!(old_sig.diff(&old_sig).is_empty());
+    let diff = old_sig.diff(&new_sig);
+    assert_eq!(
+        diff.added_parameters,
+        vec![Arg::from_var(Variable::mock("RDX", 8), None)]
+    );
+    assert_eq!(
+        diff.removed_parameters,
+        vec![Arg::from_var(Variable::mock("RSI", 8), None)]
+    );
+    assert!(diff.changed_access_patterns.is_empty());
+    assert!(diff.added_return_values.is_empty() && diff.removed_return_values.is_empty());
+
+    // Analysis-internal fields of access patterns are not reported as changes.
+    let rdi = Arg::from_var(Variable::mock("RDI", 8), None);
+    let mut read_on_some_paths = AccessPattern::new();
+    read_on_some_paths.set_read_flag_on_unknown_paths();
+    assert_ne!(read_on_some_paths, *old_sig.parameters.get(&rdi).unwrap());
+    let mut internally_changed_sig = old_sig.clone();
+    internally_changed_sig
+        .parameters
+        .insert(rdi.clone(), read_on_some_paths);
+    assert!(old_sig.diff(&internally_changed_sig).is_empty());
+
+    // Changed access patterns and return values
+    let rdi = Arg::from_var(Variable::mock("RDI", 8), None);
+    let mut dereferenced = *old_sig.parameters.get(&rdi).unwrap();
+    dereferenced.set_dereference_flag();
+    old_sig.parameters.insert(rdi.clone(), dereferenced);
+    let rax = Arg::from_var(Variable::mock("RAX", 8), None);
+    old_sig.return_values.retain(|arg| *arg != rax);
+    let diff = old_sig.diff(&new_sig);
+    assert_eq!(
+        diff.changed_access_patterns,
+        vec![AccessPatternChange {
+            parameter: rdi,
+            old: dereferenced,
+            new: *new_sig.get_param(0).unwrap().1,
+        }]
+    );
+    assert_eq!(diff.added_return_values, vec![rax]);
+    assert!(diff.removed_return_values.is_empty());
+    // The diff is serializable.
+    let json = serde_json::to_value(&diff).unwrap();
+    assert_eq!(json["added_parameters"].as_array().unwrap().len(), 1);
+}
+
+/// Mock a project with a function `caller` calling the function `callee`,
+/// which loads a value from the address given in the given parameter register.
+fn mock_project_with_call(param_register: &str) -> Project {
+    let mut project = Project::mock_x64();
+    let return_jmp = Term {
+        tid: Tid::new("return"),
+        term: Jmp::Return(Expression::Var(Variable::mock("RSP", 8))),
+    };
+    let mut caller = Sub::mock("caller");
+    let mut call_block = Blk::mock_with_tid("caller_call_blk");
+    call_block
+        .term
+        .jmps
+        .push(Jmp::call("call", "callee", Some("caller_return_blk")));
+    let mut return_block = Blk::mock_with_tid("caller_return_blk");
+    return_block.term.jmps.push(return_jmp.clone());
+    caller.term.blocks = vec![call_block, return_block];
+    let mut callee = Sub::mock("callee");
+    let mut callee_block = Blk::mock_with_tid("callee_blk");
+    callee_block.term.defs.push(Def::load(
+        "load",
+        Variable::mock("RAX", 8),
+        Expression::Var(Variable::mock(param_register, 8)),
+    ));
+    callee_block.term.jmps.push(return_jmp);
+    callee.term.blocks = vec![callee_block];
+    project.program.term.subs =
+        BTreeMap::from([(caller.tid.clone(), caller), (callee.tid.clone(), callee)]);
+    project
+}
+
+/// Mock a project like [`mock_project_with_call`] with an additional function `other`
+/// that neither calls nor is called by the other functions.
+fn mock_project_with_call_and_other_function(param_register: &str) -> Project {
+    let mut project = mock_project_with_call(param_register);
+    let mut other = Sub::mock("other");
+    let mut other_block = Blk::mock_with_tid("other_blk");
+    other_block.term.defs.push(Def::load(
+        "other_load",
+        Variable::mock("RAX", 8),
+        Expression::Var(Variable::mock("RDX", 8)),
+    ));
+    other_block.term.jmps.push(Term {
+        tid: Tid::new("return"),
+        term: Jmp::Return(Expression::Var(Variable::mock("RSP", 8))),
+    });
+    other.term.blocks = vec![other_block];
+    project.program.term.subs.insert(other.tid.clone(), other);
+    project
+}
+
+/// Return the node index of the start node of the entry block of the given function.
+fn get_entry_node(graph: &Graph, fn_name: &str) -> NodeIndex {
+    graph
+        .node_indices()
+        .find(|node| {
+            is_function_entry_node(&graph[*node]) && graph[*node].get_sub().tid == Tid::new(fn_name)
+        })
+        .unwrap()
+}
+
+#[test]
+fn test_recompute_function_signatures() {
+    // The accesses of the callee are not propagated to the caller with these options.
+    let options = || SignatureOptions {
+        max_propagation_depth: Some(0),
+        ..SignatureOptions::default()
+    };
+    // The patched project differs from the original project by the parameter register used in the callee.
+    let patched_project = mock_project_with_call_and_other_function("RSI");
+    let patched_graph =
+        crate::analysis::graph::get_program_cfg(&patched_project.program, HashSet::new());
+    let project = mock_project_with_call_and_other_function("RDI");
+    let graph = crate::analysis::graph::get_program_cfg(&project.program, HashSet::new());
+    let (_, _, fixpoint) = compute_function_signatures_with_options(&project, &graph, options());
+    let rdi_arg = Arg::from_var(Variable::mock("RDI", 8), None);
+    let rsi_arg = Arg::from_var(Variable::mock("RSI", 8), None);
+
+    let (fn_sigs, logs, _) = recompute_function_signatures(
+        &patched_project,
+        &patched_graph,
+        &fixpoint,
+        &HashSet::from([Tid::new("callee")]),
+    );
+    let (expected_fn_sigs, expected_logs, _) =
+        compute_function_signatures_with_options(&patched_project, &patched_graph, options());
+    assert_eq!(fn_sigs, expected_fn_sigs);
+    assert_eq!(logs, expected_logs);
+    let callee_params = &fn_sigs[&Tid::new("callee")].parameters;
+    assert!(callee_params.contains_key(&rsi_arg));
+    assert!(!callee_params.contains_key(&rdi_arg));
+    // The options of the prior computation are used for the recomputation.
+    assert!(fn_sigs[&Tid::new("caller")].parameters.is_empty());
+
+    // Functions that neither changed nor call changed functions are not analyzed again.
+    // To detect this, their prior node values are replaced by values that cannot result from the analysis.
+    let mut tampered_fixpoint = SignatureFixpoint {
+        node_values: fixpoint.node_values().clone(),
+        options: options(),
+    };
+    let mut tampered_nodes = Vec::new();
+    for fn_name in ["caller", "callee", "other"] {
+        let node = get_entry_node(&graph, fn_name);
+        let mut state = match &fixpoint.node_values()[&node] {
+            NodeValue::Value(state) => state.clone(),
+            _ => panic!(),
+        };
+        state.set_read_flag_for_input_ids_of_expression(&Expression::Var(Variable::mock("R9", 8)));
+        let tampered_value = NodeValue::Value(state);
+        tampered_fixpoint
+            .node_values
+            .insert(node, tampered_value.clone());
+        tampered_nodes.push((fn_name, node, tampered_value));
+    }
+    let (_, _, recomputed_fixpoint) = recompute_function_signatures(
+        &project,
+        &graph,
+        &tampered_fixpoint,
+        &HashSet::from([Tid::new("caller")]),
+    );
+    for (fn_name, node, tampered_value) in tampered_nodes {
+        let is_tampered = recomputed_fixpoint.node_values()[&node] == tampered_value;
+        // Only the changed function is analyzed again.
+        assert_eq!(is_tampered, fn_name != "caller");
+    }
+}
+
+#[test]
+fn test_max_propagation_depth() {
+    let project = mock_project_with_call("RDI");
+    let graph = crate::analysis::graph::get_program_cfg(&project.program, HashSet::new());
+    let rdi_arg = Arg::from_var(Variable::mock("RDI", 8), None);
+    // With depth zero the access to `RDI` in the callee is not propagated to the caller.
+    let options = |max_depth| SignatureOptions {
+        max_propagation_depth: Some(max_depth),
+        ..SignatureOptions::default()
+    };
+    let (fn_sigs, _, _) = compute_function_signatures_with_options(&project, &graph, options(0));
+    assert!(fn_sigs[&Tid::new("callee")]
+        .parameters
+        .contains_key(&rdi_arg));
+    assert!(!fn_sigs[&Tid::new("caller")]
+        .parameters
+        .contains_key(&rdi_arg));
+    // With depth one it is propagated to the direct caller.
+    let (fn_sigs, _, _) = compute_function_signatures_with_options(&project, &graph, options(1));
+    let caller_access = fn_sigs[&Tid::new("caller")].parameters[&rdi_arg];
+    assert!(caller_access.is_dereferenced());
+    assert_eq!(caller_access.get_propagation_depth(), Some(1));
+    let (unlimited_fn_sigs, _) = compute_function_signatures(&project, &graph);
+    assert_eq!(fn_sigs, unlimited_fn_sigs);
+}
+
+#[test]
+fn test_void_return() {
+    let rax_arg = Arg::from_var(Variable::mock("RAX", 8), None);
+    let project = mock_project_with_call("RDI");
+    let graph = crate::analysis::graph::get_program_cfg(&project.program, HashSet::new());
+    let (fn_sigs, _) = compute_function_signatures(&project, &graph);
+    assert!(fn_sigs[&Tid::new("callee")]
+        .return_values()
+        .contains(&rax_arg));
+    assert!(!fn_sigs[&Tid::new("callee")].returns_void());
+
+    // The callee loads into `RCX` instead and never touches `RAX`.
+    let mut project = mock_project_with_call("RDI");
+    let callee = project
+        .program
+        .term
+        .subs
+        .get_mut(&Tid::new("callee"))
+        .unwrap();
+    callee.term.blocks[0].term.defs = vec![Def::load(
+        "load",
+        Variable::mock("RCX", 8),
+        Expression::Var(Variable::mock("RDI", 8)),
+    )];
+    let graph = crate::analysis::graph::get_program_cfg(&project.program, HashSet::new());
+    let (fn_sigs, _) = compute_function_signatures(&project, &graph);
+    for fn_tid in ["caller", "callee"] {
+        let fn_sig = &fn_sigs[&Tid::new(fn_tid)];
+        assert!(!fn_sig.return_values().contains(&rax_arg));
+        assert!(fn_sig.returns_void());
+    }
+}
+
+#[test]
+fn test_result_values() {
+    let rax_arg = Arg::from_var(Variable::mock("RAX", 8), None);
+    let rdx_arg = Arg::from_var(Variable::mock("RDX", 8), None);
+    // The callee loads an unknown value into `RAX` and writes the result into `RDX`.
+    let mut project = mock_project_with_call("RDI");
+    let callee = project
+        .program
+        .term
+        .subs
+        .get_mut(&Tid::new("callee"))
+        .unwrap();
+    callee.term.blocks[0].term.defs = vec![
+        Def::load(
+            "load",
+            Variable::mock("RAX", 8),
+            Expression::Var(Variable::mock("RDI", 8)),
+        ),
+        Def::assign(
+            "assign",
+            Variable::mock("RDX", 8),
+            Expression::const_from_i64(0),
+        ),
+    ];
+    let graph = crate::analysis::graph::get_program_cfg(&project.program, HashSet::new());
+    let (fn_sigs, _) = compute_function_signatures(&project, &graph);
+    let callee_sig = &fn_sigs[&Tid::new("callee")];
+    assert!(callee_sig.return_values().contains(&rax_arg));
+    assert_eq!(callee_sig.result_values(), &[rdx_arg]);
+
+    // If all written return registers contain unknown values, the first one is the result register.
+    let mut project = mock_project_with_call("RDI");
+    let callee = project
+        .program
+        .term
+        .subs
+        .get_mut(&Tid::new("callee"))
+        .unwrap();
+    callee.term.blocks[0].term.defs = vec![
+        Def::load(
+            "load_rax",
+            Variable::mock("RAX", 8),
+            Expression::Var(Variable::mock("RDI", 8)),
+        ),
+        Def::load(
+            "load_rdx",
+            Variable::mock("RDX", 8),
+            Expression::Var(Variable::mock("RSI", 8)),
+        ),
+    ];
+    let graph = crate::analysis::graph::get_program_cfg(&project.program, HashSet::new());
+    let (fn_sigs, _) = compute_function_signatures(&project, &graph);
+    assert_eq!(fn_sigs[&Tid::new("callee")].result_values(), &[rax_arg]);
+
+    // If both registers of the return register pair are written, the result is the composite value.
+    let mut project = mock_project_with_call("RDI");
+    let callee = project
+        .program
+        .term
+        .subs
+        .get_mut(&Tid::new("callee"))
+        .unwrap();
+    callee.term.blocks[0].term.defs = vec![
+        Def::assign(
+            "assign_rax",
+            Variable::mock("RAX", 8),
+            Expression::const_from_i64(1),
+        ),
+        Def::assign(
+            "assign_rdx",
+            Variable::mock("RDX", 8),
+            Expression::const_from_i64(0),
+        ),
+    ];
+    let graph = crate::analysis::graph::get_program_cfg(&project.program, HashSet::new());
+    let (fn_sigs, _) = compute_function_signatures(&project, &graph);
+    let register_pair_arg = Arg::Register {
+        expr: Expression::BinOp {
+            op: BinOpType::Piece,
+            lhs: Box::new(Expression::Var(Variable::mock("RDX", 8))),
+            rhs: Box::new(Expression::Var(Variable::mock("RAX", 8))),
+        },
+        data_type: None,
+    };
+    assert_eq!(
+        fn_sigs[&Tid::new("callee")].result_values(),
+        &[register_pair_arg]
+    );
+}
+
+/// Mock a project with a function `caller` that moves `RSI` to `RDI`
+/// and then jumps to the entry block of the function `callee`,
+/// which loads a value from the address given in `RDI`.
+/// If `stack_adjustment` is not zero, then the stack pointer is adjusted by it before the jump.
+fn mock_project_with_tail_call(stack_adjustment: i64) -> Project {
+    let mut project = mock_project_with_call("RDI");
+    let rsp = Variable::mock("RSP", 8);
+    let mut caller_block = Blk::mock_with_tid("caller_blk");
+    caller_block.term.defs = vec![Def::assign(
+        "mov",
+        Variable::mock("RDI", 8),
+        Expression::Var(Variable::mock("RSI", 8)),
+    )];
+    if stack_adjustment != 0 {
+        caller_block.term.defs.push(Def::assign(
+            "adjust_stack",
+            rsp.clone(),
+            Expression::Var(rsp).plus_const(stack_adjustment),
+        ));
+    }
+    caller_block.term.jmps = vec![Jmp::branch("tail_call", "callee_blk")];
+    project
+        .program
+        .term
+        .subs
+        .get_mut(&Tid::new("caller"))
+        .unwrap()
+        .term
+        .blocks = vec![caller_block];
+    project
+}
+
+#[test]
+fn test_tail_call() {
+    let rsi_arg = Arg::from_var(Variable::mock("RSI", 8), None);
+    let rdi_arg = Arg::from_var(Variable::mock("RDI", 8), None);
+    let rax_arg = Arg::from_var(Variable::mock("RAX", 8), None);
+    let project = mock_project_with_tail_call(0);
+    let graph = crate::analysis::graph::get_program_cfg(&project.program, HashSet::new());
+    let mut computation =
+        generate_fixpoint_computation(&project, &graph, SignatureOptions::default());
+    computation.compute_with_max_steps(100);
+    let tail_calls = get_tail_calls(&project, &graph, &computation);
+    assert_eq!(tail_calls.len(), 1);
+    assert_eq!(graph[tail_calls[0].0].get_sub().tid, Tid::new("caller"));
+    assert_eq!(tail_calls[0].1.tid, Tid::new("callee"));
+
+    // The parameter of the callee is passed in `RSI` to the caller
+    // and the return value of the callee is returned by the caller.
+    let (fn_sigs, _) = compute_function_signatures(&project, &graph);
+    let caller_sig = &fn_sigs[&Tid::new("caller")];
+    assert!(caller_sig.parameters[&rsi_arg].is_dereferenced());
+    assert!(!caller_sig.parameters.contains_key(&rdi_arg));
+    assert!(caller_sig.return_values().contains(&rax_arg));
+    assert!(fn_sigs[&Tid::new("callee")].parameters[&rdi_arg].is_dereferenced());
+
+    // Jumps with an unbalanced stack are not tail calls.
+    let project = mock_project_with_tail_call(-8);
+    let graph = crate::analysis::graph::get_program_cfg(&project.program, HashSet::new());
+    let mut computation =
+        generate_fixpoint_computation(&project, &graph, SignatureOptions::default());
+    computation.compute_with_max_steps(100);
+    assert!(get_tail_calls(&project, &graph, &computation).is_empty());
+}
+
+#[test]
+fn test_transitive_tail_call() {
+    // The function `outer` moves `RDX` to `RSI` and jumps to `caller`,
+    // which in turn jumps to `callee`.
+    let mut project = mock_project_with_tail_call(0);
+    let mut outer = Sub::mock("a_outer");
+    let mut outer_block = Blk::mock_with_tid("outer_blk");
+    outer_block.term.defs = vec![Def::assign(
+        "mov_outer",
+        Variable::mock("RSI", 8),
+        Expression::Var(Variable::mock("RDX", 8)),
+    )];
+    outer_block.term.jmps = vec![Jmp::branch("outer_tail_call", "caller_blk")];
+    outer.term.blocks = vec![outer_block];
+    project.program.term.subs.insert(outer.tid.clone(), outer);
+    let graph = crate::analysis::graph::get_program_cfg(&project.program, HashSet::new());
+    let (fn_sigs, logs) = compute_function_signatures(&project, &graph);
+    assert!(logs.is_empty());
+    let rdx_arg = Arg::from_var(Variable::mock("RDX", 8), None);
+    let rax_arg = Arg::from_var(Variable::mock("RAX", 8), None);
+    let outer_sig = &fn_sigs[&Tid::new("a_outer")];
+    assert!(outer_sig.parameters[&rdx_arg].is_dereferenced());
+    assert!(outer_sig.return_values().contains(&rax_arg));
+}
+
+#[test]
+fn test_no_return_extern_call() {
+    let mut project = mock_project_with_call("RDI");
+    let mut exit_symbol = ExternSymbol::mock_x64();
+    exit_symbol.tid = Tid::new("exit");
+    exit_symbol.name = "exit".to_string();
+    exit_symbol.no_return = true;
+    project
+        .program
+        .term
+        .extern_symbols
+        .insert(exit_symbol.tid.clone(), exit_symbol);
+    let caller = project
+        .program
+        .term
+        .subs
+        .get_mut(&Tid::new("caller"))
+        .unwrap();
+    caller.term.blocks[0].term.jmps = vec![Jmp::call("call", "exit", Some("caller_return_blk"))];
+    caller.term.blocks[1].term.defs.push(Def::load(
+        "load",
+        Variable::mock("RAX", 8),
+        Expression::Var(Variable::mock("RSI", 8)),
+    ));
+    let graph = crate::analysis::graph::get_program_cfg(
+        &project.program,
+        HashSet::from([Tid::new("exit")]),
+    );
+    let (fn_sigs, _, fixpoint) =
+        compute_function_signatures_with_options(&project, &graph, SignatureOptions::default());
+    // The parameter of `exit` is a parameter of the caller,
+    // but the load on the unreachable path after the call is not.
+    let params = &fn_sigs[&Tid::new("caller")].parameters;
+    assert!(params.contains_key(&Arg::from_var(Variable::mock("RDI", 8), None)));
+    assert!(!params.contains_key(&Arg::from_var(Variable::mock("RSI", 8), None)));
+    assert_eq!(
+        get_unreachable_return_sites_of_no_return_calls(&project, &graph, fixpoint.node_values()),
+        BTreeSet::from([Tid::new("caller_return_blk")])
+    );
+}
+
+#[test]
+fn test_provenance_of_return_values() {
+    let project = mock_project_with_call("RDI");
+    let graph = crate::analysis::graph::get_program_cfg(&project.program, HashSet::new());
+    let return_site_state = |node_values: &FixpointNodeValues| {
+        let node = graph
+            .node_indices()
+            .find(|node| match graph[*node] {
+                Node::BlkStart(block, _) => block.tid == Tid::new("caller_return_blk"),
+                _ => false,
+            })
+            .unwrap();
+        match node_values.get(&node) {
+            Some(NodeValue::Value(state)) => state.clone(),
+            _ => panic!("No state at the return site."),
+        }
+    };
+    let rax = Variable::mock("RAX", 8);
+    let return_id = AbstractIdentifier::new_return_value(Tid::new("call"), &rax);
+
+    let provenance_options = || SignatureOptions {
+        track_provenance: true,
+        ..SignatureOptions::default()
+    };
+    let (fn_sigs, _, fixpoint) =
+        compute_function_signatures_with_options(&project, &graph, provenance_options());
+    let state = return_site_state(fixpoint.node_values());
+    assert!(state
+        .get_register(&rax)
+        .get_relative_values()
+        .contains_key(&return_id));
+    assert_eq!(
+        state.get_provenance(&return_id),
+        Some(&BTreeSet::from([Tid::new("call")]))
+    );
+    // Provenance tracking does not change the computed signatures.
+    assert_eq!(fn_sigs, compute_function_signatures(&project, &graph).0);
+
+    // Provenance tracking can be combined with other options.
+    let (fn_sigs, _, fixpoint) = compute_function_signatures_with_options(
+        &project,
+        &graph,
+        SignatureOptions {
+            max_propagation_depth: Some(0),
+            ..provenance_options()
+        },
+    );
+    assert!(return_site_state(fixpoint.node_values()).is_provenance_tracking_enabled());
+    assert!(!fn_sigs[&Tid::new("caller")]
+        .parameters
+        .contains_key(&Arg::from_var(Variable::mock("RDI", 8), None)));
+
+    // Provenance is not tracked by default.
+    let (_, _, fixpoint) =
+        compute_function_signatures_with_options(&project, &graph, SignatureOptions::default());
+    let state = return_site_state(fixpoint.node_values());
+    assert!(!state.is_provenance_tracking_enabled());
+    assert_eq!(state.get_provenance(&return_id), None);
+}
+
+#[test]
+fn test_parameter_confidence() {
+    let mut project = mock_project_with_call("RDI");
+    let return_jmp = Term {
+        tid: Tid::new("return"),
+        term: Jmp::Return(Expression::Var(Variable::mock("RSP", 8))),
+    };
+    let rdx = Expression::Var(Variable::mock("RDX", 8));
+    // Both paths of the callee dereference `RDI` and compute with `RDX`, but with different sizes.
+    // Only one path dereferences `RSI`.
+    let mut callee = Sub::mock("callee");
+    let mut entry_block = Blk::mock_with_tid("callee_entry");
+    entry_block.term.jmps = vec![
+        Term {
+            tid: Tid::new("cbranch"),
+            term: Jmp::CBranch {
+                target: Tid::new("callee_path_one"),
+                condition: Expression::Var(Variable::mock("ZF", 1)),
+            },
+        },
+        Jmp::branch("branch", "callee_path_two"),
+    ];
+    let mut path_one = Blk::mock_with_tid("callee_path_one");
+    path_one.term.defs = vec![
+        Def::load(
+            "load_rdi_one",
+            Variable::mock("RAX", 8),
+            Expression::Var(Variable::mock("RDI", 8)),
+        ),
+        Def::load(
+            "load_rsi",
+            Variable::mock("RCX", 8),
+            Expression::Var(Variable::mock("RSI", 8)),
+        ),
+        Def::assign(
+            "add_rdx",
+            Variable::mock("R8", 8),
+            rdx.clone().plus_const(1),
+        ),
+    ];
+    path_one.term.jmps.push(return_jmp.clone());
+    let mut path_two = Blk::mock_with_tid("callee_path_two");
+    path_two.term.defs = vec![
+        Def::load(
+            "load_rdi_two",
+            Variable::mock("RAX", 8),
+            Expression::Var(Variable::mock("RDI", 8)),
+        ),
+        Def::assign(
+            "add_edx",
+            Variable::mock("R8", 4),
+            rdx.subpiece(ByteSize::new(0), ByteSize::new(4))
+                .plus_const(1),
+        ),
+    ];
+    path_two.term.jmps.push(return_jmp);
+    callee.term.blocks = vec![entry_block, path_one, path_two];
+    project.program.term.subs.insert(callee.tid.clone(), callee);
+
+    let graph = crate::analysis::graph::get_program_cfg(&project.program, HashSet::new());
+    let (fn_sigs, _) = compute_function_signatures(&project, &graph);
+    let callee_sig = &fn_sigs[&Tid::new("callee")];
+    let confidence_of = |register: &str| {
+        callee_sig.get_parameter_confidence(&Arg::from_var(Variable::mock(register, 8), None))
+    };
+    assert_eq!(confidence_of("RDI"), Some(ParameterConfidence::High));
+    assert_eq!(confidence_of("RDX"), Some(ParameterConfidence::Medium));
+    assert_eq!(confidence_of("RSI"), Some(ParameterConfidence::Low));
+    assert_eq!(confidence_of("RCX"), None);
+}
+
+#[test]
+fn test_return_values_of_multiple_return_sites() {
+    let mut project = mock_project_with_call("RDI");
+    let return_jmp = Term {
+        tid: Tid::new("return"),
+        term: Jmp::Return(Expression::Var(Variable::mock("RSP", 8))),
+    };
+    // The callee returns the constant 1 on one path and its first parameter on the other path.
+    let mut callee = Sub::mock("callee");
+    let mut entry_block = Blk::mock_with_tid("callee_entry");
+    entry_block.term.jmps = vec![
+        Term {
+            tid: Tid::new("cbranch"),
+            term: Jmp::CBranch {
+                target: Tid::new("callee_return_param"),
+                condition: Expression::Var(Variable::mock("ZF", 1)),
+            },
+        },
+        Jmp::branch("branch", "callee_return_const"),
+    ];
+    let mut return_const_block = Blk::mock_with_tid("callee_return_const");
+    return_const_block.term.defs.push(Def::assign(
+        "assign_const",
+        Variable::mock("RAX", 8),
+        Expression::const_from_i64(1),
+    ));
+    return_const_block.term.jmps.push(return_jmp.clone());
+    let mut return_param_block = Blk::mock_with_tid("callee_return_param");
+    return_param_block.term.defs.push(Def::assign(
+        "assign_param",
+        Variable::mock("RAX", 8),
+        Expression::Var(Variable::mock("RDI", 8)),
+    ));
+    return_param_block.term.jmps.push(return_jmp);
+    callee.term.blocks = vec![entry_block, return_const_block, return_param_block];
+    project.program.term.subs.insert(callee.tid.clone(), callee);
+
+    let graph = crate::analysis::graph::get_program_cfg(&project.program, HashSet::new());
+    let (_, _, fixpoint) =
+        compute_function_signatures_with_options(&project, &graph, SignatureOptions::default());
+    let return_site = graph
+        .node_indices()
+        .find(|node| match graph[*node] {
+            Node::BlkStart(block, _) => block.tid == Tid::new("caller_return_blk"),
+            _ => false,
+        })
+        .unwrap();
+    let state = match fixpoint.node_values().get(&return_site) {
+        Some(NodeValue::Value(state)) => state,
+        _ => panic!("No state at the return site."),
+    };
+    // The return value at the call site contains the return values of both return sites of the callee.
+    // The constant originates in the callee, so it is represented by the return value ID of the call.
+    let rax = Variable::mock("RAX", 8);
+    let return_value = state.get_register(&rax);
+    let return_id = AbstractIdentifier::new_return_value(Tid::new("call"), &rax);
+    let caller_rdi_id =
+        AbstractIdentifier::new_from_var(Tid::new("caller"), &Variable::mock("RDI", 8));
+    assert_eq!(
+        return_value
+            .get_relative_values()
+            .keys()
+            .cloned()
+            .collect::<BTreeSet<_>>(),
+        BTreeSet::from([return_id, caller_rdi_id])
+    );
+    assert!(!return_value.contains_top());
+}
+
+#[test]
+fn test_compared_only_parameter() {
+    let mut project = mock_project_with_call("RDI");
+    let rdi = Expression::Var(Variable::mock("RDI", 8));
+    let zero = Expression::const_from_i64(0);
+    let temp = |name: &str, size: u64| Variable {
+        name: name.to_string(),
+        size: ByteSize::new(size),
+        is_temp: true,
+    };
+    let bin_op = |lhs: &Expression, op: BinOpType, rhs: &Expression| Expression::BinOp {
+        op,
+        lhs: Box::new(lhs.clone()),
+        rhs: Box::new(rhs.clone()),
+    };
+    let cmp_result = Expression::Var(temp("$U1", 8));
+    let parity_byte = Expression::Var(temp("$U2", 8));
+    let parity_count = Expression::Var(temp("$U3", 8));
+    // The callee executes `CMP RDI, 0; JZ` (as lowered by Ghidra) and returns on both paths.
+    let mut callee = Sub::mock("callee");
+    let mut entry_block = Blk::mock_with_tid("callee_entry");
+    entry_block.term.defs = vec![
+        Def::assign(
+            "cf",
+            Variable::mock("CF", 1),
+            bin_op(&rdi, BinOpType::IntLess, &zero),
+        ),
+        Def::assign(
+            "of",
+            Variable::mock("OF", 1),
+            bin_op(&rdi, BinOpType::IntSBorrow, &zero),
+        ),
+        Def::assign(
+            "sub",
+            temp("$U1", 8),
+            bin_op(&rdi, BinOpType::IntSub, &zero),
+        ),
+        Def::assign(
+            "sf",
+            Variable::mock("SF", 1),
+            bin_op(&cmp_result, BinOpType::IntSLess, &zero),
+        ),
+        Def::assign(
+            "zf",
+            Variable::mock("ZF", 1),
+            bin_op(&cmp_result, BinOpType::IntEqual, &zero),
+        ),
+        Def::assign(
+            "parity_byte",
+            temp("$U2", 8),
+            bin_op(
+                &cmp_result,
+                BinOpType::IntAnd,
+                &Expression::const_from_i64(0xff),
+            ),
+        ),
+        Def::assign(
+            "parity_count",
+            temp("$U3", 8),
+            parity_byte.cast(CastOpType::PopCount),
+        ),
+        Def::assign(
+            "pf",
+            Variable::mock("PF", 1),
+            bin_op(
+                &bin_op(
+                    &parity_count,
+                    BinOpType::IntAnd,
+                    &Expression::const_from_i64(1),
+                ),
+                BinOpType::IntEqual,
+                &zero,
+            ),
+        ),
+    ];
+    entry_block.term.jmps = vec![
+        Term {
+            tid: Tid::new("jz"),
+            term: Jmp::CBranch {
+                target: Tid::new("callee_return"),
+                condition: Expression::Var(Variable::mock("ZF", 1)),
+            },
+        },
+        Jmp::branch("branch", "callee_return"),
+    ];
+    let mut return_block = Blk::mock_with_tid("callee_return");
+    return_block.term.jmps.push(Term {
+        tid: Tid::new("return"),
+        term: Jmp::Return(Expression::Var(Variable::mock("RSP", 8))),
+    });
+    callee.term.blocks = vec![entry_block, return_block];
+    project.program.term.subs.insert(callee.tid.clone(), callee);
+
+    let graph = crate::analysis::graph::get_program_cfg(&project.program, HashSet::new());
+    let (fn_sigs, _) = compute_function_signatures(&project, &graph);
+    let rdi_arg = Arg::from_var(Variable::mock("RDI", 8), None);
+    let access_pattern = &fn_sigs[&Tid::new("callee")].parameters[&rdi_arg];
+    assert!(access_pattern.is_read());
+    assert!(access_pattern.is_compared_only());
+    assert_eq!(
+        access_pattern.get_pointer_access(),
+        PointerAccess::ComparedOnly
+    );
+}
+
+#[test]
+fn test_propagate_known_arguments() {
+    let mut project = Project::mock_x64();
+    let rdi = Variable::mock("RDI", 8);
+    let rsi = Variable::mock("RSI", 8);
+    let return_jmp = Term {
+        tid: Tid::new("return"),
+        term: Jmp::Return(Expression::Var(Variable::mock("RSP", 8))),
+    };
+    // The caller always passes zero in `RDI` to the callee.
+    let mut caller = Sub::mock("caller");
+    let mut call_block = Blk::mock_with_tid("caller_call_blk");
+    call_block.term.defs.push(Def::assign(
+        "set_rdi",
+        rdi.clone(),
+        Expression::const_from_i64(0),
+    ));
+    call_block
+        .term
+        .jmps
+        .push(Jmp::call("call", "callee", Some("caller_return_blk")));
+    let mut caller_return_block = Blk::mock_with_tid("caller_return_blk");
+    caller_return_block.term.jmps.push(return_jmp.clone());
+    caller.term.blocks = vec![call_block, caller_return_block];
+    // The callee only dereferences `RSI` if `RDI` is not zero.
+    let mut callee = Sub::mock("callee");
+    let mut entry_block = Blk::mock_with_tid("callee_entry");
+    entry_block.term.jmps = vec![
+        Term {
+            tid: Tid::new("cbranch"),
+            term: Jmp::CBranch {
+                target: Tid::new("callee_return_blk"),
+                condition: Expression::BinOp {
+                    op: BinOpType::IntEqual,
+                    lhs: Box::new(Expression::Var(rdi.clone())),
+                    rhs: Box::new(Expression::const_from_i64(0)),
+                },
+            },
+        },
+        Jmp::branch("branch", "callee_deref_blk"),
+    ];
+    let mut deref_block = Blk::mock_with_tid("callee_deref_blk");
+    deref_block.term.defs.push(Def::load(
+        "load",
+        Variable::mock("RAX", 8),
+        Expression::Var(rsi.clone()),
+    ));
+    deref_block.term.jmps.push(return_jmp.clone());
+    let mut callee_return_block = Blk::mock_with_tid("callee_return_blk");
+    callee_return_block.term.jmps.push(return_jmp.clone());
+    callee.term.blocks = vec![entry_block, deref_block, callee_return_block];
+    // A function that is only called by itself.
+    let mut recursive = Sub::mock("recursive");
+    let mut recursive_block = Blk::mock_with_tid("recursive_blk");
+    recursive_block.term.defs.push(Def::load(
+        "recursive_load",
+        Variable::mock("RAX", 8),
+        Expression::Var(rdi.clone()),
+    ));
+    recursive_block.term.jmps.push(Jmp::call(
+        "recursive_call",
+        "recursive",
+        Some("recursive_return_blk"),
+    ));
+    let mut recursive_return_block = Blk::mock_with_tid("recursive_return_blk");
+    recursive_return_block.term.jmps.push(return_jmp);
+    recursive.term.blocks = vec![recursive_block, recursive_return_block];
+    project.program.term.subs = BTreeMap::from([
+        (caller.tid.clone(), caller),
+        (callee.tid.clone(), callee),
+        (recursive.tid.clone(), recursive),
+    ]);
+    let graph = crate::analysis::graph::get_program_cfg(&project.program, HashSet::new());
+    let rdi_arg = Arg::from_var(rdi, None);
+    let rsi_arg = Arg::from_var(rsi, None);
+
+    let (generic_fn_sigs, _) = compute_function_signatures(&project, &graph);
+    assert!(generic_fn_sigs[&Tid::new("caller")]
+        .parameters
+        .contains_key(&rsi_arg));
+    let (fn_sigs, _, _) = compute_function_signatures_with_options(
+        &project,
+        &graph,
+        SignatureOptions {
+            propagate_known_arguments: true,
+            ..SignatureOptions::default()
+        },
+    );
+    // The dereference of `RSI` is unreachable for the caller.
+    assert!(!fn_sigs[&Tid::new("caller")]
+        .parameters
+        .contains_key(&rsi_arg));
+    // The signature of the callee itself is valid for all call sites, including unknown ones.
+    assert_eq!(
+        fn_sigs[&Tid::new("callee")],
+        generic_fn_sigs[&Tid::new("callee")]
+    );
+    assert!(fn_sigs[&Tid::new("callee")]
+        .parameters
+        .contains_key(&rsi_arg));
+    // Functions without a reachable call site also get a signature.
+    assert!(fn_sigs[&Tid::new("recursive")]
+        .parameters
+        .contains_key(&rdi_arg));
+}
+
+#[test]
+fn test_confidence_of_stack_parameter_loaded_on_one_path() {
+    let mut project = Project::mock_x64();
+    let return_jmp = Term {
+        tid: Tid::new("return"),
+        term: Jmp::Return(Expression::Var(Variable::mock("RSP", 8))),
+    };
+    // Only one path loads the stack parameter before both paths join.
+    let mut func = Sub::mock("func");
+    let mut entry_block = Blk::mock_with_tid("entry");
+    entry_block.term.jmps = vec![
+        Term {
+            tid: Tid::new("cbranch"),
+            term: Jmp::CBranch {
+                target: Tid::new("load_blk"),
+                condition: Expression::Var(Variable::mock("ZF", 1)),
+            },
+        },
+        Jmp::branch("branch", "join_blk"),
+    ];
+    let mut load_block = Blk::mock_with_tid("load_blk");
+    load_block.term.defs.push(Def::load(
+        "load",
+        Variable::mock("RAX", 8),
+        Expression::Var(Variable::mock("RSP", 8)).plus_const(8),
+    ));
+    load_block
+        .term
+        .jmps
+        .push(Jmp::branch("branch_to_join", "join_blk"));
+    let mut join_block = Blk::mock_with_tid("join_blk");
+    join_block.term.jmps.push(return_jmp);
+    func.term.blocks = vec![entry_block, load_block, join_block];
+    project.program.term.subs = BTreeMap::from([(func.tid.clone(), func)]);
+
+    let graph = crate::analysis::graph::get_program_cfg(&project.program, HashSet::new());
+    let (fn_sigs, _) = compute_function_signatures(&project, &graph);
+    let stack_param = Arg::Stack {
+        address: Expression::Var(Variable::mock("RSP", 8)).plus_const(8),
+        size: ByteSize::new(8),
+        data_type: None,
+    };
+    let fn_sig = &fn_sigs[&Tid::new("func")];
+    assert!(fn_sig.parameters[&stack_param].is_read());
+    assert_eq!(
+        fn_sig.get_parameter_confidence(&stack_param),
+        Some(ParameterConfidence::Low)
+    );
+}
@@ -45,7 +45,7 @@
 use crate::intermediate_representation::*;
 use crate::prelude::*;
 use petgraph::graph::{DiGraph, NodeIndex};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 
 /// The graph type of an interprocedural control flow graph
 pub type Graph<'a> = DiGraph<Node<'a>, Edge<'a>>;
@@ -494,10 +494,62 @@ pub fn get_program_cfg(program: &Term<Program>, extern_subs: HashSet<Tid>) -> Gr
     builder.build()
 }
 
+/// The functions of a program that take part in recursion.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct RecursiveFunctions {
+    /// Functions that directly call themselves.
+    pub self_recursive: BTreeSet<Tid>,
+    /// Functions that are part of a cycle of calls between two or more functions.
+    pub mutually_recursive: BTreeSet<Tid>,
+}
+
+impl RecursiveFunctions {
+    /// Returns true if the function with the given TID takes part in any kind of recursion.
+    pub fn contains(&self, function_tid: &Tid) -> bool {
+        self.self_recursive.contains(function_tid) || self.mutually_recursive.contains(function_tid)
+    }
+}
+
+/// Get the functions taking part in recursion in the call graph contained in the given control flow graph.
+///
+/// A function is mutually recursive if it is contained in a strongly connected component
+/// of the call graph together with at least one other function.
+/// Note that a function may be both self-recursive and mutually recursive.
+/// Since indirect calls and calls to extern functions are not represented by `Call` edges in the graph,
+/// recursion through such calls is not detected.
+pub fn get_recursive_functions(graph: &Graph) -> RecursiveFunctions {
+    let mut call_graph: DiGraph<&Tid, ()> = DiGraph::new();
+    let mut call_graph_nodes: HashMap<&Tid, NodeIndex> = HashMap::new();
+    let mut recursive_functions = RecursiveFunctions::default();
+    for node in graph.node_weights() {
+        if let Node::CallSource { source, target } = node {
+            let (caller, callee) = (&source.1.tid, &target.1.tid);
+            if caller == callee {
+                recursive_functions.self_recursive.insert(caller.clone());
+            }
+            let caller_node = *call_graph_nodes
+                .entry(caller)
+                .or_insert_with(|| call_graph.add_node(caller));
+            let callee_node = *call_graph_nodes
+                .entry(callee)
+                .or_insert_with(|| call_graph.add_node(callee));
+            call_graph.update_edge(caller_node, callee_node, ());
+        }
+    }
+    for component in petgraph::algo::kosaraju_scc(&call_graph) {
+        if component.len() > 1 {
+            recursive_functions
+                .mutually_recursive
+                .extend(component.into_iter().map(|node| call_graph[node].clone()));
+        }
+    }
+    recursive_functions
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::{BTreeMap, BTreeSet};
+    use std::collections::BTreeMap;
     use std::iter::FromIterator;
 
     fn mock_program() -> Term<Program> {
@@ -632,4 +684,44 @@ mod tests {
         assert_eq!(graph.node_count(), 2);
         assert_eq!(graph.edge_count(), 2);
     }
+
+    #[test]
+    fn recursive_functions() {
+        let mock_sub = |name: &str, callees: &[&str]| {
+            let mut sub = Sub::mock(name);
+            for (index, callee) in callees.iter().enumerate() {
+                let mut block = Blk::mock_with_tid(&format!("{}_blk{}", name, index));
+                block
+                    .term
+                    .jmps
+                    .push(Jmp::call(&format!("{}_call{}", name, index), callee, None));
+                sub.term.blocks.push(block);
+            }
+            sub
+        };
+        let mut program = Program::mock_empty();
+        for sub in [
+            mock_sub("main", &["main", "even"]),
+            mock_sub("even", &["odd"]),
+            mock_sub("odd", &["even", "leaf"]),
+            mock_sub("leaf", &[]),
+        ] {
+            program.subs.insert(sub.tid.clone(), sub);
+        }
+        let program_term = Term {
+            tid: Tid::new("program".to_string()),
+            term: program,
+        };
+        let graph = get_program_cfg(&program_term, HashSet::new());
+        let recursive_functions = get_recursive_functions(&graph);
+        assert_eq!(
+            recursive_functions.self_recursive,
+            BTreeSet::from([Tid::new("main")])
+        );
+        assert_eq!(
+            recursive_functions.mutually_recursive,
+            BTreeSet::from([Tid::new("even"), Tid::new("odd")])
+        );
+        assert!(!recursive_functions.contains(&Tid::new("leaf")));
+    }
 }
@@ -96,6 +96,7 @@ fn mock_project() -> (Project, Config) {
         integer_return_register: vec![Variable::mock("RDX", 8)],
         float_return_register: vec![],
         callee_saved_register: vec![Variable::mock("callee_saved_reg", 8)],
+        first_stack_arg_offset: 8,
     };
     let register_set = vec!["RAX", "RCX", "RDX", "RBX", "RSP", "RBP", "RSI", "RDI"]
         .into_iter()
@@ -109,10 +110,12 @@ fn mock_project() -> (Project, Config) {
             calling_conventions: BTreeMap::from_iter([(cconv.name.clone(), cconv)]),
             register_set,
             datatype_properties: DatatypeProperties::mock(),
+            format_string_functions: FormatStringFunctions::default(),
         },
         Config {
             allocation_symbols: vec!["malloc".into()],
             deallocation_symbols: vec!["free".into()],
+            widening_threshold: None,
         },
     )
 }
@@ -13,6 +13,11 @@ impl<'a> crate::analysis::forward_interprocedural_fixpoint::Context<'a> for Cont
         value1.merge(value2)
     }
 
+    /// Widen the old state `value1` with the new state `value2`.
+    fn widen(&self, value1: &State, value2: &State) -> State {
+        value1.widen(value2)
+    }
+
     /// Update the state according to the effects of the given `Def` term.
     fn update_def(&self, state: &Self::Value, def: &Term<Def>) -> Option<Self::Value> {
         let mut new_state = state.clone();
@@ -75,6 +75,12 @@ pub struct Config {
     /// Note that the analysis currently does not detect mismatching allocation-deallocation pairs,
     /// i.e. it cannot distinguish between memory allocated by `malloc` and memory allocated by `new`.
     pub deallocation_symbols: Vec<String>,
+    /// The number of value changes of a node in the fixpoint computation
+    /// after which the values of the node get widened instead of merged.
+    /// Widening guarantees that loops with growing values stabilize quickly at the cost of precision.
+    /// If not set or `null` (the default), node values are only merged.
+    #[serde(default)]
+    pub widening_threshold: Option<u64>,
 }
 
 /// A wrapper struct for the pointer inference computation object.
@@ -93,6 +99,7 @@ impl<'a> PointerInference<'a> {
         log_sender: crossbeam_channel::Sender<LogThreadMsg>,
         print_stats: bool,
     ) -> PointerInference<'a> {
+        let widening_threshold = config.widening_threshold;
         let context = Context::new(analysis_results, config, log_sender.clone());
         let project = analysis_results.project;
 
@@ -134,6 +141,9 @@ impl<'a> PointerInference<'a> {
             .collect();
         let mut fixpoint_computation =
             super::forward_interprocedural_fixpoint::create_computation_with_alternate_worklist_order(context, None);
+        if let Some(threshold) = widening_threshold {
+            fixpoint_computation.set_widening_threshold(threshold);
+        }
         if print_stats {
             let _ = log_sender.send(LogThreadMsg::Log(
                 LogMessage::new_info(format!(
@@ -533,6 +543,7 @@ mod tests {
             let config = Config {
                 allocation_symbols: vec!["malloc".to_string()],
                 deallocation_symbols: vec!["free".to_string()],
+                widening_threshold: None,
             };
             let (log_sender, _) = crossbeam_channel::unbounded();
             PointerInference::new(analysis_results, config, log_sender, false)
@@ -198,6 +198,28 @@ impl AbstractObject {
 impl AbstractDomain for AbstractObject {
     /// Merge two abstract objects
     fn merge(&self, other: &Self) -> Self {
+        self.combine_with(other, MemRegion::merge)
+    }
+
+    /// Widen two abstract objects.
+    /// Works like [`merge`](Self::merge), except that the memory contents are widened.
+    fn widen(&self, other: &Self) -> Self {
+        self.combine_with(other, MemRegion::widen)
+    }
+
+    /// The domain has no *Top* element, thus this function always returns false.
+    fn is_top(&self) -> bool {
+        false
+    }
+}
+
+impl AbstractObject {
+    /// Merge two abstract objects, where the memory contents are combined with the given `combine_memory` function.
+    fn combine_with(
+        &self,
+        other: &Self,
+        combine_memory: fn(&MemRegion<Data>, &MemRegion<Data>) -> MemRegion<Data>,
+    ) -> Self {
         if self == other {
             self.clone()
         } else {
@@ -211,7 +233,7 @@ impl AbstractDomain for AbstractObject {
                 is_unique: self.inner.is_unique && other.inner.is_unique,
                 state: self.inner.state.merge(other.inner.state),
                 type_: same_or_none(&self.inner.type_, &other.inner.type_),
-                memory: self.inner.memory.merge(&other.inner.memory),
+                memory: combine_memory(&self.inner.memory, &other.inner.memory),
                 lower_index_bound: self
                     .inner
                     .lower_index_bound
@@ -224,11 +246,6 @@ impl AbstractDomain for AbstractObject {
             .into()
         }
     }
-
-    /// The domain has no *Top* element, thus this function always returns false.
-    fn is_top(&self) -> bool {
-        false
-    }
 }
 
 impl AbstractObject {
@@ -150,11 +150,35 @@ impl AbstractDomain for AbstractObjectList {
     /// to decide, how to correctly represent and handle cases,
     /// where more than one ID should point to the same object.
     fn merge(&self, other: &Self) -> Self {
+        self.combine_with(other, AbstractObject::merge, ValueDomain::merge)
+    }
+
+    /// Widen two abstract object lists.
+    /// Works like [`merge`](Self::merge), except that the objects and their offsets are widened.
+    fn widen(&self, other: &Self) -> Self {
+        self.combine_with(other, AbstractObject::widen, ValueDomain::widen)
+    }
+
+    /// Always returns `false`, since abstract object lists have no *Top* element.
+    fn is_top(&self) -> bool {
+        false
+    }
+}
+
+impl AbstractObjectList {
+    /// Merge two abstract object lists,
+    /// where objects and offsets contained in both lists are combined with the given functions.
+    fn combine_with(
+        &self,
+        other: &Self,
+        combine_objects: fn(&AbstractObject, &AbstractObject) -> AbstractObject,
+        combine_offsets: fn(&ValueDomain, &ValueDomain) -> ValueDomain,
+    ) -> Self {
         let mut merged_objects = self.objects.clone();
         for (id, (other_object, other_offset)) in other.objects.iter() {
             if let Some((object, offset)) = merged_objects.get_mut(id) {
-                *object = object.merge(other_object);
-                *offset = offset.merge(other_offset);
+                *object = combine_objects(object, other_object);
+                *offset = combine_offsets(offset, other_offset);
             } else {
                 merged_objects.insert(id.clone(), (other_object.clone(), other_offset.clone()));
             }
@@ -163,11 +187,6 @@ impl AbstractDomain for AbstractObjectList {
             objects: merged_objects,
         }
     }
-
-    /// Always returns `false`, since abstract object lists have no *Top* element.
-    fn is_top(&self) -> bool {
-        false
-    }
 }
 
 impl AbstractObjectList {
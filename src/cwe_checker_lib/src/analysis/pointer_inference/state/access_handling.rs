@@ -220,6 +220,49 @@ impl State {
         }
     }
 
+    /// Evaluate the value of an expression like [`eval`](Self::eval)
+    /// and also return the divisors of all integer division and remainder operations in the expression
+    /// that may be zero, i.e. whose absolute values contain zero or whose values may be `Top`.
+    ///
+    /// This is meant as a building block for checks for divisions by zero (CWE-369).
+    /// Since the divisors have to be evaluated separately,
+    /// analyses that do not need the check should use [`eval`](Self::eval) instead.
+    pub fn eval_with_zero_divisor_check<'a>(
+        &self,
+        expression: &'a Expression,
+    ) -> (Data, Vec<&'a Expression>) {
+        let mut zero_divisors = Vec::new();
+        self.collect_possibly_zero_divisors(expression, &mut zero_divisors);
+        (self.eval(expression), zero_divisors)
+    }
+
+    /// Add the divisors of all integer division and remainder operations in the expression
+    /// that may be zero to `zero_divisors`.
+    fn collect_possibly_zero_divisors<'a>(
+        &self,
+        expression: &'a Expression,
+        zero_divisors: &mut Vec<&'a Expression>,
+    ) {
+        use Expression::*;
+        match expression {
+            Var(_) | Const(_) | Unknown { .. } => (),
+            BinOp { op, lhs, rhs } => {
+                self.collect_possibly_zero_divisors(lhs, zero_divisors);
+                self.collect_possibly_zero_divisors(rhs, zero_divisors);
+                if matches!(
+                    op,
+                    BinOpType::IntDiv | BinOpType::IntSDiv | BinOpType::IntRem | BinOpType::IntSRem
+                ) && may_be_zero(&self.eval(rhs))
+                {
+                    zero_divisors.push(rhs);
+                }
+            }
+            UnOp { arg, .. } | Cast { arg, .. } | Subpiece { arg, .. } => {
+                self.collect_possibly_zero_divisors(arg, zero_divisors)
+            }
+        }
+    }
+
     /// Evaluate the value of a parameter of an extern symbol for the given state.
     pub fn eval_parameter_arg(
         &self,
@@ -367,3 +410,19 @@ impl State {
         Ok(false)
     }
 }
+
+/// Returns true if the given value may be zero,
+/// i.e. if its absolute value contains zero or if it may be `Top`.
+/// Pointers (i.e. relative values) are not considered to be zero.
+fn may_be_zero(value: &Data) -> bool {
+    if value.contains_top() {
+        return true;
+    }
+    match value.get_absolute_value() {
+        Some(absolute_value) => match absolute_value.try_to_interval() {
+            Ok(interval) => interval.contains(&Bitvector::zero(absolute_value.bytesize().into())),
+            Err(_) => true,
+        },
+        None => false,
+    }
+}
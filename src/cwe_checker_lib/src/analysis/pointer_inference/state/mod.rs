@@ -272,18 +272,42 @@ impl State {
 impl AbstractDomain for State {
     /// Merge two states
     fn merge(&self, other: &Self) -> Self {
+        self.combine_with(other, Data::merge, AbstractObjectList::merge)
+    }
+
+    /// Widen two states.
+    /// Works like [`merge`](Self::merge), except that register values and memory objects are widened,
+    /// so that the values of states in loops stabilize after a few iterations.
+    fn widen(&self, other: &Self) -> Self {
+        self.combine_with(other, Data::widen, AbstractObjectList::widen)
+    }
+
+    /// A state has no *Top* element
+    fn is_top(&self) -> bool {
+        false
+    }
+}
+
+impl State {
+    /// Merge two states, where register values and memory objects are combined with the given functions.
+    fn combine_with(
+        &self,
+        other: &Self,
+        combine_values: fn(&Data, &Data) -> Data,
+        combine_memory: fn(&AbstractObjectList, &AbstractObjectList) -> AbstractObjectList,
+    ) -> Self {
         assert_eq!(self.stack_id, other.stack_id);
         let mut merged_register = BTreeMap::new();
         for (register, other_value) in other.register.iter() {
             if let Some(value) = self.register.get(register) {
-                let merged_value = value.merge(other_value);
+                let merged_value = combine_values(value, other_value);
                 if !merged_value.is_top() {
                     // We only have to keep non-*Top* elements.
                     merged_register.insert(register.clone(), merged_value);
                 }
             }
         }
-        let merged_memory_objects = self.memory.merge(&other.memory);
+        let merged_memory_objects = combine_memory(&self.memory, &other.memory);
         State {
             register: merged_register,
             memory: merged_memory_objects,
@@ -300,11 +324,6 @@ impl AbstractDomain for State {
                 .collect(),
         }
     }
-
-    /// A state has no *Top* element
-    fn is_top(&self) -> bool {
-        false
-    }
 }
 
 impl State {
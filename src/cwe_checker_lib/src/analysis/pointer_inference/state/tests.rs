@@ -136,6 +136,55 @@ fn state() {
     assert_eq!(state.memory.get_num_objects(), 2);
 }
 
+#[test]
+fn widen_growing_loop_values() {
+    let global_memory = RuntimeMemoryImage::mock();
+    let rax = register("RAX");
+    let stack_slot = reg_add("RSP", -8);
+    // The loop body increments RAX and stores it on the stack.
+    let loop_body = |state: &State| {
+        let mut state = state.clone();
+        state.set_register(&rax, state.eval(&reg_add("RAX", 1)));
+        state
+            .write_to_address(
+                &stack_slot,
+                &state.eval(&Expression::Var(rax.clone())),
+                &global_memory,
+            )
+            .unwrap();
+        state
+    };
+    let mut entry_state = State::new(&register("RSP"), Tid::new("func_tid"));
+    let mut rax_value = IntervalDomain::mock(0, 0);
+    rax_value.update_widening_upper_bound(&Some(Bitvector::from_i64(1000)));
+    entry_state.set_register(&rax, rax_value.into());
+    let entry_state = loop_body(&entry_state);
+    // Compute the fixpoint of the loop and return the number of iterations until the state stabilized.
+    let iterations_until_stable = |combine: fn(&State, &State) -> State| {
+        let mut state = entry_state.clone();
+        for iteration in 1.. {
+            let new_state = combine(&state, &loop_body(&state));
+            if new_state == state {
+                return (iteration, state);
+            }
+            state = new_state;
+        }
+        unreachable!()
+    };
+
+    let (merge_iterations, _) = iterations_until_stable(State::merge);
+    let (widen_iterations, widened_state) = iterations_until_stable(State::widen);
+    assert!(widen_iterations < merge_iterations);
+    // The growing values were widened to all possible values.
+    let stored_value = widened_state
+        .load_value(&stack_slot, ByteSize::new(8), &global_memory)
+        .unwrap();
+    for value in [widened_state.get_register(&rax), stored_value] {
+        assert!(value.get_absolute_value().unwrap().is_top());
+        assert!(value.get_relative_values().is_empty());
+    }
+}
+
 #[test]
 fn handle_store() {
     use Expression::*;
@@ -1008,6 +1057,36 @@ fn stack_pointer_with_nonnegative_offset() {
     assert!(!state.is_stack_pointer_with_nonnegative_offset(&pointer)); // The offset is not a constant
 }
 
+#[test]
+fn eval_with_zero_divisor_check() {
+    let mut state = State::new(&register("RSP"), Tid::new("func_tid"));
+    state.set_register(&register("RAX"), IntervalDomain::mock(0, 5).into());
+    state.set_register(&register("RBX"), bv(10).into());
+    let division = |divisor: Expression| Expression::BinOp {
+        op: BinOpType::IntSDiv,
+        lhs: Box::new(Expression::Var(register("RBX"))),
+        rhs: Box::new(divisor),
+    };
+    // The divisor may be zero
+    let expression = division(Expression::Var(register("RAX")));
+    let (value, zero_divisors) = state.eval_with_zero_divisor_check(&expression);
+    assert_eq!(value, state.eval(&expression));
+    assert_eq!(zero_divisors, vec![&Expression::Var(register("RAX"))]);
+    // The divisor is a nonzero constant
+    let expression = division(Expression::Const(Bitvector::from_i64(2)));
+    let (value, zero_divisors) = state.eval_with_zero_divisor_check(&expression);
+    assert_eq!(value, bv(5).into());
+    assert!(zero_divisors.is_empty());
+    // The value of the divisor is unknown
+    let expression = division(Expression::Var(register("RCX")));
+    let (_, zero_divisors) = state.eval_with_zero_divisor_check(&expression);
+    assert_eq!(zero_divisors, vec![&Expression::Var(register("RCX"))]);
+    // Other operations are not checked
+    let expression = reg_add("RCX", 0);
+    let (_, zero_divisors) = state.eval_with_zero_divisor_check(&expression);
+    assert!(zero_divisors.is_empty());
+}
+
 #[test]
 fn out_of_bounds_access_recognition() {
     let mut state = State::new(&register("RSP"), Tid::new("func_tid"));
@@ -42,8 +42,6 @@ pub struct Context<'a, T: AbstractDomain + DomainInsertion + HasTop + Eq + From<
     pub string_symbol_map: HashMap<Tid, &'a ExternSymbol>,
     /// Maps the TIDs of functions that shall be treated as general extern symbols to the `ExternSymbol` object representing it.
     pub extern_symbol_map: HashMap<Tid, &'a ExternSymbol>,
-    /// Maps string symbols to their corresponding format string parameter index.
-    pub format_string_index_map: HashMap<String, usize>,
     /// A map to get the node index of the `BlkStart` node containing a given [`Def`] as the first `Def` of the block.
     /// The keys are of the form `(Def-TID, Current-Sub-TID)`
     /// to distinguish the nodes for blocks contained in more than one function.
@@ -97,7 +95,6 @@ impl<'a, T: AbstractDomain + HasTop + Eq + From<String> + DomainInsertion> Conte
             project,
             runtime_memory_image,
             pointer_inference_results,
-            format_string_index_map: config.format_string_index.into_iter().collect(),
             string_symbol_map,
             extern_symbol_map,
             block_start_node_map,
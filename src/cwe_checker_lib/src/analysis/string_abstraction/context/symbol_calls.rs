@@ -39,7 +39,7 @@ impl<'a, T: AbstractDomain + DomainInsertion + HasTop + Eq + From<String>> Conte
         if let Some(standard_cconv) = self.project.get_standard_calling_convention() {
             let mut filtered_map = state.get_variable_to_pointer_map().clone();
             for (register, _) in state.get_variable_to_pointer_map().clone().iter() {
-                if !standard_cconv.callee_saved_register.contains(register) {
+                if !standard_cconv.is_callee_saved(register) {
                     filtered_map.remove(register);
                 }
             }
@@ -100,8 +100,9 @@ impl<'a, T: AbstractDomain + DomainInsertion + HasTop + Eq + From<String>> Conte
     }
 
     /// Regex that filters format specifier from a format string.
+    /// The numeric width and precision fields may have arbitrary length.
     pub fn re_format_specifier() -> Regex {
-        Regex::new(r#"%\d{0,2}([c,C,d,i,o,u,x,X,e,E,f,F,g,G,a,A,n,p,s,S]|hi|hd|hu|li|ld|lu|lli|lld|llu|lf|lg|le|la|lF|lG|lE|lA|Lf|Lg|Le|La|LF|LG|LE|LA)"#).expect("No valid regex!")
+        Regex::new(r#"%\d*(\.\d*)?([c,C,d,i,o,u,x,X,e,E,f,F,g,G,a,A,n,p,s,S]|hi|hd|hu|li|ld|lu|lli|lld|llu|lf|lg|le|la|lF|lG|lE|lA|Lf|Lg|Le|La|LF|LG|LE|LA)"#).expect("No valid regex!")
     }
 
     /// Merges domains from multiple pointer targets. The merged domain serves as input to a format string.
@@ -149,8 +150,12 @@ impl<'a, T: AbstractDomain + DomainInsertion + HasTop + Eq + From<String>> Conte
     pub fn approximate_string_domain_from_datatype(specifier: String) -> T {
         match Datatype::from(specifier) {
             Datatype::Char => T::create_char_domain(),
-            Datatype::Integer => T::create_integer_domain(),
+            Datatype::Integer | Datatype::SizeT => T::create_integer_domain(),
             Datatype::Pointer => T::create_pointer_value_domain(),
+            // The `%n` specifiers do not print any characters.
+            Datatype::WritebackPointer
+            | Datatype::ShortWritebackPointer
+            | Datatype::CharWritebackPointer => T::from(String::new()),
             Datatype::Double | Datatype::Long | Datatype::LongDouble | Datatype::LongLong => {
                 T::create_float_value_domain()
             }
@@ -20,11 +20,10 @@ impl<'a, T: AbstractDomain + DomainInsertion + HasTop + Eq + From<String>> Conte
         let mut new_state = state.clone();
         if let Some(pi_state) = state.get_pointer_inference_state() {
             // Check whether the format string parameters can be parsed.
-            if let Ok(return_values) = get_variable_parameters(
+            if let Ok((return_values, _)) = get_variable_parameters(
                 self.project,
                 pi_state,
                 extern_symbol,
-                &self.format_string_index_map,
                 self.runtime_memory_image,
             ) {
                 self.create_abstract_domain_entries_for_function_return_values(
@@ -153,11 +152,10 @@ impl<'a, T: AbstractDomain + DomainInsertion + HasTop + Eq + From<String>> Conte
         extern_symbol: &ExternSymbol,
         source_string: &str,
     ) -> Result<HashMap<Arg, Option<String>>, Error> {
-        if let Ok(all_parameters) = get_variable_parameters(
+        if let Ok((all_parameters, _)) = get_variable_parameters(
             self.project,
             pi_state,
             extern_symbol,
-            &self.format_string_index_map,
             self.runtime_memory_image,
         ) {
             let return_values: Vec<String> =
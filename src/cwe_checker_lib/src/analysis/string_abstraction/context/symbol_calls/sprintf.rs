@@ -7,7 +7,7 @@ use crate::{
     },
     analysis::string_abstraction::{context::Context, state::State},
     intermediate_representation::{Arg, Datatype, ExternSymbol},
-    utils::arguments::{get_input_format_string, get_variable_parameters},
+    utils::arguments::{get_format_string_index, get_input_format_string, get_variable_parameters},
 };
 
 impl<'a, T: AbstractDomain + DomainInsertion + HasTop + Eq + From<String>> Context<'a, T> {
@@ -28,17 +28,20 @@ impl<'a, T: AbstractDomain + DomainInsertion + HasTop + Eq + From<String>> Conte
                     pi_state.eval_parameter_arg(return_arg, self.runtime_memory_image)
                 {
                     if !return_pointer.get_relative_values().is_empty() {
-                        let format_string_index = self
-                            .format_string_index_map
-                            .get(&extern_symbol.name)
-                            .unwrap();
-                        self.parse_format_string_and_add_new_string_domain(
-                            &mut new_state,
+                        if let Ok(format_string_index) = get_format_string_index(
+                            self.project,
                             pi_state,
                             extern_symbol,
-                            *format_string_index,
-                            &return_pointer,
-                        )
+                            self.runtime_memory_image,
+                        ) {
+                            self.parse_format_string_and_add_new_string_domain(
+                                &mut new_state,
+                                pi_state,
+                                extern_symbol,
+                                format_string_index,
+                                &return_pointer,
+                            )
+                        }
                     }
 
                     new_state.add_unassigned_return_pointer(return_pointer);
@@ -63,6 +66,8 @@ impl<'a, T: AbstractDomain + DomainInsertion + HasTop + Eq + From<String>> Conte
             pi_state,
             extern_symbol,
             format_string_index,
+            &self.project.datatype_properties,
+            &self.project.cpu_architecture,
             self.runtime_memory_image,
         ) {
             let returned_abstract_domain = self.create_string_domain_for_sprintf_snprintf(
@@ -104,10 +109,9 @@ impl<'a, T: AbstractDomain + DomainInsertion + HasTop + Eq + From<String>> Conte
             self.project,
             pi_state,
             extern_symbol,
-            &self.format_string_index_map,
             self.runtime_memory_image,
         ) {
-            Ok(var_args) => {
+            Ok((var_args, _)) => {
                 if var_args.is_empty() {
                     return T::create_top_value_domain();
                 }
@@ -280,11 +284,11 @@ impl<'a, T: AbstractDomain + DomainInsertion + HasTop + Eq + From<String>> Conte
         ))
     }
 
-    /// Removes the '%' character and any size number from a format specifier.
+    /// Removes the '%' character and any width or precision number from a format specifier.
     pub fn trim_format_specifier(specifier: String) -> String {
         specifier
             .as_str()
-            .trim_start_matches(&['%', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9'][..])
+            .trim_start_matches(&['%', '.', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9'][..])
             .to_string()
     }
 
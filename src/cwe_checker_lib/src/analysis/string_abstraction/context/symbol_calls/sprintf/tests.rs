@@ -258,6 +258,34 @@ fn test_push_format_specifier_approximation() {
     );
 }
 
+#[test]
+fn test_push_format_specifier_approximation_with_wide_fields() {
+    let mut domains: Vec<CharacterInclusionDomain> = vec![];
+    let format_string = "%100s, %.256s, %123d and %10.300f";
+    let re = Context::<CharacterInclusionDomain>::re_format_specifier();
+    let matches: Vec<Match> = re.find_iter(format_string).collect();
+    assert_eq!(
+        vec!["%100s", "%.256s", "%123d", "%10.300f"],
+        matches
+            .iter()
+            .map(|mat| mat.as_str())
+            .collect::<Vec<&str>>()
+    );
+    for mat in matches.into_iter() {
+        Context::<CharacterInclusionDomain>::push_format_specifier_approximation(&mut domains, mat);
+    }
+
+    assert_eq!(
+        vec![
+            CharacterInclusionDomain::Top,
+            CharacterInclusionDomain::Top,
+            CharacterInclusionDomain::create_integer_domain(),
+            CharacterInclusionDomain::create_float_value_domain(),
+        ],
+        domains
+    );
+}
+
 #[test]
 fn test_push_constant_subsequences_before_and_between_specifiers() {
     let mut domains: Vec<CharacterInclusionDomain> = vec![];
@@ -529,6 +557,14 @@ fn test_trim_format_specifier() {
         "d".to_string(),
         Context::<CharacterInclusionDomain>::trim_format_specifier("%02d".to_string())
     );
+    assert_eq!(
+        "s".to_string(),
+        Context::<CharacterInclusionDomain>::trim_format_specifier("%100.256s".to_string())
+    );
+    assert_eq!(
+        "lf".to_string(),
+        Context::<CharacterInclusionDomain>::trim_format_specifier("%.3lf".to_string())
+    );
 }
 
 #[test]
@@ -59,7 +59,6 @@ impl<'a, T: AbstractDomain + DomainInsertion + HasTop + Eq + From<String> + Debu
         let context: Context<T> = Context::mock(
             &pi_context.project,
             mock_string_symbol_map(&pi_context.project),
-            mock_format_index_map(),
             &pi_results,
             &pi_context.runtime_memory_image,
         );
@@ -85,15 +84,6 @@ impl<'a, T: AbstractDomain + DomainInsertion + HasTop + Eq + From<String> + Debu
     }
 }
 
-fn mock_format_index_map() -> HashMap<String, usize> {
-    let mut map: HashMap<String, usize> = HashMap::new();
-    map.insert("sprintf".to_string(), 1);
-    map.insert("scanf".to_string(), 0);
-    map.insert("sscanf".to_string(), 1);
-
-    map
-}
-
 fn mock_string_symbol_map(project: &Project) -> HashMap<Tid, &ExternSymbol> {
     get_symbol_map(
         project,
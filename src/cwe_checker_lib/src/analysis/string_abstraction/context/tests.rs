@@ -4,7 +4,6 @@ impl<'a, T: AbstractDomain + DomainInsertion + HasTop + Eq + From<String>> Conte
     pub fn mock(
         project: &'a Project,
         string_symbols: HashMap<Tid, &'a ExternSymbol>,
-        format_string_index: HashMap<String, usize>,
         pointer_inference_results: &'a PointerInferenceComputation<'a>,
         runtime_memory_image: &'a RuntimeMemoryImage,
     ) -> Self {
@@ -39,7 +38,6 @@ impl<'a, T: AbstractDomain + DomainInsertion + HasTop + Eq + From<String>> Conte
             pointer_inference_results,
             string_symbol_map: string_symbols,
             extern_symbol_map,
-            format_string_index_map: format_string_index,
             block_start_node_map,
             block_first_def_set,
             jmp_to_blk_end_node_map: jmp_to_blk_end_node_map,
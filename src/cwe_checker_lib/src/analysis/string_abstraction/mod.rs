@@ -1,10 +1,7 @@
 //! A fixpoint analysis that abstracts strings in the program using various string abstract domains.
 //! These include the Character Inclusion Domain and Bricks Domain among others.
 
-use std::{
-    collections::{BTreeMap, HashMap},
-    fmt::Debug,
-};
+use std::{collections::HashMap, fmt::Debug};
 
 use crate::{
     abstract_domain::{AbstractDomain, DomainInsertion, HasTop},
@@ -33,9 +30,6 @@ pub struct Config {
     /// Names of extern functions that manipulate strings
     /// or could introduce new strings (e.g. scanf).
     pub string_symbols: Vec<String>,
-    /// The index of the format string parameter in the function signature
-    /// of an external symbol.
-    pub format_string_index: BTreeMap<String, usize>,
 }
 
 /// A wrapper struct for the string abstraction computation object.
@@ -504,6 +504,7 @@ impl CallingConvention {
             integer_return_register: vec![Variable::mock("r0", 4)],
             float_return_register: vec![],
             callee_saved_register: vec![Variable::mock("r11", 4)],
+            first_stack_arg_offset: 0,
         }
     }
 }
@@ -520,6 +521,7 @@ impl DatatypeProperties {
             long_size: ByteSize::new(4),
             pointer_size: ByteSize::new(4),
             short_size: ByteSize::new(2),
+            wide_char_size: ByteSize::new(4),
         }
     }
 }
@@ -627,5 +629,6 @@ pub fn mock_project_with_intraprocedural_control_flow(
         calling_conventions: BTreeMap::from_iter([(cconv.name.clone(), cconv)]),
         register_set,
         datatype_properties: DatatypeProperties::mock_standard_arm_32(),
+        format_string_functions: FormatStringFunctions::default(),
     }
 }
@@ -15,13 +15,14 @@
 //!
 //! - symbols that take a format string parameter.
 //!
+//! The index of the format string parameter of a symbol is taken from the `FormatStringFunctions` entry
+//! of the configuration file (see [`FormatStringFunctions`](crate::intermediate_representation::FormatStringFunctions)).
+//!
 //! ## False Positives
 //!
 //! - The input was externally provided on purpose and originates from a trusted source.
 //! - A pointer target could be lost but the format string was not externally provided.
 
-use std::collections::HashMap;
-
 use petgraph::graph::NodeIndex;
 use petgraph::visit::EdgeRef;
 
@@ -31,7 +32,9 @@ use crate::analysis::interprocedural_fixpoint_generic::NodeValue;
 use crate::analysis::pointer_inference::PointerInference;
 use crate::intermediate_representation::ExternSymbol;
 use crate::intermediate_representation::Jmp;
+use crate::intermediate_representation::Project;
 use crate::prelude::*;
+use crate::utils::arguments::{get_format_string_index, resolve_relative_address};
 use crate::utils::binary::RuntimeMemoryImage;
 use crate::utils::log::CweWarning;
 use crate::utils::log::LogMessage;
@@ -49,8 +52,6 @@ pub static CWE_MODULE: CweModule = CweModule {
 pub struct Config {
     /// The names of the system call symbols.
     format_string_symbols: Vec<String>,
-    /// The index of the format string paramater of the symbol.
-    format_string_index: HashMap<String, usize>,
 }
 
 /// The categorization of the string location based on kinds of different memory.
@@ -77,7 +78,6 @@ pub fn check_cwe(
     let config: Config = serde_json::from_value(cwe_params.clone()).unwrap();
     let format_string_symbols =
         crate::utils::symbol_utils::get_symbol_map(project, &config.format_string_symbols[..]);
-    let format_string_index = config.format_string_index.clone();
 
     let pointer_inference_results = analysis_results.pointer_inference.unwrap();
     let mut cwe_warnings = Vec::new();
@@ -89,8 +89,8 @@ pub fn check_cwe(
                     let location = locate_format_string(
                         &edge.source(),
                         symbol,
-                        &format_string_index,
                         pointer_inference_results,
+                        project,
                         analysis_results.runtime_memory_image,
                     );
 
@@ -116,19 +116,27 @@ pub fn check_cwe(
 fn locate_format_string(
     node: &NodeIndex,
     symbol: &ExternSymbol,
-    format_string_index: &HashMap<String, usize>,
     pointer_inference_results: &PointerInference,
+    project: &Project,
     runtime_memory_image: &RuntimeMemoryImage,
 ) -> StringLocation {
     if let Some(NodeValue::Value(pi_state)) = pointer_inference_results.get_node_value(*node) {
-        let format_string_parameter = symbol
-            .parameters
-            .get(*format_string_index.get(&symbol.name).unwrap())
-            .unwrap();
+        let format_string_parameter =
+            match get_format_string_index(project, pi_state, symbol, runtime_memory_image)
+                .ok()
+                .and_then(|index| symbol.parameters.get(index))
+            {
+                Some(parameter) => parameter,
+                None => return StringLocation::Unknown,
+            };
         if let Ok(address) =
             pi_state.eval_parameter_arg(format_string_parameter, runtime_memory_image)
         {
-            if let Ok(address_vector) = address.try_to_bitvec() {
+            let address_vector = address.try_to_bitvec().ok().or_else(|| {
+                resolve_relative_address(&address, &project.cpu_architecture, runtime_memory_image)
+                    .and_then(|address| address.try_to_bitvec().ok())
+            });
+            if let Some(address_vector) = address_vector {
                 if runtime_memory_image.is_global_memory_address(&address_vector) {
                     if runtime_memory_image
                         .is_address_writeable(&address_vector)
@@ -224,8 +232,6 @@ pub mod tests {
         let graph = crate::analysis::graph::get_program_cfg(&project.program, HashSet::new());
         let mut pi_results = PointerInferenceComputation::mock(&project);
         pi_results.compute();
-        let mut format_string_index: HashMap<String, usize> = HashMap::new();
-        format_string_index.insert("sprintf".to_string(), 1);
         // Get the BlkEnd node with the function call.
         let node = graph
             .node_indices()
@@ -239,8 +245,8 @@ pub mod tests {
             locate_format_string(
                 &node,
                 &sprintf_symbol,
-                &format_string_index,
                 &pi_results,
+                &project,
                 &runtime_memory_image,
             ),
             StringLocation::GlobalReadable
@@ -321,11 +321,7 @@ impl State {
             .register_taint
             .iter()
             .filter_map(|(register, taint)| {
-                if calling_conv
-                    .callee_saved_register
-                    .iter()
-                    .any(|callee_saved_reg| register == callee_saved_reg)
-                {
+                if calling_conv.is_callee_saved(register) {
                     Some((register.clone(), *taint))
                 } else {
                     None
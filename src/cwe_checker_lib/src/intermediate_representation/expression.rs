@@ -5,6 +5,7 @@ use super::{ByteSize, Def};
 use crate::{pcode::RegisterProperties, prelude::*};
 
 mod builder;
+mod constant_folding;
 mod trivial_operation_substitution;
 
 /// An expression is a calculation rule
@@ -147,6 +148,19 @@ pub enum UnOpType {
     FloatNaN,
 }
 
+/// A classification of expressions by how their value is computed from their input variables,
+/// see [`Expression::kind`].
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum ExpressionKind {
+    /// The expression does not depend on any variable, i.e. all of its sub-expressions are constants.
+    Constant,
+    /// The expression copies the value of a variable or a part of it (via [`Expression::Subpiece`])
+    /// without modifying it.
+    Copy,
+    /// Any other expression, e.g. an arithmetic combination of variables and constants.
+    Arithmetic,
+}
+
 impl Expression {
     /// Return the size (in bytes) of the result value of the expression.
     pub fn bytesize(&self) -> ByteSize {
@@ -188,6 +202,29 @@ impl Expression {
         }
     }
 
+    /// Classify the expression into constants, (partial) copies of a variable and all other expressions.
+    ///
+    /// Copies are e.g. generated when saving a callee-saved register to the stack,
+    /// where the subpiece of a register occurs when saving a sub-register.
+    /// An [`Expression::Unknown`] is an [`ExpressionKind::Arithmetic`] expression without input variables.
+    pub fn kind(&self) -> ExpressionKind {
+        use Expression::*;
+        match self {
+            Const(_) => ExpressionKind::Constant,
+            Var(_) => ExpressionKind::Copy,
+            Unknown { .. } => ExpressionKind::Arithmetic,
+            Subpiece { arg, .. } => arg.kind(),
+            UnOp { arg, .. } | Cast { arg, .. } => match arg.kind() {
+                ExpressionKind::Constant => ExpressionKind::Constant,
+                _ => ExpressionKind::Arithmetic,
+            },
+            BinOp { lhs, rhs, .. } => match (lhs.kind(), rhs.kind()) {
+                (ExpressionKind::Constant, ExpressionKind::Constant) => ExpressionKind::Constant,
+                _ => ExpressionKind::Arithmetic,
+            },
+        }
+    }
+
     /// Substitute every occurence of `input_var` in `self` with the given `replace_with_expression`.
     pub fn substitute_input_var(
         &mut self,
@@ -0,0 +1,149 @@
+use super::*;
+
+impl Expression {
+    /// Substitute trivial expressions with their result and fold constant subexpressions,
+    /// so that the expression is brought into a canonical form.
+    ///
+    /// See [`substitute_trivial_operations`](Self::substitute_trivial_operations)
+    /// and [`fold_constants`](Self::fold_constants) for the performed simplifications.
+    pub fn simplify(&mut self) {
+        self.substitute_trivial_operations();
+        self.fold_constants();
+    }
+
+    /// Replace subexpressions with only constant inputs by their result
+    /// and remove identity operations like `x + 0` or `x * 1`.
+    /// Chains of additions and subtractions of constants like `(x + 4) - 8`
+    /// are combined into a single addition, i.e. `x + (-4)`.
+    ///
+    /// Operations that cannot be evaluated on bitvectors (e.g. float operations or divisions by zero)
+    /// and operations with inputs of inconsistent sizes are not folded.
+    pub fn fold_constants(&mut self) {
+        use Expression::*;
+        match self {
+            Var(_) | Const(_) | Unknown { .. } => (),
+            Subpiece {
+                low_byte,
+                size,
+                arg,
+            } => {
+                arg.fold_constants();
+                if let Const(bitvec) = &**arg {
+                    if *low_byte + *size <= bitvec.bytesize() {
+                        *self = Const(bitvec.subpiece(*low_byte, *size));
+                    }
+                }
+            }
+            Cast { op, size, arg } => {
+                arg.fold_constants();
+                if let Const(bitvec) = &**arg {
+                    let is_foldable = match op {
+                        CastOpType::IntZExt | CastOpType::IntSExt => *size >= bitvec.bytesize(),
+                        CastOpType::PopCount => *size <= ByteSize::new(8),
+                        CastOpType::Int2Float | CastOpType::Float2Float | CastOpType::Trunc => {
+                            false
+                        }
+                    };
+                    if is_foldable {
+                        if let Ok(result) = bitvec.cast(*op, *size) {
+                            *self = Const(result);
+                        }
+                    }
+                }
+            }
+            UnOp { op, arg } => {
+                arg.fold_constants();
+                if let Const(bitvec) = &**arg {
+                    // Boolean negation is only defined for the values 0 and 1.
+                    if *op == UnOpType::BoolNegate
+                        && (bitvec.bytesize() != ByteSize::new(1)
+                            || !(bitvec.is_zero() || bitvec.is_one()))
+                    {
+                        return;
+                    }
+                    if let Ok(result) = bitvec.un_op(*op) {
+                        *self = Const(result);
+                    }
+                }
+            }
+            BinOp { .. } => self.fold_constant_binop(),
+        }
+    }
+
+    /// Fold the constants of a `BinOp` expression.
+    /// See [`fold_constants`](Self::fold_constants) for more information.
+    fn fold_constant_binop(&mut self) {
+        use BinOpType::*;
+        use Expression::*;
+        if let BinOp { op, lhs, rhs } = self {
+            lhs.fold_constants();
+            rhs.fold_constants();
+            let is_shift = matches!(op, IntLeft | IntRight | IntSRight);
+            if !is_shift && *op != Piece && lhs.bytesize() != rhs.bytesize() {
+                return;
+            }
+            match (&**lhs, *op, &**rhs) {
+                (Const(left), op, Const(right)) => {
+                    if is_shift && right.try_to_u64().is_err() {
+                        return;
+                    }
+                    if let Ok(result) = left.bin_op(op, right) {
+                        *self = Const(result);
+                    }
+                }
+                (Const(bitvec), IntAdd, other)
+                | (other, IntAdd | IntSub | IntLeft | IntRight | IntSRight, Const(bitvec))
+                    if bitvec.is_zero() =>
+                {
+                    // `x + 0 = x`, `x - 0 = x` and `x << 0 = x`
+                    *self = other.clone();
+                }
+                (Const(bitvec), IntMult, other)
+                | (other, IntMult | IntDiv | IntSDiv, Const(bitvec))
+                    if bitvec.is_one() =>
+                {
+                    // `x * 1 = x` and `x / 1 = x`
+                    *self = other.clone();
+                }
+                (Const(bitvec), IntMult, _) | (_, IntMult, Const(bitvec)) if bitvec.is_zero() => {
+                    // `x * 0 = 0`
+                    *self = Const(bitvec.clone());
+                }
+                (
+                    BinOp {
+                        op: inner_op @ (IntAdd | IntSub),
+                        lhs: inner_lhs,
+                        rhs: inner_rhs,
+                    },
+                    op @ (IntAdd | IntSub),
+                    Const(outer_const),
+                ) => {
+                    match &**inner_rhs {
+                        Const(inner_const) if inner_const.width() == outer_const.width() => {
+                            // `(x + c1) + c2 = x + (c1 + c2)` and analogously for subtractions.
+                            let inner_offset = match inner_op {
+                                IntAdd => inner_const.clone(),
+                                _ => -inner_const.clone(),
+                            };
+                            let offset = match op {
+                                IntAdd => inner_offset + outer_const,
+                                _ => inner_offset - outer_const,
+                            };
+                            *self = if offset.is_zero() {
+                                (**inner_lhs).clone()
+                            } else {
+                                BinOp {
+                                    op: IntAdd,
+                                    lhs: inner_lhs.clone(),
+                                    rhs: Box::new(Const(offset)),
+                                }
+                            };
+                        }
+                        _ => (),
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+}
@@ -516,3 +516,128 @@ fn processing_sub_registers() {
     expr.cast_sub_registers_to_base_register_subpieces(output, &register_map, peeked);
     assert_eq!(expr, setup.int_sub_subpiece_expr);
 }
+
+#[test]
+fn constant_folding() {
+    let rax = Expression::var("RAX", 8);
+    let constant = |value: i64| Expression::Const(Bitvector::from_i64(value));
+
+    // Constant subexpressions are folded.
+    let mut expr = rax.clone().plus(constant(2).plus(constant(3)));
+    expr.fold_constants();
+    assert_eq!(expr, rax.clone().plus_const(5));
+    let mut expr = Expression::Const(Bitvector::from_u32(0x1234)).un_op(UnOpType::IntNegate);
+    expr.fold_constants();
+    assert_eq!(expr, Expression::Const(Bitvector::from_u32(!0x1234)));
+    let mut expr = Expression::Const(Bitvector::from_i32(-1)).cast(CastOpType::IntSExt);
+    expr.fold_constants();
+    assert_eq!(expr, constant(-1));
+    let mut expr = constant(0x1122).subpiece(ByteSize::new(1), ByteSize::new(1));
+    expr.fold_constants();
+    assert_eq!(expr, Expression::Const(Bitvector::from_u8(0x11)));
+
+    // Identity operations are removed.
+    for op in [BinOpType::IntAdd, BinOpType::IntSub, BinOpType::IntLeft] {
+        let mut expr = Expression::BinOp {
+            op,
+            lhs: Box::new(rax.clone()),
+            rhs: Box::new(constant(0)),
+        };
+        expr.fold_constants();
+        assert_eq!(expr, rax);
+    }
+    for op in [BinOpType::IntMult, BinOpType::IntDiv, BinOpType::IntSDiv] {
+        let mut expr = Expression::BinOp {
+            op,
+            lhs: Box::new(rax.clone()),
+            rhs: Box::new(constant(1)),
+        };
+        expr.fold_constants();
+        assert_eq!(expr, rax);
+    }
+    let mut expr = Expression::BinOp {
+        op: BinOpType::IntMult,
+        lhs: Box::new(constant(0)),
+        rhs: Box::new(rax.clone()),
+    };
+    expr.fold_constants();
+    assert_eq!(expr, constant(0));
+
+    // Chains of constant additions and subtractions are combined.
+    let mut expr = rax.clone().plus_const(4).minus_const(12);
+    expr.fold_constants();
+    assert_eq!(expr, rax.clone().plus_const(-8));
+    let mut expr = rax.clone().minus_const(8).plus_const(8);
+    expr.fold_constants();
+    assert_eq!(expr, rax);
+
+    // Operations that cannot be evaluated are not folded.
+    let mut expr = Expression::BinOp {
+        op: BinOpType::IntDiv,
+        lhs: Box::new(constant(1)),
+        rhs: Box::new(constant(0)),
+    };
+    let expected = expr.clone();
+    expr.fold_constants();
+    assert_eq!(expr, expected);
+    let mut expr = Expression::BinOp {
+        op: BinOpType::FloatAdd,
+        lhs: Box::new(constant(1)),
+        rhs: Box::new(constant(2)),
+    };
+    let expected = expr.clone();
+    expr.fold_constants();
+    assert_eq!(expr, expected);
+    let mut expr = Expression::Const(Bitvector::from_u8(2)).un_op(UnOpType::BoolNegate);
+    let expected = expr.clone();
+    expr.fold_constants();
+    assert_eq!(expr, expected);
+}
+
+#[test]
+fn expression_simplification() {
+    let rax = Expression::var("RAX", 8);
+    // `(RAX xor RAX) + 5` is simplified to `5`.
+    let mut expr = Expression::BinOp {
+        op: BinOpType::IntXOr,
+        lhs: Box::new(rax.clone()),
+        rhs: Box::new(rax),
+    }
+    .plus_const(5);
+    expr.simplify();
+    assert_eq!(expr, Expression::Const(Bitvector::from_i64(5)));
+}
+
+#[test]
+fn expression_kind() {
+    let rax = Expression::var("RAX", 8);
+    assert_eq!(rax.kind(), ExpressionKind::Copy);
+    assert_eq!(
+        rax.clone()
+            .subpiece(ByteSize::new(0), ByteSize::new(4))
+            .kind(),
+        ExpressionKind::Copy
+    );
+    assert_eq!(rax.clone().plus_const(8).kind(), ExpressionKind::Arithmetic);
+    assert_eq!(
+        rax.cast(CastOpType::IntZExt).kind(),
+        ExpressionKind::Arithmetic
+    );
+    let constant = Expression::Const(Bitvector::from_i64(5));
+    assert_eq!(constant.kind(), ExpressionKind::Constant);
+    assert_eq!(
+        constant
+            .plus_const(3)
+            .subpiece(ByteSize::new(0), ByteSize::new(4))
+            .kind(),
+        ExpressionKind::Constant
+    );
+    assert_eq!(
+        Expression::Unknown {
+            description: "unknown".to_string(),
+            size: ByteSize::new(8)
+        }
+        .kind(),
+        ExpressionKind::Arithmetic
+    );
+}
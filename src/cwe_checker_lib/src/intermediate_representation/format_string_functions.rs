@@ -0,0 +1,222 @@
+use super::Datatype;
+use crate::prelude::*;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The extern functions that take a format string parameter,
+/// e.g. `printf`-like functions or custom logging functions wrapping them.
+///
+/// The standard C library functions are known by default.
+/// Additional functions (e.g. in-house wrappers around `printf`) can be registered at runtime
+/// through the `FormatStringFunctions` entry of the configuration file.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct FormatStringFunctions {
+    /// Maps the names of format string functions to the index of their format string parameter.
+    #[serde(default)]
+    pub format_string_index: BTreeMap<String, usize>,
+    /// Maps the names of bounded format string functions like `snprintf`
+    /// to the index of their buffer size parameter.
+    #[serde(default)]
+    pub size_parameter_index: BTreeMap<String, usize>,
+    /// The names of `scanf`-like functions,
+    /// i.e. functions whose variable parameters are pointers to the locations that parsed values are written to.
+    #[serde(default)]
+    pub scanf_like: BTreeSet<String>,
+    /// The names of functions whose format string is a wide character string, e.g. `wprintf`.
+    #[serde(default)]
+    pub wide_character: BTreeSet<String>,
+    /// Non-standard conversion characters of `printf`-like functions (e.g. the GNU `%b` binary conversion)
+    /// mapped to the data type of their argument.
+    #[serde(default)]
+    pub extra_conversions: BTreeMap<char, Datatype>,
+}
+
+/// The format string parameter indices of the standard C library format string functions.
+const STANDARD_FORMAT_STRING_INDEX: &[(&str, usize)] = &[
+    ("printf", 0),
+    ("fprintf", 1),
+    ("dprintf", 1),
+    ("sprintf", 1),
+    ("snprintf", 2),
+    ("vprintf", 0),
+    ("vfprintf", 1),
+    ("vdprintf", 1),
+    ("vsprintf", 1),
+    ("vsnprintf", 2),
+    ("syslog", 1),
+    ("vsyslog", 1),
+    ("wprintf", 0),
+    ("fwprintf", 1),
+    ("swprintf", 2),
+    ("vwprintf", 0),
+    ("vfwprintf", 1),
+    ("vswprintf", 2),
+    ("scanf", 0),
+    ("__isoc99_scanf", 0),
+    ("fscanf", 1),
+    ("__isoc99_fscanf", 1),
+    ("sscanf", 1),
+    ("__isoc99_sscanf", 1),
+    ("vscanf", 0),
+    ("vfscanf", 1),
+    ("vsscanf", 1),
+    ("__isoc99_vsscanf", 1),
+    ("wscanf", 0),
+    ("fwscanf", 1),
+    ("swscanf", 1),
+];
+
+/// The non-standard conversions of `printf`-like functions that are known by default.
+/// `%b` and `%B` print unsigned integers in binary (supported by glibc since version 2.35).
+const STANDARD_EXTRA_CONVERSIONS: &[(char, Datatype)] =
+    &[('b', Datatype::Integer), ('B', Datatype::Integer)];
+
+/// The buffer size parameter indices of the standard C library bounded format string functions.
+const STANDARD_SIZE_PARAMETER_INDEX: &[(&str, usize)] = &[
+    ("snprintf", 1),
+    ("vsnprintf", 1),
+    ("swprintf", 1),
+    ("vswprintf", 1),
+];
+
+impl FormatStringFunctions {
+    /// Create the list of the format string functions of the standard C library.
+    pub fn new() -> Self {
+        let format_string_index: BTreeMap<String, usize> = STANDARD_FORMAT_STRING_INDEX
+            .iter()
+            .map(|(name, index)| (name.to_string(), *index))
+            .collect();
+        let size_parameter_index = STANDARD_SIZE_PARAMETER_INDEX
+            .iter()
+            .map(|(name, index)| (name.to_string(), *index))
+            .collect();
+        let scanf_like = format_string_index
+            .keys()
+            .filter(|name| name.contains("scanf"))
+            .cloned()
+            .collect();
+        let wide_character = format_string_index
+            .keys()
+            .filter(|name| name.contains("wprintf") || name.contains("wscanf"))
+            .cloned()
+            .collect();
+        FormatStringFunctions {
+            format_string_index,
+            size_parameter_index,
+            scanf_like,
+            wide_character,
+            extra_conversions: STANDARD_EXTRA_CONVERSIONS.iter().cloned().collect(),
+        }
+    }
+
+    /// Get the index of the format string parameter of the given function.
+    /// Returns `None` if the function is not a known format string function.
+    pub fn get_format_string_index(&self, function_name: &str) -> Option<usize> {
+        self.format_string_index.get(function_name).copied()
+    }
+
+    /// Get the index of the buffer size parameter of the given function.
+    /// Returns `None` if the function is not a known bounded format string function.
+    pub fn get_size_parameter_index(&self, function_name: &str) -> Option<usize> {
+        self.size_parameter_index.get(function_name).copied()
+    }
+
+    /// Returns true if the given function is registered as a `scanf`-like function.
+    pub fn is_scanf_like(&self, function_name: &str) -> bool {
+        self.scanf_like.contains(function_name)
+    }
+
+    /// Returns true if the given function is registered as a wide character format string function.
+    pub fn is_wide_character(&self, function_name: &str) -> bool {
+        self.wide_character.contains(function_name)
+    }
+
+    /// Get the data type of the argument of the given non-standard `printf` conversion character.
+    /// Returns `None` if the conversion is not registered as an extra conversion.
+    pub fn get_extra_conversion_datatype(&self, conversion: char) -> Option<&Datatype> {
+        self.extra_conversions.get(&conversion)
+    }
+
+    /// Register a format string function with the given index of its format string parameter.
+    /// Overwrites the index if the function is already known.
+    pub fn register_format_string_function(&mut self, function_name: &str, index: usize) {
+        self.format_string_index
+            .insert(function_name.to_string(), index);
+    }
+
+    /// Add all functions of `other` to `self`.
+    /// The parameter indices of `other` take precedence for functions contained in both lists.
+    pub fn extend(&mut self, other: FormatStringFunctions) {
+        self.format_string_index.extend(other.format_string_index);
+        self.size_parameter_index.extend(other.size_parameter_index);
+        self.scanf_like.extend(other.scanf_like);
+        self.wide_character.extend(other.wide_character);
+        self.extra_conversions.extend(other.extra_conversions);
+    }
+}
+
+impl Default for FormatStringFunctions {
+    /// Returns the format string functions of the standard C library (see [`FormatStringFunctions::new`]).
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn standard_format_string_functions() {
+        let functions = FormatStringFunctions::default();
+        assert_eq!(functions.get_format_string_index("sprintf"), Some(1));
+        assert_eq!(functions.get_format_string_index("my_log"), None);
+        assert_eq!(functions.get_size_parameter_index("snprintf"), Some(1));
+        assert_eq!(functions.get_size_parameter_index("sprintf"), None);
+        assert!(functions.is_scanf_like("__isoc99_sscanf"));
+        assert!(!functions.is_scanf_like("printf"));
+        assert!(functions.is_wide_character("swprintf"));
+        assert!(!functions.is_wide_character("sprintf"));
+        assert_eq!(
+            functions.get_extra_conversion_datatype('b'),
+            Some(&Datatype::Integer)
+        );
+        assert_eq!(functions.get_extra_conversion_datatype('k'), None);
+    }
+
+    #[test]
+    fn new_equals_default() {
+        assert_eq!(
+            FormatStringFunctions::new(),
+            FormatStringFunctions::default()
+        );
+    }
+
+    #[test]
+    fn register_custom_functions() {
+        let mut functions = FormatStringFunctions::default();
+        functions.register_format_string_function("my_log", 2);
+        assert_eq!(functions.get_format_string_index("my_log"), Some(2));
+
+        let custom: FormatStringFunctions = serde_json::from_str(
+            r#"{
+                "format_string_index": { "my_scan": 1, "sprintf": 3 },
+                "scanf_like": [ "my_scan" ],
+                "extra_conversions": { "k": "Pointer" }
+            }"#,
+        )
+        .unwrap();
+        functions.extend(custom);
+        assert_eq!(functions.get_format_string_index("my_scan"), Some(1));
+        assert_eq!(functions.get_format_string_index("sprintf"), Some(3));
+        assert!(functions.is_scanf_like("my_scan"));
+        assert_eq!(functions.get_size_parameter_index("snprintf"), Some(1));
+        assert_eq!(
+            functions.get_extra_conversion_datatype('k'),
+            Some(&Datatype::Pointer)
+        );
+        assert_eq!(
+            functions.get_extra_conversion_datatype('b'),
+            Some(&Datatype::Integer)
+        );
+    }
+}
@@ -30,6 +30,8 @@ mod program;
 pub use program::*;
 mod project;
 pub use project::*;
+mod format_string_functions;
+pub use format_string_functions::*;
 
 /// An unsigned number of bytes.
 ///
@@ -120,6 +122,15 @@ pub struct DatatypeProperties {
     pub pointer_size: ByteSize,
     /// Holds the size of the short type
     pub short_size: ByteSize,
+    /// Holds the size of the wide character type `wchar_t`.
+    /// If not given, the size on Unix-like ABIs (4 bytes) is used.
+    #[serde(default = "default_wide_char_size")]
+    pub wide_char_size: ByteSize,
+}
+
+/// The size of the wide character type `wchar_t` on Unix-like ABIs.
+fn default_wide_char_size() -> ByteSize {
+    ByteSize::new(4)
 }
 
 impl DatatypeProperties {
@@ -135,6 +146,31 @@ impl DatatypeProperties {
             Datatype::Long => self.long_size,
             Datatype::Pointer => self.pointer_size,
             Datatype::Short => self.short_size,
+            Datatype::SizeT => self.pointer_size,
+            Datatype::WritebackPointer
+            | Datatype::ShortWritebackPointer
+            | Datatype::CharWritebackPointer
+            | Datatype::GenericWritebackPointer => self.pointer_size,
+        }
+    }
+
+    /// Returns the natural alignment of the given data type,
+    /// i.e. its size rounded up to the next power of two.
+    pub fn get_alignment_of_data_type(&self, data_type: Datatype) -> ByteSize {
+        u64::from(self.get_size_from_data_type(data_type))
+            .next_power_of_two()
+            .into()
+    }
+
+    /// Returns the size of the value that the callee writes to for writeback pointer data types.
+    /// Returns `None` if the data type is not a writeback pointer
+    /// or if the size of the pointed-to value is not tracked by the data type.
+    pub fn get_writeback_target_size(&self, data_type: &Datatype) -> Option<ByteSize> {
+        match data_type {
+            Datatype::WritebackPointer => Some(self.integer_size),
+            Datatype::ShortWritebackPointer => Some(self.short_size),
+            Datatype::CharWritebackPointer => Some(self.char_size),
+            _ => None,
         }
     }
 }
@@ -160,6 +196,121 @@ pub enum Datatype {
     Pointer,
     /// C short data type
     Short,
+    /// C `size_t` data type, also used for the `ptrdiff_t` data type.
+    /// Both types have the size of a pointer.
+    SizeT,
+    /// C pointer data type to an integer that the callee writes to, e.g. for the `%n` format specifier
+    WritebackPointer,
+    /// C pointer data type to a short that the callee writes to, e.g. for the `%hn` format specifier
+    ShortWritebackPointer,
+    /// C pointer data type to a char that the callee writes to, e.g. for the `%hhn` format specifier
+    CharWritebackPointer,
+    /// C pointer data type to a value of some other type that the callee writes to,
+    /// e.g. for the `%lf` format specifier of `scanf`
+    GenericWritebackPointer,
+}
+
+impl Datatype {
+    /// Convert a length modifier and a conversion character of a `printf`-like format string
+    /// into the data type of the corresponding variadic argument.
+    ///
+    /// The supported length modifiers are `h`, `hh`, `l`, `ll`, `L`, `z`, `j` and `t`.
+    /// Since char and short values undergo the default argument promotion,
+    /// the `h` and `hh` modifiers do not change the data type of integer arguments.
+    /// The `size_t` and `ptrdiff_t` types of the `z` and `t` modifiers are mapped to [`Datatype::SizeT`]
+    /// and the `intmax_t` type of the `j` modifier is mapped to `long long`.
+    /// All floating point conversions (including the hexadecimal `%a` and `%A` conversions)
+    /// take `double` arguments, since `float` values are promoted to `double`,
+    /// or `long double` arguments if the `L` modifier is present.
+    ///
+    /// Returns an error for unknown or invalid combinations of length modifier and conversion.
+    pub fn from_format_specifier(modifier: &str, conversion: char) -> Result<Self, Error> {
+        let data_type = match (modifier, conversion) {
+            ("", 'c' | 'C') => Datatype::Char,
+            ("l", 'c') => Datatype::Integer,
+            ("" | "l", 's' | 'S') => Datatype::Pointer,
+            ("", 'p') => Datatype::Integer,
+            (modifier, 'd' | 'i' | 'o' | 'u' | 'x' | 'X') => match modifier {
+                "" | "h" | "hh" => Datatype::Integer,
+                "l" => Datatype::Long,
+                "z" | "t" => Datatype::SizeT,
+                "ll" | "j" => Datatype::LongLong,
+                _ => return Err(anyhow!("Invalid length modifier {}", modifier)),
+            },
+            (modifier, 'n') => match modifier {
+                "" => Datatype::WritebackPointer,
+                "h" => Datatype::ShortWritebackPointer,
+                "hh" => Datatype::CharWritebackPointer,
+                "l" | "ll" | "z" | "j" | "t" => Datatype::GenericWritebackPointer,
+                _ => return Err(anyhow!("Invalid length modifier {}", modifier)),
+            },
+            ("" | "l", 'f' | 'F' | 'e' | 'E' | 'g' | 'G' | 'a' | 'A') => Datatype::Double,
+            ("L", 'f' | 'F' | 'e' | 'E' | 'g' | 'G' | 'a' | 'A') => Datatype::LongDouble,
+            _ => {
+                return Err(anyhow!(
+                    "Invalid format specifier {}{}",
+                    modifier,
+                    conversion
+                ))
+            }
+        };
+        Ok(data_type)
+    }
+
+    /// Convert a length modifier and a conversion character of a `scanf`-like format string
+    /// into the data type of the corresponding variadic argument.
+    ///
+    /// In contrast to `printf`-like functions every variadic argument of `scanf`-like functions
+    /// is a pointer to the location where the parsed value is written to.
+    /// Strings are written to buffers, which are represented by [`Datatype::Pointer`].
+    /// All other conversions are mapped to writeback pointer types.
+    ///
+    /// Returns an error for unknown or invalid combinations of length modifier and conversion.
+    pub fn from_scanf_format_specifier(modifier: &str, conversion: char) -> Result<Self, Error> {
+        let data_type = match (modifier, conversion) {
+            ("" | "l", 's' | 'S') => Datatype::Pointer,
+            ("", 'c' | 'C') => Datatype::CharWritebackPointer,
+            ("l", 'c') | ("", 'p') => Datatype::GenericWritebackPointer,
+            (modifier, 'd' | 'i' | 'o' | 'u' | 'x' | 'X' | 'n') => match modifier {
+                "" => Datatype::WritebackPointer,
+                "h" => Datatype::ShortWritebackPointer,
+                "hh" => Datatype::CharWritebackPointer,
+                "l" | "ll" | "z" | "j" | "t" => Datatype::GenericWritebackPointer,
+                _ => return Err(anyhow!("Invalid length modifier {}", modifier)),
+            },
+            ("" | "l" | "L", 'f' | 'F' | 'e' | 'E' | 'g' | 'G' | 'a' | 'A') => {
+                Datatype::GenericWritebackPointer
+            }
+            _ => {
+                return Err(anyhow!(
+                    "Invalid format specifier {}{}",
+                    modifier,
+                    conversion
+                ))
+            }
+        };
+        Ok(data_type)
+    }
+
+    /// Convert a conversion specifier of a `scanf`-like format string
+    /// (consisting of the length modifier and the conversion character) into a data type.
+    ///
+    /// See [`Datatype::from_scanf_format_specifier`] for the used data types.
+    /// Panics if the specifier is not a valid conversion specifier.
+    pub fn from_scanf_specifier(specifier: &str) -> Self {
+        let (modifier, conversion) = split_format_specifier(specifier);
+        Datatype::from_scanf_format_specifier(modifier, conversion)
+            .expect("Invalid data type specifier from format string.")
+    }
+}
+
+/// Split a conversion specifier into its length modifier and its conversion character.
+fn split_format_specifier(specifier: &str) -> (&str, char) {
+    let mut chars = specifier.chars();
+    let conversion = chars
+        .next_back()
+        .expect("Invalid data type specifier from format string.");
+    (chars.as_str(), conversion)
 }
 
 impl From<String> for Datatype {
@@ -167,18 +318,12 @@ impl From<String> for Datatype {
     /// Therefore, char types have to be mapped to the integer size since they undergo the default
     /// argument promotion. (e.g. 1 byte char -> 4 byte integer)
     /// The same holds for all float types that are promoted to doubles. (e.g. 8 byte float -> 16 byte double)
+    ///
+    /// See [`Datatype::from_format_specifier`] for the used data types.
     fn from(specifier: String) -> Self {
-        match specifier.as_str() {
-            "c" | "C" => Datatype::Char,
-            "d" | "i" | "u" | "o" | "p" | "x" | "X" | "hi" | "hd" | "hu" => Datatype::Integer,
-            "s" | "S" | "n" => Datatype::Pointer,
-            "lf" | "lg" | "le" | "la" | "lF" | "lG" | "lE" | "lA" | "f" | "F" | "e" | "E" | "a"
-            | "A" | "g" | "G" => Datatype::Double,
-            "li" | "ld" | "lu" => Datatype::Long,
-            "lli" | "lld" | "llu" => Datatype::LongLong,
-            "Lf" | "Lg" | "Le" | "La" | "LF" | "LG" | "LE" | "LA" => Datatype::LongDouble,
-            _ => panic!("Invalid data type specifier from format string."),
-        }
+        let (modifier, conversion) = split_format_specifier(&specifier);
+        Datatype::from_format_specifier(modifier, conversion)
+            .expect("Invalid data type specifier from format string.")
     }
 }
 
@@ -199,8 +344,62 @@ mod tests {
                 long_size: ByteSize::new(4),
                 pointer_size: ByteSize::new(8),
                 short_size: ByteSize::new(2),
+                wide_char_size: ByteSize::new(4),
             }
         }
+
+        /// Datatype properties of the System V ABI for x86-64.
+        pub fn mock_x64() -> DatatypeProperties {
+            DatatypeProperties {
+                char_size: ByteSize::new(1),
+                double_size: ByteSize::new(8),
+                float_size: ByteSize::new(4),
+                integer_size: ByteSize::new(4),
+                long_double_size: ByteSize::new(16),
+                long_long_size: ByteSize::new(8),
+                long_size: ByteSize::new(8),
+                pointer_size: ByteSize::new(8),
+                short_size: ByteSize::new(2),
+                wide_char_size: ByteSize::new(4),
+            }
+        }
+
+        /// Datatype properties of the AAPCS for 32-bit ARM.
+        pub fn mock_arm32() -> DatatypeProperties {
+            DatatypeProperties {
+                char_size: ByteSize::new(1),
+                double_size: ByteSize::new(8),
+                float_size: ByteSize::new(4),
+                integer_size: ByteSize::new(4),
+                long_double_size: ByteSize::new(8),
+                long_long_size: ByteSize::new(8),
+                long_size: ByteSize::new(4),
+                pointer_size: ByteSize::new(4),
+                short_size: ByteSize::new(2),
+                wide_char_size: ByteSize::new(4),
+            }
+        }
+    }
+
+    #[test]
+    fn datatype_properties_without_wide_char_size() {
+        let properties: DatatypeProperties = serde_json::from_str(
+            r#"
+            {
+                "char_size": 1,
+                "double_size": 8,
+                "float_size": 4,
+                "integer_size": 4,
+                "long_double_size": 8,
+                "long_long_size": 8,
+                "long_size": 4,
+                "pointer_size": 8,
+                "short_size": 2
+            }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(properties, DatatypeProperties::mock());
     }
 
     #[test]
@@ -213,4 +412,51 @@ mod tests {
 
         assert_eq!(ByteSize::new(2).as_bit_length(), 16);
     }
+
+    #[test]
+    fn datatype_from_format_specifier() {
+        assert_eq!(
+            Datatype::from_format_specifier("", 'c').unwrap(),
+            Datatype::Char
+        );
+        assert_eq!(
+            Datatype::from_format_specifier("hh", 'd').unwrap(),
+            Datatype::Integer
+        );
+        assert_eq!(
+            Datatype::from_format_specifier("l", 'x').unwrap(),
+            Datatype::Long
+        );
+        assert_eq!(
+            Datatype::from_format_specifier("z", 'u').unwrap(),
+            Datatype::SizeT
+        );
+        assert_eq!(
+            Datatype::from_format_specifier("t", 'd').unwrap(),
+            Datatype::SizeT
+        );
+        assert_eq!(
+            Datatype::from_format_specifier("j", 'd').unwrap(),
+            Datatype::LongLong
+        );
+        assert_eq!(
+            Datatype::from_format_specifier("ll", 'n').unwrap(),
+            Datatype::GenericWritebackPointer
+        );
+        assert_eq!(
+            Datatype::from_format_specifier("L", 'g').unwrap(),
+            Datatype::LongDouble
+        );
+        assert!(Datatype::from_format_specifier("L", 'd').is_err());
+        assert!(Datatype::from_format_specifier("z", 's').is_err());
+        assert_eq!(
+            Datatype::from_scanf_format_specifier("hh", 'u').unwrap(),
+            Datatype::CharWritebackPointer
+        );
+        assert_eq!(
+            Datatype::from_scanf_format_specifier("z", 'u').unwrap(),
+            Datatype::GenericWritebackPointer
+        );
+        assert_eq!(Datatype::from("lld".to_string()), Datatype::LongLong);
+    }
 }
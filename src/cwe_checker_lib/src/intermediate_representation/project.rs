@@ -24,6 +24,9 @@ pub struct Project {
     pub register_set: BTreeSet<Variable>,
     /// Contains the properties of C data types. (e.g. size)
     pub datatype_properties: DatatypeProperties,
+    /// The known extern functions that take a format string parameter.
+    #[serde(default)]
+    pub format_string_functions: FormatStringFunctions,
 }
 
 impl Project {
@@ -64,22 +67,36 @@ impl Project {
             self.get_standard_calling_convention().unwrap()
         }
     }
+
+    /// Return the extern symbol that is located at the given address,
+    /// e.g. because the address is the address of the PLT stub of the symbol in a dynamically linked ELF binary.
+    ///
+    /// Returns `None` if the address is not one of the addresses of an extern symbol.
+    pub fn get_extern_symbol_at_address(&self, address: &Bitvector) -> Option<&ExternSymbol> {
+        let address = address.try_to_u64().ok()?;
+        self.program.term.extern_symbols.values().find(|symbol| {
+            symbol.addresses.iter().any(|symbol_address| {
+                u64::from_str_radix(symbol_address.trim_start_matches("0x"), 16).ok()
+                    == Some(address)
+            })
+        })
+    }
 }
 
 impl Project {
     /// For all expressions contained in the project,
-    /// replace trivially computable subexpressions like `a XOR a` with their result.
+    /// replace trivially computable subexpressions like `a XOR a` or `4 + 8` with their result.
     fn substitute_trivial_expressions(&mut self) {
         for sub in self.program.term.subs.values_mut() {
             for block in sub.term.blocks.iter_mut() {
                 for def in block.term.defs.iter_mut() {
                     match &mut def.term {
                         Def::Assign { value: expr, .. } | Def::Load { address: expr, .. } => {
-                            expr.substitute_trivial_operations()
+                            expr.simplify()
                         }
                         Def::Store { address, value } => {
-                            address.substitute_trivial_operations();
-                            value.substitute_trivial_operations();
+                            address.simplify();
+                            value.simplify();
                         }
                     }
                 }
@@ -91,7 +108,7 @@ impl Project {
                             condition: expr, ..
                         }
                         | Jmp::CallInd { target: expr, .. }
-                        | Jmp::Return(expr) => expr.substitute_trivial_operations(),
+                        | Jmp::Return(expr) => expr.simplify(),
                     }
                 }
             }
@@ -264,6 +281,40 @@ mod tests {
                 calling_conventions: BTreeMap::new(),
                 register_set,
                 datatype_properties: DatatypeProperties::mock(),
+                format_string_functions: FormatStringFunctions::default(),
+            }
+        }
+
+        /// Returns a project without any functions for the x86-64 architecture
+        /// with the System V calling convention as its standard calling convention.
+        pub fn mock_x64() -> Project {
+            let mut project = Project::mock_empty();
+            let cconv = CallingConvention::mock_x64();
+            project.calling_conventions = BTreeMap::from([(cconv.name.clone(), cconv)]);
+            project.datatype_properties = DatatypeProperties::mock_x64();
+            project
+        }
+
+        /// Returns a project without any functions for the 32-bit ARM architecture
+        /// with the AAPCS calling convention as its standard calling convention.
+        pub fn mock_arm32() -> Project {
+            let register_set = (0..13)
+                .map(|index| format!("r{}", index))
+                .chain(["sp".to_string(), "lr".to_string(), "pc".to_string()])
+                .map(|name| Variable::mock(name, ByteSize::new(4)))
+                .collect();
+            let cconv = CallingConvention::mock_arm32();
+            Project {
+                program: Term {
+                    tid: Tid::new("program_tid"),
+                    term: Program::mock_empty(),
+                },
+                cpu_architecture: "arm32".to_string(),
+                stack_pointer_register: Variable::mock("sp", 4u64),
+                calling_conventions: BTreeMap::from([(cconv.name.clone(), cconv)]),
+                register_set,
+                datatype_properties: DatatypeProperties::mock_arm32(),
+                format_string_functions: FormatStringFunctions::default(),
             }
         }
     }
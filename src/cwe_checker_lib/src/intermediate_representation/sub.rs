@@ -171,6 +171,11 @@ pub struct CallingConvention {
     /// A list of callee-saved register,
     /// i.e. the values of these registers should be the same after the call as they were before the call.
     pub callee_saved_register: Vec<Variable>,
+    /// The offset of the first stack parameter relative to the stack pointer at the time of the call,
+    /// e.g. the size of the return address on x86
+    /// or the size of the register parameter home area on MIPS.
+    #[serde(default)]
+    pub first_stack_arg_offset: i64,
 }
 
 impl CallingConvention {
@@ -195,6 +200,31 @@ impl CallingConvention {
         }
         register_list
     }
+
+    /// Returns true if the given register is callee-saved in the calling convention.
+    ///
+    /// Registers are compared by name, so that a variable representing only a part of a callee-saved register
+    /// with the same name (i.e. a variable of smaller size) is also considered to be callee-saved.
+    /// Temporary variables are never callee-saved.
+    /// Note that sub-registers with their own names (e.g. `EBX` for `RBX` on x86-64) are represented
+    /// as sub-pieces of their base registers in the intermediate representation,
+    /// see [`is_callee_saved_expression`](Self::is_callee_saved_expression) for such cases.
+    pub fn is_callee_saved(&self, register: &Variable) -> bool {
+        !register.is_temp
+            && self.callee_saved_register.iter().any(|callee_saved| {
+                callee_saved.name == register.name && register.size <= callee_saved.size
+            })
+    }
+
+    /// Returns true if the given expression denotes a callee-saved register
+    /// or a sub-register of a callee-saved register, e.g. `EBX` as a sub-piece of `RBX`.
+    pub fn is_callee_saved_expression(&self, expression: &Expression) -> bool {
+        match expression {
+            Expression::Var(register) => self.is_callee_saved(register),
+            Expression::Subpiece { arg, .. } => self.is_callee_saved_expression(arg),
+            _ => false,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -265,6 +295,7 @@ mod tests {
                     Variable::mock("R14", 8),
                     Variable::mock("R15", 8),
                 ],
+                first_stack_arg_offset: 8,
             }
         }
         /// Following ARM32 ABI with MVE Extention
@@ -315,6 +346,7 @@ mod tests {
                     Variable::mock("q6", 16),
                     Variable::mock("q7", 16),
                 ],
+                first_stack_arg_offset: 0,
             }
         }
     }
@@ -389,4 +421,23 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn callee_saved_register() {
+        let cconv = CallingConvention::mock_x64();
+        assert!(cconv.is_callee_saved(&Variable::mock("RBX", 8)));
+        assert!(cconv.is_callee_saved(&Variable::mock("RBX", 4)));
+        assert!(!cconv.is_callee_saved(&Variable::mock("RAX", 8)));
+        let mut temp_register = Variable::mock("RBX", 8);
+        temp_register.is_temp = true;
+        assert!(!cconv.is_callee_saved(&temp_register));
+
+        let ebx =
+            Expression::Var(Variable::mock("RBX", 8)).subpiece(ByteSize::new(0), ByteSize::new(4));
+        let eax =
+            Expression::Var(Variable::mock("RAX", 8)).subpiece(ByteSize::new(0), ByteSize::new(4));
+        assert!(cconv.is_callee_saved_expression(&ebx));
+        assert!(!cconv.is_callee_saved_expression(&eax));
+        assert!(!cconv.is_callee_saved_expression(&Expression::const_from_i64(0)));
+    }
 }
@@ -10,6 +10,7 @@ use crate::intermediate_representation::DatatypeProperties;
 use crate::intermediate_representation::Def as IrDef;
 use crate::intermediate_representation::Expression as IrExpression;
 use crate::intermediate_representation::ExternSymbol as IrExternSymbol;
+use crate::intermediate_representation::FormatStringFunctions;
 use crate::intermediate_representation::Jmp as IrJmp;
 use crate::intermediate_representation::Program as IrProgram;
 use crate::intermediate_representation::Project as IrProject;
@@ -655,6 +656,10 @@ pub struct CallingConvention {
     unaffected_register: Vec<String>,
     /// Registers that may be overwritten by the call, i.e. caller-saved registers.
     killed_by_call_register: Vec<String>,
+    /// The offset of the first stack parameter relative to the stack pointer at the time of the call.
+    /// Defaults to zero if the calling convention has no stack parameters.
+    #[serde(default)]
+    first_stack_arg_offset: i64,
 }
 
 impl CallingConvention {
@@ -703,6 +708,7 @@ impl CallingConvention {
             // For example, on AArch64 only the bottom 64bit of some floating point registers are callee-saved.
             // To fix this one may have to to change callee_saved_register to a Vec<Expression>.
             callee_saved_register: to_ir_base_var_list(self.unaffected_register),
+            first_stack_arg_offset: self.first_stack_arg_offset,
         }
     }
 }
@@ -870,6 +876,7 @@ impl Project {
             calling_conventions,
             register_set,
             datatype_properties: self.datatype_properties.clone(),
+            format_string_functions: FormatStringFunctions::default(),
         }
     }
 }
@@ -102,7 +102,8 @@ impl Setup {
                         "long_long_size": 8,
                         "long_size": 4,
                         "pointer_size": 4,
-                        "short_size": 2
+                        "short_size": 2,
+                        "wide_char_size": 4
                     }
                 }
                 "#,
@@ -1,34 +1,294 @@
 //! Handles argument detection by parsing format string arguments during a function call. (e.g. sprintf)
 
-use super::binary::RuntimeMemoryImage;
+use super::binary::{RuntimeMemoryImage, MAX_STRING_LENGTH};
 use crate::prelude::*;
+use crate::utils::log::LogMessage;
 use crate::{
-    abstract_domain::{IntervalDomain, TryToBitvec},
+    abstract_domain::{
+        AbstractDomain, AbstractIdentifier, AbstractLocation, DataDomain, IntervalDomain,
+        SizedDomain, TryToBitvec, TryToInterval,
+    },
     analysis::pointer_inference::State as PointerInferenceState,
     intermediate_representation::*,
 };
 use regex::Regex;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+
+/// The maximal length (in characters) of format strings read from global memory.
+///
+/// Longer strings are not read to prevent scanning whole memory segments
+/// if the null terminator of a string is missing.
+pub const MAX_FORMAT_STRING_LENGTH: usize = 0x2000;
+
+/// The maximal number of addresses from which format strings are read
+/// if the address of a format string is only known to lie in an interval,
+/// see [`get_input_format_strings`].
+pub const MAX_FORMAT_STRING_CANDIDATES: u64 = 32;
+
+/// The maximal number of bytes scanned for the null terminator of a string
+/// when estimating its length with [`estimate_string_length`].
+pub const MAX_STRING_LENGTH_ESTIMATE: usize = 0x2000;
 
 /// Parses the input format string for the corresponding string function.
+///
+/// For wide character functions like `wprintf` the format string is read as a wide character string.
+/// If the format string is not contained in global memory but in a memory object
+/// of the pointer inference state (e.g. on the stack), it is read from there instead.
+/// An error is returned if such a format string is only partially known.
+///
+/// Format string addresses computed relative to a register whose value at function start is a known global address
+/// (e.g. the program counter on ARM) are resolved to absolute addresses (see [`resolve_relative_address`]).
+/// If the format string address is relative to some other base whose address is unknown,
+/// an error naming the base is returned.
+/// On 32-bit ARM a Thumb bit set in the format string address is cleared (see [`clear_thumb_bit`]).
+/// Format strings in global memory are always read from the memory image.
+/// Note that this includes format strings in writable global memory that may have been modified at runtime,
+/// since the pointer inference does not track the contents of global memory.
 pub fn get_input_format_string(
+    pi_state: &PointerInferenceState,
+    extern_symbol: &ExternSymbol,
+    format_string_index: usize,
+    datatype_properties: &DatatypeProperties,
+    cpu_architecture: &str,
+    runtime_memory_image: &RuntimeMemoryImage,
+) -> Result<String, Error> {
+    read_input_format_string(
+        pi_state,
+        extern_symbol,
+        format_string_index,
+        is_wide_character_function(&extern_symbol.name),
+        datatype_properties,
+        cpu_architecture,
+        runtime_memory_image,
+    )
+}
+
+/// Parses the input format string for the corresponding string function
+/// like [`get_input_format_string`],
+/// but also reads the format strings of functions registered as wide character functions
+/// in the format string functions of the project as wide character strings.
+fn get_input_format_string_of_project(
+    project: &Project,
     pi_state: &PointerInferenceState,
     extern_symbol: &ExternSymbol,
     format_string_index: usize,
     runtime_memory_image: &RuntimeMemoryImage,
+) -> Result<String, Error> {
+    read_input_format_string(
+        pi_state,
+        extern_symbol,
+        format_string_index,
+        project
+            .format_string_functions
+            .is_wide_character(&extern_symbol.name)
+            || is_wide_character_function(&extern_symbol.name),
+        &project.datatype_properties,
+        &project.cpu_architecture,
+        runtime_memory_image,
+    )
+}
+
+/// Parses all possible input format strings for the corresponding string function.
+///
+/// In contrast to [`get_input_format_string`] the address of the format string may be an interval of global addresses,
+/// e.g. if the pointer inference merged the addresses of two adjacent strings.
+/// Then the strings at all candidate addresses of the interval are returned,
+/// so that no parameter of any of the possible format strings is missed.
+/// Candidate addresses that do not point to a valid string are ignored
+/// and intervals with more than [`MAX_FORMAT_STRING_CANDIDATES`] candidate addresses are not read.
+/// Returns an error if no format string could be read.
+pub fn get_input_format_strings(
+    pi_state: &PointerInferenceState,
+    extern_symbol: &ExternSymbol,
+    format_string_index: usize,
+    datatype_properties: &DatatypeProperties,
+    cpu_architecture: &str,
+    runtime_memory_image: &RuntimeMemoryImage,
+) -> Result<Vec<String>, Error> {
+    read_input_format_strings(
+        pi_state,
+        extern_symbol,
+        format_string_index,
+        is_wide_character_function(&extern_symbol.name),
+        datatype_properties,
+        cpu_architecture,
+        runtime_memory_image,
+    )
+}
+
+/// Parses all possible input format strings for the corresponding string function
+/// like [`get_input_format_strings`],
+/// but also reads the format strings of functions registered as wide character functions
+/// in the format string functions of the project as wide character strings.
+fn get_input_format_strings_of_project(
+    project: &Project,
+    pi_state: &PointerInferenceState,
+    extern_symbol: &ExternSymbol,
+    format_string_index: usize,
+    runtime_memory_image: &RuntimeMemoryImage,
+) -> Result<Vec<String>, Error> {
+    read_input_format_strings(
+        pi_state,
+        extern_symbol,
+        format_string_index,
+        project
+            .format_string_functions
+            .is_wide_character(&extern_symbol.name)
+            || is_wide_character_function(&extern_symbol.name),
+        &project.datatype_properties,
+        &project.cpu_architecture,
+        runtime_memory_image,
+    )
+}
+
+/// Parses all possible input format strings for the corresponding string function.
+/// If `is_wide_character_string` is set, the format strings are read as wide character strings.
+fn read_input_format_strings(
+    pi_state: &PointerInferenceState,
+    extern_symbol: &ExternSymbol,
+    format_string_index: usize,
+    is_wide_character_string: bool,
+    datatype_properties: &DatatypeProperties,
+    cpu_architecture: &str,
+    runtime_memory_image: &RuntimeMemoryImage,
+) -> Result<Vec<String>, Error> {
+    let candidate_addresses = extern_symbol
+        .parameters
+        .get(format_string_index)
+        .and_then(|format_string| eval_arg(pi_state, format_string, runtime_memory_image).ok())
+        .and_then(|address| address.get_if_absolute_value().cloned())
+        .and_then(|address| get_candidate_addresses(&address));
+    let candidate_addresses = match candidate_addresses {
+        Some(candidate_addresses) => candidate_addresses,
+        None => {
+            return read_input_format_string(
+                pi_state,
+                extern_symbol,
+                format_string_index,
+                is_wide_character_string,
+                datatype_properties,
+                cpu_architecture,
+                runtime_memory_image,
+            )
+            .map(|format_string| vec![format_string])
+        }
+    };
+    let mut format_strings: Vec<String> = Vec::new();
+    for address in candidate_addresses {
+        let address = clear_thumb_bit(address, cpu_architecture, runtime_memory_image);
+        let format_string = if is_wide_character_string {
+            parse_wide_format_string_destination_and_return_content(
+                address,
+                datatype_properties.wide_char_size,
+                runtime_memory_image,
+            )
+        } else {
+            parse_format_string_destination_and_return_content(address, 0, runtime_memory_image)
+        };
+        if let Ok(format_string) = format_string {
+            if !format_strings.contains(&format_string) {
+                format_strings.push(format_string);
+            }
+        }
+    }
+    if format_strings.is_empty() {
+        return Err(anyhow!(
+            "No format string found at the possible format string addresses."
+        ));
+    }
+    Ok(format_strings)
+}
+
+/// If the given address is an interval containing more than one address,
+/// return all addresses contained in the interval.
+///
+/// Returns `None` for exactly known addresses
+/// and for intervals containing more than [`MAX_FORMAT_STRING_CANDIDATES`] addresses.
+fn get_candidate_addresses(address: &IntervalDomain) -> Option<Vec<IntervalDomain>> {
+    if address.try_to_bitvec().is_ok() {
+        return None;
+    }
+    let interval = address.try_to_interval().ok()?;
+    let start = interval.start.try_to_u64().ok()?;
+    let length = interval.end.try_to_u64().ok()?.checked_sub(start)?;
+    if interval.stride == 0 || length / interval.stride >= MAX_FORMAT_STRING_CANDIDATES {
+        return None;
+    }
+    Some(
+        (0..=length / interval.stride)
+            .map(|index| {
+                Bitvector::from_u64(start + index * interval.stride)
+                    .into_resize_unsigned(address.bytesize())
+                    .into()
+            })
+            .collect(),
+    )
+}
+
+/// Parses the input format string for the corresponding string function.
+/// If `is_wide_character_string` is set, the format string is read as a wide character string.
+///
+/// Format strings in global memory are read from the memory image,
+/// even if they are contained in writable memory and may have been modified at runtime.
+fn read_input_format_string(
+    pi_state: &PointerInferenceState,
+    extern_symbol: &ExternSymbol,
+    format_string_index: usize,
+    is_wide_character_string: bool,
+    datatype_properties: &DatatypeProperties,
+    cpu_architecture: &str,
+    runtime_memory_image: &RuntimeMemoryImage,
 ) -> Result<String, Error> {
     if let Some(format_string) = extern_symbol.parameters.get(format_string_index) {
-        if let Ok(Some(address)) = pi_state
-            .eval_parameter_arg(format_string, runtime_memory_image)
-            .as_ref()
-            .map(|param| param.get_if_absolute_value())
-        {
+        let format_string_address = eval_arg(pi_state, format_string, runtime_memory_image);
+        if let Some(address) = format_string_address.as_ref().ok().and_then(|param| {
+            param
+                .get_if_absolute_value()
+                .cloned()
+                .or_else(|| resolve_relative_address(param, cpu_architecture, runtime_memory_image))
+        }) {
+            let address = clear_thumb_bit(address, cpu_architecture, runtime_memory_image);
+            if is_wide_character_string {
+                return parse_wide_format_string_destination_and_return_content(
+                    address,
+                    datatype_properties.wide_char_size,
+                    runtime_memory_image,
+                );
+            }
             return parse_format_string_destination_and_return_content(
-                address.clone(),
+                address,
+                0,
                 runtime_memory_image,
             );
         }
 
+        if let Some((id, _)) = format_string_address
+            .as_ref()
+            .ok()
+            .and_then(|address| address.get_if_unique_target())
+        {
+            if !is_wide_character_string {
+                match read_string_from_pointer_inference_memory(
+                    pi_state,
+                    format_string_address.as_ref().unwrap(),
+                    runtime_memory_image,
+                ) {
+                    Ok((format_string, true)) => return Ok(format_string),
+                    Ok((format_string, false)) => {
+                        return Err(anyhow!(
+                            "Format string is only partially known: \"{}\"",
+                            format_string
+                        ))
+                    }
+                    Err(_) => (),
+                }
+            }
+            return Err(anyhow!(
+                "Format string address is relative to {} with unknown base address.",
+                id
+            ));
+        }
+
         return Err(anyhow!("Format string not in global memory."));
     }
 
@@ -39,18 +299,300 @@ pub fn get_input_format_string(
     ))
 }
 
-/// Parses the destiniation address of the format string.
-/// It checks whether the address points to another pointer in memory.
-/// If so, it will use the target address of that pointer read the format string from memory.
+/// Returns true if the given CPU architecture is an ARM or AArch64 architecture.
+fn is_arm_architecture(cpu_architecture: &str) -> bool {
+    let cpu_architecture = cpu_architecture.to_lowercase();
+    cpu_architecture.starts_with("arm") || cpu_architecture.starts_with("aarch64")
+}
+
+/// Returns true if the given CPU architecture is a 32-bit ARM architecture,
+/// i.e. an ARM architecture supporting the Thumb instruction set.
+fn is_arm32_architecture(cpu_architecture: &str) -> bool {
+    is_arm_architecture(cpu_architecture)
+        && !cpu_architecture.to_lowercase().starts_with("aarch64")
+        && !cpu_architecture.contains("64")
+}
+
+/// Clear the Thumb bit (i.e. the least significant bit) of a data address on 32-bit ARM.
+///
+/// Addresses computed relative to code addresses in Thumb mode may still have the Thumb bit set,
+/// so that they point one byte behind the start of the string.
+/// Since strings may also legitimately start at odd addresses,
+/// the bit is only cleared if the byte before the address is not a null byte,
+/// i.e. if the address points into the middle of a string.
+/// For other architectures and for addresses that are not exactly known, the address is returned unchanged.
+fn clear_thumb_bit(
+    address: IntervalDomain,
+    cpu_architecture: &str,
+    runtime_memory_image: &RuntimeMemoryImage,
+) -> IntervalDomain {
+    if !is_arm32_architecture(cpu_architecture) {
+        return address;
+    }
+    let address_vector = match address.try_to_bitvec() {
+        Ok(address_vector) => address_vector,
+        Err(_) => return address,
+    };
+    match address_vector.try_to_u64() {
+        Ok(value) if value % 2 == 1 => {
+            let previous_byte_address = runtime_memory_image
+                .translate_address(&Bitvector::from_u64(value - 1))
+                .into_resize_unsigned(address_vector.bytesize());
+            match runtime_memory_image.read(&previous_byte_address, ByteSize::new(1)) {
+                Ok(Some(byte)) if !byte.is_zero() => Bitvector::from_u64(value - 1)
+                    .into_resize_unsigned(address_vector.bytesize())
+                    .into(),
+                _ => address,
+            }
+        }
+        _ => address,
+    }
+}
+
+/// Returns true if the given CPU architecture is a MIPS architecture.
+fn is_mips_architecture(cpu_architecture: &str) -> bool {
+    cpu_architecture.to_lowercase().starts_with("mips")
+}
+
+/// Return the value of the given abstract ID if it is a known global address.
+///
+/// This is the case for IDs representing the value of a register at the start of a function
+/// if the register is known to contain the address of the function:
+/// * The `pc` register on ARM and AArch64.
+/// * The `t9` register on MIPS, since the caller has to store the callee address in it
+///   according to the System V ABI for MIPS.
+///
+/// The value of `pc` at the start of a function is assumed to be the address of the function itself.
+/// On 32-bit ARM an instruction reading the program counter sees the address of the instruction plus 8
+/// (plus 4 in Thumb mode), but Ghidra already translates such reads to constants when lifting the instruction,
+/// so that the offsets of values relative to the `pc` register do not contain this pipeline offset.
+fn get_known_base_address(id: &AbstractIdentifier, cpu_architecture: &str) -> Option<u64> {
+    let is_function_address_register = match id.get_location() {
+        AbstractLocation::Register(var) => {
+            (is_arm_architecture(cpu_architecture) && var.name.eq_ignore_ascii_case("pc"))
+                || (is_mips_architecture(cpu_architecture) && var.name == "t9")
+        }
+        AbstractLocation::Pointer(..) => false,
+    };
+    if !is_function_address_register {
+        return None;
+    }
+    u64::from_str_radix(id.get_tid().address.trim_start_matches("0x"), 16).ok()
+}
+
+/// Resolve a value relative to a base with known global address to an absolute address in global memory.
+///
+/// Position-independent code computes addresses of global data relative to some base register,
+/// e.g. as `PC + offset` on ARM and AArch64 or relative to `t9` on MIPS.
+/// If the base register is not known as a constant,
+/// such values are relative to the value of the base register at the start of the function.
+/// The value of the base is then given by the address of the function
+/// (see [`get_known_base_address`] for the supported base registers).
+///
+/// Returns `None` for values that are not relative to a single base with known address
+/// or if the resolved address is not contained in global memory.
+pub fn resolve_relative_address(
+    value: &DataDomain<IntervalDomain>,
+    cpu_architecture: &str,
+    runtime_memory_image: &RuntimeMemoryImage,
+) -> Option<IntervalDomain> {
+    let (id, offset) = value.get_if_unique_target()?;
+    let base_address = get_known_base_address(id, cpu_architecture)?;
+    let offset = offset.try_to_bitvec().ok()?;
+    let address = Bitvector::from_u64(base_address)
+        .into_resize_unsigned(offset.bytesize())
+        .bin_op(BinOpType::IntAdd, &offset)
+        .ok()?;
+    if runtime_memory_image.is_global_memory_address(&address) {
+        Some(address.into())
+    } else {
+        None
+    }
+}
+
+/// Parses the destiniation address of the format string and reads the format string from memory.
+///
+/// Up to `max_pointer_hops` times it checks whether the address points to another pointer in memory.
+/// If so, it follows the pointer and uses its target address instead.
+/// Pointers are only followed as long as the dereferenced value is a valid address in global memory.
+/// The pointers are read with the endianness of the runtime memory image
+/// and have the same size as the given address.
+/// With `max_pointer_hops` set to zero the format string is read directly from the given address.
+/// The given address and the targets of followed pointers are translated with
+/// [`RuntimeMemoryImage::translate_address`], so that addresses of position independent binaries
+/// that do not contain the base offset of the memory image can also be resolved.
+///
+/// Returns an error if the chain of pointers contains a cycle.
 pub fn parse_format_string_destination_and_return_content(
     address: IntervalDomain,
+    max_pointer_hops: usize,
     runtime_memory_image: &RuntimeMemoryImage,
 ) -> Result<String, Error> {
-    if let Ok(address_vector) = address.try_to_bitvec() {
-        return match runtime_memory_image.read_string_until_null_terminator(&address_vector) {
-            Ok(format_string) => Ok(format_string.to_string()),
-            Err(e) => Err(anyhow!("{}", e)),
+    if let Some(mut address_vector) = translate_string_address(&address, runtime_memory_image) {
+        let mut visited_addresses = vec![address_vector.clone()];
+        for _ in 0..max_pointer_hops {
+            match runtime_memory_image
+                .read_pointer(&address_vector, address_vector.bytesize())
+                .map(|target| runtime_memory_image.translate_address(&target))
+            {
+                Ok(target) if runtime_memory_image.is_global_memory_address(&target) => {
+                    if visited_addresses.contains(&target) {
+                        return Err(anyhow!("Cyclic pointer chain to format string detected."));
+                    }
+                    visited_addresses.push(target.clone());
+                    address_vector = target;
+                }
+                _ => break,
+            }
+        }
+        return runtime_memory_image.read_cached_string(
+            &address_vector,
+            ByteSize::new(1),
+            MAX_FORMAT_STRING_LENGTH,
+        );
+    }
+
+    Err(anyhow!(
+        "Could not translate format string address to bitvector."
+    ))
+}
+
+/// Convert the given exactly known string address to a bitvector
+/// and translate it with [`RuntimeMemoryImage::translate_address`].
+/// Returns `None` if the address is not exactly known.
+fn translate_string_address(
+    address: &IntervalDomain,
+    runtime_memory_image: &RuntimeMemoryImage,
+) -> Option<Bitvector> {
+    address
+        .try_to_bitvec()
+        .ok()
+        .map(|address_vector| runtime_memory_image.translate_address(&address_vector))
+}
+
+/// Reads a string from a memory object (e.g. the stack) of the pointer inference state.
+/// The given address has to point to a unique memory object.
+///
+/// Returns the bytes read until the first null byte together with a flag indicating whether the string is complete.
+/// If the contents of the memory object are only partially known,
+/// the longest known prefix of the string is returned and the flag is set to `false`.
+/// Returns an error if not even the first byte of the string is known.
+/// At most [`MAX_STRING_LENGTH`] bytes are read, longer strings are returned as incomplete.
+pub fn read_string_from_pointer_inference_memory(
+    pi_state: &PointerInferenceState,
+    address: &DataDomain<IntervalDomain>,
+    runtime_memory_image: &RuntimeMemoryImage,
+) -> Result<(String, bool), Error> {
+    read_string_from_pointer_inference_memory_bounded(
+        pi_state,
+        address,
+        runtime_memory_image,
+        MAX_STRING_LENGTH,
+    )
+}
+
+/// Reads a string of at most `max_len` bytes from a memory object of the pointer inference state
+/// (see [`read_string_from_pointer_inference_memory`]).
+fn read_string_from_pointer_inference_memory_bounded(
+    pi_state: &PointerInferenceState,
+    address: &DataDomain<IntervalDomain>,
+    runtime_memory_image: &RuntimeMemoryImage,
+    max_len: usize,
+) -> Result<(String, bool), Error> {
+    if address.get_if_unique_target().is_none() {
+        return Err(anyhow!("String address has no unique target."));
+    }
+    let mut string_bytes: Vec<u8> = Vec::new();
+    let mut is_complete = false;
+    'read_loop: loop {
+        let offset =
+            Bitvector::from_i64(string_bytes.len() as i64).into_resize_signed(address.bytesize());
+        let current_address = address.add_offset(&offset.into());
+        let known_bytes = [8u64, 4, 2, 1].iter().find_map(|size| {
+            pi_state
+                .memory
+                .get_value(&current_address, ByteSize::new(*size))
+                .get_if_absolute_value()
+                .and_then(|value| value.try_to_bitvec().ok())
+        });
+        let known_bytes = match known_bytes {
+            Some(bitvector) => bitvector,
+            None => break,
         };
+        let size = u64::from(known_bytes.bytesize());
+        for index in 0..size {
+            let low_byte = if runtime_memory_image.is_little_endian_byte_order() {
+                index
+            } else {
+                size - 1 - index
+            };
+            let byte = known_bytes
+                .subpiece(ByteSize::new(low_byte), ByteSize::new(1))
+                .try_to_u8()?;
+            if byte == 0 {
+                is_complete = true;
+                break 'read_loop;
+            }
+            if string_bytes.len() >= max_len {
+                break 'read_loop;
+            }
+            string_bytes.push(byte);
+        }
+    }
+    if string_bytes.is_empty() && !is_complete {
+        return Err(anyhow!("String contents are unknown."));
+    }
+
+    Ok((String::from_utf8(string_bytes)?, is_complete))
+}
+
+/// Estimate the possible lengths (in bytes, without the null terminator) of the string
+/// that the given pointer points to, e.g. for the argument of a `%s` format specifier.
+///
+/// If the pointer is a known constant address in global memory,
+/// then the string is read from the memory image and its exact length is returned.
+/// Note that the string has to be a valid UTF-8 string.
+/// At most [`MAX_STRING_LENGTH_ESTIMATE`] bytes are scanned for the null terminator.
+/// In all other cases, e.g. for pointers to the stack or the heap
+/// or if no null terminator is found within the scan limit, `Top` is returned.
+pub fn estimate_string_length(
+    pointer: &DataDomain<IntervalDomain>,
+    runtime_memory_image: &RuntimeMemoryImage,
+) -> IntervalDomain {
+    let length = pointer
+        .get_if_absolute_value()
+        .and_then(|address| address.try_to_bitvec().ok())
+        .and_then(|address| {
+            runtime_memory_image
+                .read_string_until_null_terminator_bounded(
+                    &runtime_memory_image.translate_address(&address),
+                    MAX_STRING_LENGTH_ESTIMATE,
+                )
+                .ok()
+        })
+        .map(|string| string.len());
+    match length {
+        Some(length) => Bitvector::from_u64(length as u64)
+            .into_resize_unsigned(pointer.bytesize())
+            .into(),
+        None => IntervalDomain::new_top(pointer.bytesize()),
+    }
+}
+
+/// Parses the destination address of a wide character format string
+/// and reads the format string with the given character size from memory.
+/// The address is translated like in [`parse_format_string_destination_and_return_content`].
+pub fn parse_wide_format_string_destination_and_return_content(
+    address: IntervalDomain,
+    char_size: ByteSize,
+    runtime_memory_image: &RuntimeMemoryImage,
+) -> Result<String, Error> {
+    if let Some(address_vector) = translate_string_address(&address, runtime_memory_image) {
+        return runtime_memory_image.read_cached_string(
+            &address_vector,
+            char_size,
+            MAX_FORMAT_STRING_LENGTH,
+        );
     }
 
     Err(anyhow!(
@@ -58,140 +600,799 @@ pub fn parse_format_string_destination_and_return_content(
     ))
 }
 
+/// Returns whether the given function name belongs to a wide character format string function
+/// of the standard C library, i.e. a function of the `wprintf` or `wscanf` family.
+pub fn is_wide_character_function(function_name: &str) -> bool {
+    FormatStringFunctions::default().is_wide_character(function_name)
+}
+
+/// A parameter of a format string together with the format specifier that it corresponds to.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct FormatStringParameter {
+    /// The data type of the parameter.
+    pub data_type: Datatype,
+    /// The size of the parameter.
+    pub size: ByteSize,
+    /// The text of the format specifier, e.g. `%08x`.
+    pub specifier: String,
+    /// The byte offset of the format specifier inside the format string.
+    pub offset: usize,
+    /// Whether the format specifier has an explicit precision field, e.g. `%.32s`.
+    /// A precision given as an argument (`%.*s`) also counts as an explicit precision.
+    pub has_precision: bool,
+    /// Whether the parameter belongs to a positional `%n` specifier (e.g. `%7$n`)
+    /// whose argument position is preceded by arguments that are not referenced by any format specifier.
+    ///
+    /// Legitimate format strings do not skip arguments,
+    /// so this pattern (direct parameter access) almost always indicates an exploitation attempt
+    /// that writes through a pointer further up the stack.
+    pub is_suspicious_positional_write: bool,
+}
+
+impl FormatStringParameter {
+    /// Returns true if the parameter is the string argument of a `%s` specifier without a precision,
+    /// i.e. if the number of characters read from the string is not bounded by the format string.
+    ///
+    /// Note that this only applies to `printf`-like functions,
+    /// since the precision field has no meaning for `scanf`-like functions.
+    pub fn is_unbounded_string(&self) -> bool {
+        matches!(self.data_type, Datatype::Pointer)
+            && (self.specifier.ends_with('s') || self.specifier.ends_with('S'))
+            && !self.has_precision
+    }
+}
+
+/// Strip the format specifier information from the given parameters.
+///
+/// Returns an error if a parameter is a suspicious positional write,
+/// since the argument positions of the skipped arguments are unknown.
+fn get_parameter_types(
+    parameters: Vec<FormatStringParameter>,
+) -> Result<Vec<(Datatype, ByteSize)>, Error> {
+    if parameters
+        .iter()
+        .any(|param| param.is_suspicious_positional_write)
+    {
+        return Err(anyhow!(
+            "Positional format specifiers do not reference consecutive arguments."
+        ));
+    }
+    Ok(parameters
+        .into_iter()
+        .map(|param| (param.data_type, param.size))
+        .collect())
+}
+
+/// Returns true if the function is a `scanf`-like function,
+/// i.e. if its variadic parameters are pointers to the locations where the parsed values are written to.
+pub fn is_scanf_like_function(function_name: &str) -> bool {
+    function_name.contains("scanf")
+}
+
+/// Returns true if the function is a `scanf`-like function
+/// or registered as such in the format string functions of the project.
+fn is_scanf_like_function_of_project(project: &Project, function_name: &str) -> bool {
+    project.format_string_functions.is_scanf_like(function_name)
+        || is_scanf_like_function(function_name)
+}
+
 /// Parses the format string parameters using a regex, determines their data types,
 /// and calculates their positions (register or memory).
+///
+/// Each `*` in the width or precision field of a specifier consumes an additional integer argument,
+/// which is placed before the argument of the conversion itself.
+/// Positional specifiers like `%2$d` are sorted by their explicit argument position.
+/// Specifiers that consume no argument, i.e. `%%` and the glibc-specific `%m`, are skipped.
+/// The non-standard conversions known by default (e.g. the GNU `%b` binary conversion) are also recognized,
+/// see [`FormatStringFunctions::extra_conversions`].
+/// Returns an error if positional and non-positional specifiers are mixed
+/// or if the positional specifiers do not reference a consecutive range of arguments.
+///
+/// See [`parse_format_string_parameters_detailed`] for a variant
+/// that also returns the text and position of the corresponding format specifiers.
 pub fn parse_format_string_parameters(
     format_string: &str,
     datatype_properties: &DatatypeProperties,
 ) -> Result<Vec<(Datatype, ByteSize)>, Error> {
-    let re = Regex::new(r#"%\d{0,2}(([c,C,d,i,o,u,x,X,e,E,f,F,g,G,a,A,n,p,s,S])|(hi|hd|hu|li|ld|lu|lli|lld|llu|lf|lg|le|la|lF|lG|lE|lA|Lf|Lg|Le|La|LF|LG|LE|LA))"#)
+    get_parameter_types(parse_format_string_parameters_detailed(
+        format_string,
+        datatype_properties,
+    )?)
+}
+
+/// Parses the format string parameters like [`parse_format_string_parameters`],
+/// but additionally returns the text of the format specifier of each parameter
+/// and its byte offset inside the format string.
+///
+/// The integer arguments consumed by `*` in the width or precision field of a specifier
+/// are annotated with the specifier they belong to.
+///
+/// In contrast to [`parse_format_string_parameters`], positional `%n` specifiers
+/// that skip arguments (e.g. in `"%7$n"`) are not an error.
+/// Their parameters are flagged as [suspicious positional writes](FormatStringParameter::is_suspicious_positional_write),
+/// and the skipped arguments are missing in the returned list.
+pub fn parse_format_string_parameters_detailed(
+    format_string: &str,
+    datatype_properties: &DatatypeProperties,
+) -> Result<Vec<FormatStringParameter>, Error> {
+    parse_format_string_parameters_of_function_kind(
+        format_string,
+        datatype_properties,
+        &FormatStringFunctions::default(),
+        false,
+    )
+}
+
+/// Parses the format string parameters of a `scanf`-like function.
+///
+/// In contrast to [`parse_format_string_parameters`] every parameter is a pointer
+/// to the location that the parsed value is written to
+/// (see [`Datatype::from_scanf_specifier`] for the used data types).
+/// Conversions with a `*` in the width field only consume input without assigning it,
+/// so they do not correspond to any parameter.
+pub fn parse_scanf_format_string_parameters(
+    format_string: &str,
+    datatype_properties: &DatatypeProperties,
+) -> Result<Vec<(Datatype, ByteSize)>, Error> {
+    get_parameter_types(parse_format_string_parameters_of_function_kind(
+        format_string,
+        datatype_properties,
+        &FormatStringFunctions::default(),
+        true,
+    )?)
+}
+
+/// Parses the format string parameters of the given function
+/// using the format string functions and data type properties of the project.
+///
+/// In contrast to [`parse_format_string_parameters`] the extra conversions configured for the project are recognized.
+fn parse_format_string_parameters_of_project(
+    project: &Project,
+    function_name: &str,
+    format_string: &str,
+) -> Result<Vec<(Datatype, ByteSize)>, Error> {
+    get_parameter_types(parse_format_string_parameters_of_function_kind(
+        format_string,
+        &project.datatype_properties,
+        &project.format_string_functions,
+        is_scanf_like_function_of_project(project, function_name),
+    )?)
+}
+
+/// Parses the parameters of each of the given possible format strings of the given function
+/// and returns the longest of the parameter lists,
+/// so that no parameter of any of the possible format strings is missed.
+///
+/// Format strings that cannot be parsed are ignored.
+/// Returns an error if none of the format strings can be parsed.
+fn parse_format_string_parameters_of_candidates(
+    project: &Project,
+    function_name: &str,
+    format_strings: &[String],
+) -> Result<Vec<(Datatype, ByteSize)>, Error> {
+    let mut longest_parameters: Option<Vec<(Datatype, ByteSize)>> = None;
+    let mut first_error = None;
+    for format_string in format_strings {
+        match parse_format_string_parameters_of_project(project, function_name, format_string) {
+            Ok(parameters) => {
+                let is_longest = match &longest_parameters {
+                    Some(longest) => parameters.len() > longest.len(),
+                    None => true,
+                };
+                if is_longest {
+                    longest_parameters = Some(parameters);
+                }
+            }
+            Err(err) => {
+                first_error.get_or_insert(err);
+            }
+        }
+    }
+    longest_parameters
+        .ok_or_else(|| first_error.unwrap_or_else(|| anyhow!("No format string to parse.")))
+}
+
+/// Parses the format string parameters either for `printf`-like functions
+/// or, if `is_scanf_like` is set, for `scanf`-like functions.
+///
+/// Conversion specifications may contain any combination of the flag characters `-`, `+`, space, `#`, `0` and `'`
+/// and width and precision fields of arbitrary length.
+/// The extra conversions of the given format string functions are only recognized for `printf`-like functions.
+fn parse_format_string_parameters_of_function_kind(
+    format_string: &str,
+    datatype_properties: &DatatypeProperties,
+    format_string_functions: &FormatStringFunctions,
+    is_scanf_like: bool,
+) -> Result<Vec<FormatStringParameter>, Error> {
+    let extra_conversions: String = if is_scanf_like {
+        String::new()
+    } else {
+        format_string_functions
+            .extra_conversions
+            .keys()
+            .map(|conversion| regex::escape(&conversion.to_string()))
+            .collect()
+    };
+    let re = Regex::new(&format!(r#"%((?P<no_argument>%|m)|(?P<position>\d+\$)?(?P<flags>[-+ #0']*)(?P<width>\*|\d*)(\.(?P<precision>\*|\d*))?(?P<modifier>hh|h|ll|l|L|z|j|t)?(?P<conversion>[cCdiouxXeEfFgGaAnpsS{}]))"#, extra_conversions))
         .expect("No valid regex!");
 
-    let datatype_map: Vec<(Datatype, ByteSize)> = re
-        .captures_iter(format_string)
-        .map(|cap| {
-            let data_type = Datatype::from(cap[1].to_string());
-            let size = {
-                // Considers argument promotion for char type
-                if matches!(data_type, Datatype::Char) {
-                    datatype_properties.get_size_from_data_type(Datatype::Integer)
-                } else {
-                    datatype_properties.get_size_from_data_type(data_type.clone())
+    let mut parameters: Vec<FormatStringParameter> = Vec::new();
+    let mut positional_parameters: BTreeMap<usize, FormatStringParameter> = BTreeMap::new();
+    for cap in re.captures_iter(format_string) {
+        if cap.name("no_argument").is_some() {
+            continue;
+        }
+        let specifier = cap.get(0).unwrap();
+        let new_parameter = |data_type: Datatype, size: ByteSize| FormatStringParameter {
+            data_type,
+            size,
+            specifier: specifier.as_str().to_string(),
+            offset: specifier.start(),
+            has_precision: cap.name("precision").is_some(),
+            is_suspicious_positional_write: false,
+        };
+        if is_scanf_like {
+            if cap.name("width").map(|field| field.as_str()) == Some("*") {
+                // The assignment of the converted value is suppressed.
+                continue;
+            }
+        } else {
+            for field in ["width", "precision"] {
+                if cap.name(field).map(|field| field.as_str()) == Some("*") {
+                    parameters.push(new_parameter(
+                        Datatype::Integer,
+                        datatype_properties.get_size_from_data_type(Datatype::Integer),
+                    ));
                 }
-            };
-            (data_type, size)
+            }
+        }
+        let modifier = cap
+            .name("modifier")
+            .map_or("", |modifier| modifier.as_str());
+        let conversion = cap["conversion"].chars().next().unwrap();
+        let data_type = if is_scanf_like {
+            Datatype::from_scanf_format_specifier(modifier, conversion)?
+        } else {
+            match Datatype::from_format_specifier(modifier, conversion) {
+                Ok(data_type) => data_type,
+                Err(err) => match format_string_functions.get_extra_conversion_datatype(conversion)
+                {
+                    // Length modifiers of integer conversions keep their meaning, e.g. for `%lb`.
+                    Some(Datatype::Integer) => Datatype::from_format_specifier(modifier, 'u')?,
+                    Some(data_type) => data_type.clone(),
+                    None => return Err(err),
+                },
+            }
+        };
+        let size = {
+            // Considers argument promotion for char type
+            if matches!(data_type, Datatype::Char) {
+                datatype_properties.get_size_from_data_type(Datatype::Integer)
+            } else {
+                datatype_properties.get_size_from_data_type(data_type.clone())
+            }
+        };
+        match cap.name("position") {
+            Some(position) => {
+                let position: usize = position.as_str().trim_end_matches('$').parse()?;
+                if let Some(known_parameter) = positional_parameters.get(&position) {
+                    if known_parameter.data_type != data_type {
+                        return Err(anyhow!(
+                            "Conflicting data types for positional argument {}.",
+                            position
+                        ));
+                    }
+                }
+                positional_parameters
+                    .entry(position)
+                    .or_insert_with(|| new_parameter(data_type, size));
+            }
+            None => parameters.push(new_parameter(data_type, size)),
+        }
+    }
+
+    if positional_parameters.is_empty() {
+        return Ok(parameters);
+    }
+    if !parameters.is_empty() {
+        return Err(anyhow!(
+            "Positional and non-positional format specifiers cannot be mixed."
+        ));
+    }
+    // Positions after the first argument that is not referenced by any specifier
+    // are only accepted for `%n` specifiers, which are flagged as suspicious.
+    if let Some(first_skipped_position) = positional_parameters
+        .keys()
+        .enumerate()
+        .find(|(index, position)| index + 1 != **position)
+        .map(|(index, _)| index + 1)
+    {
+        for parameter in positional_parameters
+            .range_mut(first_skipped_position..)
+            .map(|(_, parameter)| parameter)
+        {
+            if !parameter.specifier.ends_with('n') {
+                return Err(anyhow!(
+                    "Positional format specifiers do not reference consecutive arguments."
+                ));
+            }
+            parameter.is_suspicious_positional_write = true;
+        }
+    }
+
+    Ok(positional_parameters.into_values().collect())
+}
+
+/// Get the index of the format string parameter of the given extern symbol.
+///
+/// If the symbol is not contained in the format string functions of the project,
+/// then the index is guessed with [`guess_format_string_index`].
+/// Returns an error if no format string parameter could be found.
+pub fn get_format_string_index(
+    project: &Project,
+    pi_state: &PointerInferenceState,
+    extern_symbol: &ExternSymbol,
+    runtime_memory_image: &RuntimeMemoryImage,
+) -> Result<usize, Error> {
+    project
+        .format_string_functions
+        .get_format_string_index(&extern_symbol.name)
+        .or_else(|| {
+            guess_format_string_index(project, pi_state, extern_symbol, runtime_memory_image)
+        })
+        .ok_or_else(|| anyhow!("External symbol does not contain a format string parameter."))
+}
+
+/// Get all call sites of the extern format string functions of the project,
+/// i.e. of the extern symbols contained in the format string functions of the project.
+///
+/// The call sites are indexed by the TID of the called extern symbol.
+/// Besides direct calls the result also contains indirect calls
+/// whose target is a constant address of a format string function (e.g. the address of its PLT entry).
+/// Format string functions without any call site are not contained in the result.
+pub fn get_format_string_call_sites(project: &Project) -> BTreeMap<Tid, Vec<&Term<Jmp>>> {
+    let format_string_symbols: HashMap<&Tid, &ExternSymbol> = project
+        .program
+        .term
+        .extern_symbols
+        .iter()
+        .filter(|(_, symbol)| {
+            project
+                .format_string_functions
+                .get_format_string_index(&symbol.name)
+                .is_some()
+        })
+        .collect();
+    let symbol_addresses: HashMap<u64, &Tid> = format_string_symbols
+        .iter()
+        .flat_map(|(tid, symbol)| {
+            symbol.addresses.iter().filter_map(|address| {
+                u64::from_str_radix(address.trim_start_matches("0x"), 16)
+                    .ok()
+                    .map(|address| (address, *tid))
+            })
         })
         .collect();
+    let mut call_sites: BTreeMap<Tid, Vec<&Term<Jmp>>> = BTreeMap::new();
+    for sub in project.program.term.subs.values() {
+        for block in sub.term.blocks.iter() {
+            for jmp in block.term.jmps.iter() {
+                let symbol_tid = match &jmp.term {
+                    Jmp::Call { target, .. } if format_string_symbols.contains_key(target) => {
+                        Some(target)
+                    }
+                    Jmp::CallInd {
+                        target: Expression::Const(address),
+                        ..
+                    } => address
+                        .try_to_u64()
+                        .ok()
+                        .and_then(|address| symbol_addresses.get(&address).copied()),
+                    _ => None,
+                };
+                if let Some(symbol_tid) = symbol_tid {
+                    call_sites.entry(symbol_tid.clone()).or_default().push(jmp);
+                }
+            }
+        }
+    }
+    call_sites
+}
 
-    let data_type_not_yet_parsable = datatype_map.iter().any(|(data_type, _)| {
-        matches!(
-            data_type,
-            Datatype::Long | Datatype::LongLong | Datatype::LongDouble
+/// Guess the index of the format string parameter of the given extern symbol
+/// for the call corresponding to the given pointer inference state.
+///
+/// This is a heuristic for wrapper functions around `printf`-like functions (e.g. custom logging functions),
+/// whose format string parameter is not known.
+/// The index of the first parameter pointing to a string that contains a format specifier is returned.
+/// Returns `None` if no parameter points to such a string.
+pub fn guess_format_string_index(
+    project: &Project,
+    pi_state: &PointerInferenceState,
+    extern_symbol: &ExternSymbol,
+    runtime_memory_image: &RuntimeMemoryImage,
+) -> Option<usize> {
+    (0..extern_symbol.parameters.len()).find(|index| {
+        get_input_format_string_of_project(
+            project,
+            pi_state,
+            extern_symbol,
+            *index,
+            runtime_memory_image,
         )
-    });
+        .map(|string| {
+            string.contains('%')
+                && parse_format_string_parameters_of_project(project, &extern_symbol.name, &string)
+                    .is_ok()
+        })
+        .unwrap_or(false)
+    })
+}
 
-    if data_type_not_yet_parsable {
+/// Returns an argument vector of detected variable parameters
+/// together with the log messages generated while computing the locations of the parameters.
+///
+/// Returns an error for functions like `vprintf` that take their variable parameters through a `va_list`,
+/// since the parameters are not passed in registers or on the stack of the call.
+/// Use [`get_va_list_parameter`] for these functions instead.
+pub fn get_variable_parameters(
+    project: &Project,
+    pi_state: &PointerInferenceState,
+    extern_symbol: &ExternSymbol,
+    runtime_memory_image: &RuntimeMemoryImage,
+) -> Result<(Vec<Arg>, Vec<LogMessage>), Error> {
+    let format_string_index =
+        get_format_string_index(project, pi_state, extern_symbol, runtime_memory_image)?;
+    if is_va_list_function(&extern_symbol.name) {
         return Err(anyhow!(
-            "Data types: long, long long and long double, cannot be parsed yet."
+            "Variable parameters of {} are passed through a va_list.",
+            extern_symbol.name
         ));
     }
 
-    Ok(datatype_map)
+    let format_strings = get_input_format_strings_of_project(
+        project,
+        pi_state,
+        extern_symbol,
+        format_string_index,
+        runtime_memory_image,
+    )
+    .map_err(|err| anyhow!("Could not parse variable parameters: {}", err))?;
+    let parameters =
+        parse_format_string_parameters_of_candidates(project, &extern_symbol.name, &format_strings)
+            .map_err(|err| anyhow!("Could not parse variable parameters: {}", err))?;
+    Ok(calculate_parameter_locations(
+        parameters,
+        project.get_calling_convention(extern_symbol),
+        format_string_index,
+        &project.stack_pointer_register,
+        &project.cpu_architecture,
+        &project.datatype_properties,
+    ))
 }
 
-/// Returns an argument vector of detected variable parameters.
-pub fn get_variable_parameters(
+/// Returns true if the function takes its variable parameters through a `va_list` argument,
+/// e.g. `vprintf`, `vfprintf`, `vsnprintf` or `vsscanf`.
+pub fn is_va_list_function(function_name: &str) -> bool {
+    let name = function_name
+        .trim_start_matches('_')
+        .trim_start_matches("isoc99_");
+    name.starts_with('v') && (name.contains("printf") || name.contains("scanf"))
+}
+
+/// The `va_list` argument of a function like `vprintf`
+/// together with the number of variable parameters referenced in the format string.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct VaListParameter {
+    /// The location of the `va_list` argument.
+    pub arg: Arg,
+    /// If set, the argument is a pointer to a `va_list` structure (e.g. on x86-64 and AArch64).
+    /// Otherwise the `va_list` is passed by value,
+    /// i.e. the argument itself points to the array of variable parameters (e.g. on x86-32 and ARM).
+    pub is_passed_by_reference: bool,
+    /// The number of variable parameters that the format string references.
+    pub conversion_count: usize,
+}
+
+/// Returns the `va_list` argument of functions like `vprintf`, which take their variable parameters
+/// through a `va_list` instead of directly in registers or on the stack.
+///
+/// The `va_list` is the parameter directly following the format string.
+/// If it is not contained in the parameter list of the extern symbol,
+/// then its location is derived from the calling convention of the extern symbol.
+/// Returns an error if the function does not take a `va_list` argument
+/// or if the format string could not be parsed.
+pub fn get_va_list_parameter(
     project: &Project,
     pi_state: &PointerInferenceState,
     extern_symbol: &ExternSymbol,
-    format_string_index_map: &HashMap<String, usize>,
     runtime_memory_image: &RuntimeMemoryImage,
-) -> Result<Vec<Arg>, Error> {
-    let format_string_index = match format_string_index_map.get(&extern_symbol.name) {
-        Some(index) => *index,
-        None => panic!("External Symbol does not contain a format string parameter."),
+) -> Result<VaListParameter, Error> {
+    if !is_va_list_function(&extern_symbol.name) {
+        return Err(anyhow!("{} takes no va_list argument.", extern_symbol.name));
+    }
+    let format_string_index =
+        get_format_string_index(project, pi_state, extern_symbol, runtime_memory_image)?;
+    let va_list_index = format_string_index + 1;
+    let arg = match extern_symbol.parameters.get(va_list_index) {
+        Some(param) => param.clone(),
+        None => project
+            .get_specific_calling_convention(&extern_symbol.calling_convention)
+            .and_then(|cconv| cconv.integer_parameter_register.get(va_list_index))
+            .map(|register| Arg::from_var(register.clone(), Some(Datatype::Pointer)))
+            .ok_or_else(|| anyhow!("Location of the va_list argument is unknown."))?,
     };
-
-    let format_string_results = get_input_format_string(
+    let format_strings = get_input_format_strings_of_project(
+        project,
         pi_state,
         extern_symbol,
         format_string_index,
         runtime_memory_image,
-    );
+    )?;
+    let conversion_count = parse_format_string_parameters_of_candidates(
+        project,
+        &extern_symbol.name,
+        &format_strings,
+    )?
+    .len();
+    let cpu_architecture = project.cpu_architecture.to_lowercase();
+    Ok(VaListParameter {
+        arg,
+        is_passed_by_reference: cpu_architecture == "x86_64"
+            || cpu_architecture.starts_with("aarch64"),
+        conversion_count,
+    })
+}
 
-    if let Ok(format_string) = format_string_results.as_ref() {
-        let parameter_result =
-            parse_format_string_parameters(format_string, &project.datatype_properties);
-        match parameter_result {
-            Ok(parameters) => {
-                return Ok(calculate_parameter_locations(
-                    parameters,
-                    project.get_calling_convention(extern_symbol),
-                    format_string_index,
-                    &project.stack_pointer_register,
-                    &project.cpu_architecture,
-                ));
-            }
-            Err(e) => {
-                return Err(anyhow!("Could not parse variable parameters: {}", e));
-            }
-        }
+/// The value of the size parameter of a bounded string function like `snprintf`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum SizeParameter {
+    /// The size parameter is an absolute value contained in the given interval.
+    Known(IntervalDomain),
+    /// The value of the size parameter is unknown.
+    Unknown,
+}
+
+/// Returns the value of the size parameter of bounded string functions like `snprintf`.
+///
+/// The index of the size parameter is taken from the format string functions of the project.
+/// Returns `None` for extern symbols without a known size parameter, i.e. for unbounded string functions like `sprintf`.
+/// If the value of the size parameter is not exactly known as an absolute value,
+/// then [`SizeParameter::Unknown`] is returned.
+pub fn get_size_parameter(
+    project: &Project,
+    pi_state: &PointerInferenceState,
+    extern_symbol: &ExternSymbol,
+    runtime_memory_image: &RuntimeMemoryImage,
+) -> Option<SizeParameter> {
+    let size_parameter_index = project
+        .format_string_functions
+        .get_size_parameter_index(&extern_symbol.name)?;
+    let size_value = extern_symbol
+        .parameters
+        .get(size_parameter_index)
+        .and_then(|param| eval_arg(pi_state, param, runtime_memory_image).ok());
+    match size_value {
+        Some(value) if value.is_pure_absolute() => match value.get_absolute_value_as_interval() {
+            Some(interval) if !interval.is_top() => Some(SizeParameter::Known(interval)),
+            _ => Some(SizeParameter::Unknown),
+        },
+        _ => Some(SizeParameter::Unknown),
     }
+}
 
-    Err(anyhow!(
-        "Could not parse variable parameters: {}",
-        format_string_results.unwrap_err()
-    ))
+/// The indices of the destination buffer parameters of extern functions
+/// that write their output to a buffer given as a parameter.
+const DESTINATION_PARAMETER_INDEX: &[(&str, usize)] = &[
+    ("sprintf", 0),
+    ("snprintf", 0),
+    ("vsprintf", 0),
+    ("vsnprintf", 0),
+    ("swprintf", 0),
+    ("vswprintf", 0),
+    ("strcpy", 0),
+    ("strncpy", 0),
+    ("strcat", 0),
+    ("strncat", 0),
+    ("wcscpy", 0),
+    ("wcsncpy", 0),
+    ("wcscat", 0),
+    ("wcsncat", 0),
+    ("memcpy", 0),
+    ("memmove", 0),
+    ("memset", 0),
+];
+
+/// Returns the index of the destination buffer parameter
+/// if the given function writes its output to a buffer given as a parameter.
+pub fn get_destination_parameter_index(function_name: &str) -> Option<usize> {
+    DESTINATION_PARAMETER_INDEX
+        .iter()
+        .find(|(name, _)| *name == function_name)
+        .map(|(_, index)| *index)
+}
+
+/// Returns the argument corresponding to the destination buffer of the given extern symbol,
+/// e.g. the first parameter of `sprintf` or `strcpy`.
+///
+/// If the parameter is not contained in the parameter list of the extern symbol,
+/// then its location is derived from the calling convention of the extern symbol.
+/// Returns `None` if the extern symbol has no known destination buffer parameter.
+pub fn get_destination_arg(extern_symbol: &ExternSymbol, project: &Project) -> Option<Arg> {
+    let index = get_destination_parameter_index(&extern_symbol.name)?;
+    if let Some(param) = extern_symbol.parameters.get(index) {
+        return Some(param.clone());
+    }
+    project
+        .get_specific_calling_convention(&extern_symbol.calling_convention)?
+        .integer_parameter_register
+        .get(index)
+        .map(|register| Arg::from_var(register.clone(), Some(Datatype::Pointer)))
+}
+
+/// Returns the values of the fixed parameters of the given extern symbol at a call to it,
+/// e.g. the `(dest, src, len)` triple of `memcpy`.
+///
+/// The values are evaluated on the given pointer inference state at the call site.
+/// Parameters that could not be evaluated get a `Top` value of the size of the parameter.
+/// Returns an empty list if the extern symbol is not an extern symbol of the project.
+/// Returns an error if the calling convention of the extern symbol is not known to the project,
+/// since then the parameter locations of the symbol cannot be trusted.
+pub fn get_fixed_args_of(
+    extern_symbol: &ExternSymbol,
+    project: &Project,
+    pi_state: &PointerInferenceState,
+    runtime_memory_image: &RuntimeMemoryImage,
+) -> Result<Vec<DataDomain<IntervalDomain>>, Error> {
+    if !project
+        .program
+        .term
+        .extern_symbols
+        .contains_key(&extern_symbol.tid)
+    {
+        return Ok(Vec::new());
+    }
+    if project
+        .get_specific_calling_convention(&extern_symbol.calling_convention)
+        .is_none()
+    {
+        return Err(anyhow!(
+            "Unknown calling convention {:?} of {}",
+            extern_symbol.calling_convention,
+            extern_symbol.name
+        ));
+    }
+    Ok(extern_symbol
+        .parameters
+        .iter()
+        .map(|param| {
+            eval_arg(pi_state, param, runtime_memory_image).unwrap_or_else(|_| {
+                let size = match param {
+                    Arg::Register { expr, .. } => expr.bytesize(),
+                    Arg::Stack { size, .. } => *size,
+                };
+                DataDomain::new_top(size)
+            })
+        })
+        .collect())
+}
+
+/// Evaluate the value of the given argument on the given pointer inference state.
+///
+/// Register arguments are evaluated from the register values of the state.
+/// Stack arguments (e.g. generated by [`create_stack_arg`]) are loaded from the address
+/// that their address expression evaluates to on the state.
+/// Returns an error if the value of a stack argument could not be loaded.
+pub fn eval_arg(
+    pi_state: &PointerInferenceState,
+    arg: &Arg,
+    runtime_memory_image: &RuntimeMemoryImage,
+) -> Result<DataDomain<IntervalDomain>, Error> {
+    pi_state
+        .eval_parameter_arg(arg, runtime_memory_image)
+        .map_err(|err| anyhow!("Could not evaluate argument: {}", err))
 }
 
 /// Calculates the register and stack positions of format string parameters.
 /// The parameters are then returned as an argument vector for later tainting.
+///
+/// If the format string parameter itself is already passed on the stack,
+/// then all variable parameters are passed on the stack after the fixed parameters.
+/// Here it is assumed that each fixed stack parameter occupies one stack slot of the size of the stack register.
+/// A log message is returned for this case, since the calling convention may not be applicable to the call.
+///
+/// Each stack parameter occupies a multiple of the stack slot size
+/// and its offset relative to the start of the parameter area on the stack
+/// is rounded up to its alignment (see [`get_stack_alignment`]).
+/// The start of the parameter area is given by the `first_stack_arg_offset` of the calling convention
+/// (see [`get_stack_parameter_area_offset`] for calling conventions that do not define it).
+///
+/// Integer values that are larger than a register (e.g. `long long` on 32-bit architectures)
+/// are passed in a pair of consecutive registers.
+/// On 32-bit ARM such a register pair always starts at an even-numbered register (e.g. `r2:r3`),
+/// so that an odd-numbered register may be skipped.
+///
+/// Parameters of data types that are not generated by the format string parser (e.g. `float`)
+/// are treated as integers and a log message is returned for them.
 pub fn calculate_parameter_locations(
     parameters: Vec<(Datatype, ByteSize)>,
     calling_convention: &CallingConvention,
     format_string_index: usize,
     stack_register: &Variable,
     cpu_arch: &str,
-) -> Vec<Arg> {
+    datatype_properties: &DatatypeProperties,
+) -> (Vec<Arg>, Vec<LogMessage>) {
     let mut var_args: Vec<Arg> = Vec::new();
+    let mut logs: Vec<LogMessage> = Vec::new();
+    // The offset of the parameter area on the stack, e.g. behind the return address on x86.
+    let stack_area_offset =
+        get_stack_parameter_area_offset(calling_convention, stack_register, cpu_arch);
+    let slot_size = u64::from(stack_register.size);
+    // The offset of the next stack parameter relative to the start of the parameter area.
+    let mut stack_offset: u64 = 0;
     // The number of the remaining integer argument registers are calculated
     // from the format string position since it is the last fixed argument.
-    let mut integer_arg_register_count =
-        calling_convention.integer_parameter_register.len() - (format_string_index + 1);
+    let mut integer_arg_register_count = match calling_convention
+        .integer_parameter_register
+        .len()
+        .checked_sub(format_string_index + 1)
+    {
+        Some(count) => count,
+        None => {
+            let fixed_stack_param_count =
+                format_string_index + 1 - calling_convention.integer_parameter_register.len();
+            stack_offset += fixed_stack_param_count as u64 * slot_size;
+            logs.push(
+                LogMessage::new_info(format!(
+                    "Format string parameter at index {} is not passed in a register. Variable parameters are assumed to be on the stack.",
+                    format_string_index
+                ))
+                .source("Parameter Location Calculation"),
+            );
+            0
+        }
+    };
     let mut float_arg_register_count = calling_convention.float_parameter_register.len();
-    let mut stack_offset: i64 = match cpu_arch {
-        "x86" | "x86_32" | "x86_64" => u64::from(stack_register.size) as i64,
-        _ => 0,
+    let is_32_bit_arm = is_arm_architecture(cpu_arch) && stack_register.size == ByteSize::new(4);
+    let mut push_stack_arg = |var_args: &mut Vec<Arg>, data_type: &Datatype, size: ByteSize| {
+        let alignment =
+            get_stack_alignment(data_type, datatype_properties, stack_register, cpu_arch);
+        stack_offset = round_up_to_multiple(stack_offset, alignment);
+        var_args.push(create_stack_arg(
+            size,
+            stack_area_offset + stack_offset as i64,
+            data_type.clone(),
+            stack_register,
+        ));
+        stack_offset += round_up_to_multiple(u64::from(size), slot_size);
     };
 
     for (data_type, size) in parameters.iter() {
+        let (data_type, size) = if is_integer_like_datatype(data_type)
+            || matches!(data_type, Datatype::Double | Datatype::LongDouble)
+        {
+            (data_type, *size)
+        } else {
+            // Other data types are not generated by the format string parser.
+            // To not abort the analysis for unexpected inputs, the parameter is treated as an integer.
+            logs.push(
+                LogMessage::new_info(format!(
+                    "Unexpected data type {:?} of a variable parameter. The parameter is treated as an integer.",
+                    data_type
+                ))
+                .source("Parameter Location Calculation"),
+            );
+            (
+                &Datatype::Integer,
+                datatype_properties.get_size_from_data_type(Datatype::Integer),
+            )
+        };
         match data_type {
-            Datatype::Integer | Datatype::Pointer | Datatype::Char => {
-                if integer_arg_register_count > 0 {
-                    let register = calling_convention.integer_parameter_register[calling_convention
-                        .integer_parameter_register
-                        .len()
-                        - integer_arg_register_count]
-                        .clone();
-
-                    var_args.push(create_register_arg(
-                        Expression::Var(register),
-                        data_type.clone(),
-                    ));
-
-                    integer_arg_register_count -= 1;
-                } else {
-                    var_args.push(create_stack_arg(
-                        *size,
-                        stack_offset,
-                        data_type.clone(),
-                        stack_register,
-                    ));
-                    stack_offset += u64::from(*size) as i64
-                }
-            }
-            Datatype::Double => {
-                if float_arg_register_count > 0 {
+            Datatype::Double | Datatype::LongDouble => {
+                // On x86 the long double type is always passed on the stack.
+                let passed_on_stack = matches!(data_type, Datatype::LongDouble)
+                    && matches!(cpu_arch, "x86" | "x86_32" | "x86_64");
+                if float_arg_register_count > 0 && !passed_on_stack {
                     let expr = calling_convention.float_parameter_register[calling_convention
                         .float_parameter_register
                         .len()
@@ -202,20 +1403,141 @@ pub fn calculate_parameter_locations(
 
                     float_arg_register_count -= 1;
                 } else {
-                    var_args.push(create_stack_arg(
-                        *size,
-                        stack_offset,
-                        data_type.clone(),
-                        stack_register,
-                    ));
-                    stack_offset += u64::from(*size) as i64
+                    push_stack_arg(&mut var_args, data_type, size);
+                }
+            }
+            _ => {
+                let mut first_register_index = calling_convention.integer_parameter_register.len()
+                    - integer_arg_register_count;
+                let register_size = calling_convention
+                    .integer_parameter_register
+                    .get(first_register_index)
+                    .map(|register| register.size);
+                if let Some(register_size) = register_size {
+                    if size > register_size && is_32_bit_arm && first_register_index % 2 == 1 {
+                        // The register pair has to start at an even-numbered register.
+                        first_register_index += 1;
+                        integer_arg_register_count -= 1;
+                    }
+                }
+                match register_size {
+                    // The value fits into a single register.
+                    Some(register_size) if size <= register_size => {
+                        let register = calling_convention.integer_parameter_register
+                            [first_register_index]
+                            .clone();
+                        var_args.push(create_register_arg(
+                            Expression::Var(register),
+                            data_type.clone(),
+                        ));
+                        integer_arg_register_count -= 1;
+                    }
+                    // The value is split into a register pair, e.g. `long long` on 32-bit architectures.
+                    Some(register_size)
+                        if size <= register_size + register_size
+                            && integer_arg_register_count > 1 =>
+                    {
+                        let low_register = calling_convention.integer_parameter_register
+                            [first_register_index]
+                            .clone();
+                        let high_register = calling_convention.integer_parameter_register
+                            [first_register_index + 1]
+                            .clone();
+                        var_args.push(create_register_pair_arg(
+                            low_register,
+                            high_register,
+                            data_type.clone(),
+                        ));
+                        integer_arg_register_count -= 2;
+                    }
+                    _ => {
+                        // Values that are not passed in registers may not be split between registers and stack.
+                        integer_arg_register_count = 0;
+                        push_stack_arg(&mut var_args, data_type, size);
+                    }
                 }
             }
-            _ => panic!("Invalid data type specifier from format string."),
         }
     }
 
-    var_args
+    (var_args, logs)
+}
+
+/// Returns the offset of the first stack parameter relative to the stack pointer at the call.
+///
+/// This is the `first_stack_arg_offset` of the calling convention.
+/// The MIPS o32 ABI requires the caller to reserve a shadow area on the stack
+/// for the integer parameter registers `$a0-$a3`, so that the first stack parameter is located behind it.
+/// If the calling convention of a 32-bit MIPS binary does not define the offset,
+/// then the size of the shadow area is derived from the integer parameter registers of the calling convention.
+fn get_stack_parameter_area_offset(
+    calling_convention: &CallingConvention,
+    stack_register: &Variable,
+    cpu_arch: &str,
+) -> i64 {
+    if calling_convention.first_stack_arg_offset == 0
+        && is_mips_architecture(cpu_arch)
+        && stack_register.size == ByteSize::new(4)
+    {
+        calling_convention
+            .integer_parameter_register
+            .iter()
+            .map(|register| u64::from(register.size) as i64)
+            .sum()
+    } else {
+        calling_convention.first_stack_arg_offset
+    }
+}
+
+/// Returns true if variable parameters of the given data type are passed like integers,
+/// i.e. in integer parameter registers or on the stack.
+fn is_integer_like_datatype(data_type: &Datatype) -> bool {
+    matches!(
+        data_type,
+        Datatype::Integer
+            | Datatype::Pointer
+            | Datatype::WritebackPointer
+            | Datatype::ShortWritebackPointer
+            | Datatype::CharWritebackPointer
+            | Datatype::GenericWritebackPointer
+            | Datatype::Char
+            | Datatype::Long
+            | Datatype::LongLong
+            | Datatype::SizeT
+    )
+}
+
+/// Returns the alignment in bytes of a variable parameter of the given data type on the stack.
+///
+/// On 32-bit x86 all stack parameters are aligned to the stack slot size.
+/// On all other architectures the stack parameters are aligned to the maximum
+/// of the stack slot size and the natural alignment of the data type,
+/// e.g. `long double` values are aligned to 16 bytes on x86-64.
+fn get_stack_alignment(
+    data_type: &Datatype,
+    datatype_properties: &DatatypeProperties,
+    stack_register: &Variable,
+    cpu_arch: &str,
+) -> u64 {
+    let slot_size = u64::from(stack_register.size);
+    match cpu_arch {
+        "x86" | "x86_32" => slot_size,
+        _ => std::cmp::max(
+            slot_size,
+            u64::from(datatype_properties.get_alignment_of_data_type(data_type.clone())),
+        ),
+    }
+}
+
+/// Round the given value up to the next multiple of `alignment`.
+fn round_up_to_multiple(value: u64, alignment: u64) -> u64 {
+    if alignment == 0 {
+        return value;
+    }
+    match value % alignment {
+        0 => value,
+        remainder => value + alignment - remainder,
+    }
 }
 
 /// Creates a stack parameter given a size, stack offset and data type.
@@ -232,6 +1554,23 @@ pub fn create_stack_arg(
     }
 }
 
+/// Creates a register parameter for a value that is split into two registers.
+/// The low register contains the least significant bytes of the value.
+pub fn create_register_pair_arg(
+    low_register: Variable,
+    high_register: Variable,
+    data_type: Datatype,
+) -> Arg {
+    Arg::Register {
+        expr: Expression::BinOp {
+            op: BinOpType::Piece,
+            lhs: Box::new(Expression::Var(high_register)),
+            rhs: Box::new(Expression::Var(low_register)),
+        },
+        data_type: Some(data_type),
+    }
+}
+
 /// Creates a register parameter given a size, register name and data type.
 pub fn create_register_arg(expr: Expression, data_type: Datatype) -> Arg {
     Arg::Register {
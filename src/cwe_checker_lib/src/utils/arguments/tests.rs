@@ -1,6 +1,5 @@
+use crate::abstract_domain::{AbstractIdentifier, Interval};
 use crate::intermediate_representation::{Bitvector, Tid};
-use std::collections::BTreeMap;
-use std::iter::FromIterator;
 
 use super::*;
 
@@ -14,16 +13,12 @@ fn test_get_variable_parameters() {
     let mem_image = RuntimeMemoryImage::mock();
     let mut pi_state = mock_pi_state();
     let sprintf_symbol = ExternSymbol::mock_string();
-    let mut format_string_index_map: HashMap<String, usize> = HashMap::new();
-    format_string_index_map.insert("sprintf".to_string(), 1);
     let global_address = Bitvector::from_str_radix(16, "5000").unwrap();
     pi_state.set_register(
         &Variable::mock("RSI", 8 as u64),
         IntervalDomain::new(global_address.clone(), global_address).into(),
     );
-    let mut project = Project::mock_empty();
-    let cconv = CallingConvention::mock_x64();
-    project.calling_conventions = BTreeMap::from_iter([(cconv.name.clone(), cconv)]);
+    let project = Project::mock_x64();
 
     let mut output: Vec<Arg> = Vec::new();
     output.push(Arg::from_var(
@@ -37,14 +32,9 @@ fn test_get_variable_parameters() {
 
     assert_eq!(
         output,
-        get_variable_parameters(
-            &project,
-            &pi_state,
-            &sprintf_symbol,
-            &format_string_index_map,
-            &mem_image,
-        )
-        .unwrap()
+        get_variable_parameters(&project, &pi_state, &sprintf_symbol, &mem_image)
+            .unwrap()
+            .0
     );
 
     output = vec![Arg::from_var(
@@ -59,103 +49,1171 @@ fn test_get_variable_parameters() {
     );
 
     assert_eq!(
-        output,
-        get_variable_parameters(
-            &project,
-            &pi_state,
-            &sprintf_symbol,
-            &format_string_index_map,
-            &mem_image,
-        )
+        output,
+        get_variable_parameters(&project, &pi_state, &sprintf_symbol, &mem_image)
+            .unwrap()
+            .0
+    );
+}
+
+#[test]
+fn test_get_variable_parameters_with_format_string_on_stack() {
+    let mem_image = RuntimeMemoryImage::mock();
+    let mut pi_state = mock_pi_state();
+    let mut wrapper_symbol = ExternSymbol::mock_string();
+    wrapper_symbol.name = "log_message".to_string();
+    let mut project = Project::mock_x64();
+    project
+        .format_string_functions
+        .register_format_string_function("log_message", 6);
+    // The format string '/dev/sd%c%d' is the first stack parameter.
+    let rsp = Variable::mock("RSP", 8);
+    wrapper_symbol.parameters = ["RDI", "RSI", "RDX", "RCX", "R8", "R9"]
+        .iter()
+        .map(|register| Arg::mock_register(register, 8))
+        .collect();
+    wrapper_symbol.parameters.push(create_stack_arg(
+        ByteSize::new(8),
+        8,
+        Datatype::Pointer,
+        &rsp,
+    ));
+    pi_state
+        .write_to_address(
+            &Expression::Var(rsp).plus_const(8),
+            &IntervalDomain::from(Bitvector::from_u64(0x5000)).into(),
+            &mem_image,
+        )
+        .unwrap();
+    let (var_args, logs) =
+        get_variable_parameters(&project, &pi_state, &wrapper_symbol, &mem_image).unwrap();
+    assert_eq!(var_args.len(), 2);
+    assert!(var_args.iter().all(|arg| matches!(arg, Arg::Stack { .. })));
+    assert_eq!(logs.len(), 1);
+}
+
+#[test]
+fn test_get_format_string_call_sites() {
+    let mut project = Project::mock_x64();
+    let mut sprintf_symbol = ExternSymbol::mock_string();
+    sprintf_symbol.addresses = vec!["0x1010".to_string()];
+    let other_symbol = ExternSymbol::mock_x64();
+    project.program.term.extern_symbols = BTreeMap::from([
+        (sprintf_symbol.tid.clone(), sprintf_symbol.clone()),
+        (other_symbol.tid.clone(), other_symbol.clone()),
+    ]);
+    let mut block = Blk::mock();
+    block.term.jmps = vec![
+        Jmp::call("direct_call", "sprintf", None),
+        Jmp::call("other_call", "mock_symbol", None),
+        Term {
+            tid: Tid::new("resolved_call"),
+            term: Jmp::CallInd {
+                target: Expression::Const(Bitvector::from_u64(0x1010)),
+                return_: None,
+            },
+        },
+        Term {
+            tid: Tid::new("unresolved_call"),
+            term: Jmp::CallInd {
+                target: Expression::Var(Variable::mock("RAX", 8)),
+                return_: None,
+            },
+        },
+    ];
+    let mut sub = Sub::mock("func");
+    sub.term.blocks = vec![block];
+    project.program.term.subs = BTreeMap::from([(sub.tid.clone(), sub)]);
+
+    let call_sites = get_format_string_call_sites(&project);
+    assert_eq!(call_sites.len(), 1);
+    assert_eq!(
+        call_sites[&sprintf_symbol.tid]
+            .iter()
+            .map(|jmp| jmp.tid.clone())
+            .collect::<Vec<_>>(),
+        vec![Tid::new("direct_call"), Tid::new("resolved_call")]
+    );
+}
+
+#[test]
+fn test_get_variable_parameters_of_address_interval() {
+    let mem_image = RuntimeMemoryImage::mock();
+    let mut pi_state = mock_pi_state();
+    let sprintf_symbol = ExternSymbol::mock_string();
+    let properties = DatatypeProperties::mock();
+    let project = Project::mock_x64();
+    // The address is either the address of '/dev/sd%c%d' or of 'cat %s'.
+    let address_interval: IntervalDomain = Interval::new(
+        Bitvector::from_u64(0x5000),
+        Bitvector::from_u64(0x500c),
+        0xc,
+    )
+    .into();
+    pi_state.set_register(&Variable::mock("RSI", 8), address_interval.into());
+    assert_eq!(
+        get_input_format_strings(
+            &pi_state,
+            &sprintf_symbol,
+            1,
+            &properties,
+            "x86_64",
+            &mem_image
+        )
+        .unwrap(),
+        vec!["/dev/sd%c%d".to_string(), "cat %s".to_string()]
+    );
+    assert!(get_input_format_string(
+        &pi_state,
+        &sprintf_symbol,
+        1,
+        &properties,
+        "x86_64",
+        &mem_image
+    )
+    .is_err());
+    // The parameters of the format string with the most parameters are returned.
+    assert_eq!(
+        get_variable_parameters(&project, &pi_state, &sprintf_symbol, &mem_image)
+            .unwrap()
+            .0,
+        vec![
+            Arg::from_var(Variable::mock("RDX", 8), Some(Datatype::Char)),
+            Arg::from_var(Variable::mock("RCX", 8), Some(Datatype::Integer)),
+        ]
+    );
+    // Exactly known addresses yield a single format string.
+    pi_state.set_register(
+        &Variable::mock("RSI", 8),
+        IntervalDomain::from(Bitvector::from_u64(0x500c)).into(),
+    );
+    assert_eq!(
+        get_input_format_strings(
+            &pi_state,
+            &sprintf_symbol,
+            1,
+            &properties,
+            "x86_64",
+            &mem_image
+        )
+        .unwrap(),
+        vec!["cat %s".to_string()]
+    );
+    // Too many candidate addresses are not read.
+    let address_interval: IntervalDomain =
+        Interval::new(Bitvector::from_u64(0x5000), Bitvector::from_u64(0x6000), 1).into();
+    pi_state.set_register(&Variable::mock("RSI", 8), address_interval.into());
+    assert!(get_variable_parameters(&project, &pi_state, &sprintf_symbol, &mem_image).is_err());
+}
+
+#[test]
+fn test_guess_format_string_index() {
+    let mem_image = RuntimeMemoryImage::mock();
+    let mut pi_state = mock_pi_state();
+    let mut wrapper_symbol = ExternSymbol::mock_string();
+    wrapper_symbol.name = "log_message".to_string();
+    let project = Project::mock_x64();
+    let set_string_address = |pi_state: &mut PointerInferenceState, register: &str, address| {
+        pi_state.set_register(
+            &Variable::mock(register, 8),
+            IntervalDomain::from(Bitvector::from_u64(address)).into(),
+        );
+    };
+    // RDI points to 'str1 str2 str3 str4' and RSI points to '/dev/sd%c%d'.
+    set_string_address(&mut pi_state, "RDI", 0x7000);
+    set_string_address(&mut pi_state, "RSI", 0x5000);
+    assert_eq!(
+        guess_format_string_index(&project, &pi_state, &wrapper_symbol, &mem_image),
+        Some(1)
+    );
+    assert_eq!(
+        get_variable_parameters(&project, &pi_state, &wrapper_symbol, &mem_image)
+            .unwrap()
+            .0,
+        vec![
+            Arg::from_var(Variable::mock("RDX", 8), Some(Datatype::Char)),
+            Arg::from_var(Variable::mock("RCX", 8), Some(Datatype::Integer)),
+        ]
+    );
+    // Without a format string parameter an error is returned.
+    set_string_address(&mut pi_state, "RSI", 0x7000);
+    assert_eq!(
+        guess_format_string_index(&project, &pi_state, &wrapper_symbol, &mem_image),
+        None
+    );
+    assert!(get_variable_parameters(&project, &pi_state, &wrapper_symbol, &mem_image).is_err());
+    // Registered format string functions are not guessed.
+    let mut project = project;
+    project
+        .format_string_functions
+        .register_format_string_function("log_message", 0);
+    set_string_address(&mut pi_state, "RSI", 0x5000);
+    assert_eq!(
+        get_format_string_index(&project, &pi_state, &wrapper_symbol, &mem_image).unwrap(),
+        0
+    );
+    assert_eq!(
+        get_variable_parameters(&project, &pi_state, &wrapper_symbol, &mem_image)
+            .unwrap()
+            .0,
+        Vec::new()
+    );
+}
+
+#[test]
+fn test_get_va_list_parameter() {
+    let mem_image = RuntimeMemoryImage::mock();
+    let mut pi_state = mock_pi_state();
+    let mut vsprintf_symbol = ExternSymbol::mock_string();
+    vsprintf_symbol.name = "vsprintf".to_string();
+    // The format string '/dev/sd%c%d'
+    let global_address = Bitvector::from_str_radix(16, "5000").unwrap();
+    pi_state.set_register(
+        &Variable::mock("RSI", 8),
+        IntervalDomain::new(global_address.clone(), global_address).into(),
+    );
+    let project = Project::mock_x64();
+
+    // No locations are computed for the variable parameters themselves.
+    assert!(get_variable_parameters(&project, &pi_state, &vsprintf_symbol, &mem_image).is_err());
+    assert_eq!(
+        get_va_list_parameter(&project, &pi_state, &vsprintf_symbol, &mem_image).unwrap(),
+        VaListParameter {
+            arg: Arg::from_var(Variable::mock("RDX", 8), Some(Datatype::Pointer)),
+            is_passed_by_reference: true,
+            conversion_count: 2,
+        }
+    );
+    // Functions with direct variable parameters take no `va_list`.
+    let sprintf_symbol = ExternSymbol::mock_string();
+    assert!(get_va_list_parameter(&project, &pi_state, &sprintf_symbol, &mem_image).is_err());
+
+    assert!(is_va_list_function("vfprintf"));
+    assert!(is_va_list_function("__vsnprintf_chk"));
+    assert!(is_va_list_function("__isoc99_vsscanf"));
+    assert!(!is_va_list_function("printf"));
+    assert!(!is_va_list_function("wprintf"));
+}
+
+#[test]
+fn test_get_input_format_string() {
+    let mem_image = RuntimeMemoryImage::mock();
+    let mut pi_state = mock_pi_state();
+    let sprintf_symbol = ExternSymbol::mock_string();
+
+    let global_address = Bitvector::from_str_radix(16, "3002").unwrap();
+    pi_state.set_register(
+        &Variable::mock("RSI", 8 as u64),
+        IntervalDomain::new(global_address.clone(), global_address).into(),
+    );
+
+    let properties = DatatypeProperties::mock();
+
+    assert_eq!(
+        "Hello World",
+        get_input_format_string(
+            &pi_state,
+            &sprintf_symbol,
+            1,
+            &properties,
+            "x86_64",
+            &mem_image
+        )
+        .unwrap()
+    );
+
+    let mut swprintf_symbol = sprintf_symbol;
+    swprintf_symbol.name = "swprintf".to_string();
+    let wide_string_address = Bitvector::from_str_radix(16, "800c").unwrap();
+    pi_state.set_register(
+        &Variable::mock("RSI", 8),
+        IntervalDomain::new(wide_string_address.clone(), wide_string_address).into(),
+    );
+
+    assert_eq!(
+        "%s",
+        get_input_format_string(
+            &pi_state,
+            &swprintf_symbol,
+            1,
+            &properties,
+            "x86_64",
+            &mem_image
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn test_is_wide_character_function() {
+    assert!(is_wide_character_function("wprintf"));
+    assert!(is_wide_character_function("swprintf"));
+    assert!(is_wide_character_function("fwscanf"));
+    assert!(!is_wide_character_function("printf"));
+    // Functions that only start with a `w` are not wide character functions.
+    assert!(!is_wide_character_function("warn"));
+    assert!(!is_wide_character_function("write_log"));
+}
+
+#[test]
+fn test_get_pc_relative_input_format_string() {
+    let mem_image = RuntimeMemoryImage::mock();
+    let mut pi_state = mock_pi_state();
+    let sprintf_symbol = ExternSymbol::mock_string();
+    let properties = DatatypeProperties::mock();
+    let mut function_tid = Tid::new("func");
+    function_tid.address = "3000".to_string();
+    let pc_id = AbstractIdentifier::new_from_var(function_tid, &Variable::mock("pc", 8));
+    let pc_relative_address = DataDomain::from_target(pc_id, Bitvector::from_i64(2).into());
+    pi_state.set_register(&Variable::mock("RSI", 8), pc_relative_address.clone());
+
+    assert_eq!(
+        resolve_relative_address(&pc_relative_address, "aarch64", &mem_image),
+        Some(Bitvector::from_u64(0x3002).into())
+    );
+    assert_eq!(
+        "Hello World",
+        get_input_format_string(
+            &pi_state,
+            &sprintf_symbol,
+            1,
+            &properties,
+            "ARM_32",
+            &mem_image
+        )
+        .unwrap()
+    );
+    // On x86 the value is not interpreted as relative to the program counter.
+    assert_eq!(
+        resolve_relative_address(&pc_relative_address, "x86_64", &mem_image),
+        None
+    );
+    assert!(get_input_format_string(
+        &pi_state,
+        &sprintf_symbol,
+        1,
+        &properties,
+        "x86_64",
+        &mem_image
+    )
+    .is_err());
+    // Targets outside of global memory are not resolved.
+    let unmapped_address = pc_relative_address.add_offset(&Bitvector::from_i64(0x10000).into());
+    assert_eq!(
+        resolve_relative_address(&unmapped_address, "aarch64", &mem_image),
+        None
+    );
+}
+
+#[test]
+fn test_arm32_pc_relative_address_without_pipeline_offset() {
+    let mem_image = RuntimeMemoryImage::mock();
+    let mut function_tid = Tid::new("func");
+    function_tid.address = "3000".to_string();
+    let pc_id = AbstractIdentifier::new_from_var(function_tid, &Variable::mock("pc", 4));
+    let pc_relative_address = DataDomain::from_target(pc_id, Bitvector::from_i32(2).into());
+    // The base of the offset is the function address itself and not the function address plus 8.
+    assert_eq!(
+        resolve_relative_address(&pc_relative_address, "ARM_32", &mem_image),
+        Some(Bitvector::from_u32(0x3002).into())
+    );
+}
+
+#[test]
+fn test_get_writable_input_format_string() {
+    let mem_image = RuntimeMemoryImage::mock();
+    let mut pi_state = mock_pi_state();
+    let sprintf_symbol = ExternSymbol::mock_string();
+    let properties = DatatypeProperties::mock();
+    let writable_address = Bitvector::from_u64(0x2000);
+    // The contents of writable memory are unknown to the pointer inference,
+    // so the format string is read from the memory image.
+    pi_state.set_register(
+        &Variable::mock("RSI", 8),
+        IntervalDomain::new(writable_address.clone(), writable_address).into(),
+    );
+    assert_eq!(
+        get_input_format_string(
+            &pi_state,
+            &sprintf_symbol,
+            1,
+            &properties,
+            "x86_64",
+            &mem_image
+        )
+        .unwrap(),
+        ""
+    );
+}
+
+#[test]
+fn test_get_thumb_input_format_string() {
+    let mem_image = RuntimeMemoryImage::mock();
+    let mut pi_state = mock_pi_state();
+    let sprintf_symbol = ExternSymbol::mock_string();
+    let properties = DatatypeProperties::mock();
+    let set_format_string_address = |pi_state: &mut PointerInferenceState, address: u64| {
+        let address = Bitvector::from_u64(address);
+        pi_state.set_register(
+            &Variable::mock("RSI", 8),
+            IntervalDomain::new(address.clone(), address).into(),
+        );
+    };
+    let read_format_string = |pi_state: &PointerInferenceState, cpu_architecture: &str| {
+        get_input_format_string(
+            pi_state,
+            &sprintf_symbol,
+            1,
+            &properties,
+            cpu_architecture,
+            &mem_image,
+        )
+        .unwrap()
+    };
+
+    // The address of the Hello World string with the Thumb bit set.
+    set_format_string_address(&mut pi_state, 0x3003);
+    assert_eq!(read_format_string(&pi_state, "ARM_32"), "Hello World");
+    assert_eq!(read_format_string(&pi_state, "aarch64"), "ello World");
+    assert_eq!(read_format_string(&pi_state, "x86_64"), "ello World");
+    // Strings starting at odd addresses directly after a null byte are not affected.
+    set_format_string_address(&mut pi_state, 0x9001);
+    assert_eq!(read_format_string(&pi_state, "ARM_32"), "@");
+}
+
+#[test]
+fn test_get_relative_input_format_string() {
+    let mem_image = RuntimeMemoryImage::mock();
+    let mut pi_state = mock_pi_state();
+    let sprintf_symbol = ExternSymbol::mock_string();
+    let properties = DatatypeProperties::mock();
+    let mut function_tid = Tid::new("func");
+    function_tid.address = "2f00".to_string();
+    // On MIPS the register `t9` contains the address of the function at the function start.
+    let t9_id = AbstractIdentifier::new_from_var(function_tid.clone(), &Variable::mock("t9", 8));
+    let t9_relative_address = DataDomain::from_target(t9_id, Bitvector::from_i64(0x102).into());
+    pi_state.set_register(&Variable::mock("RSI", 8), t9_relative_address.clone());
+    assert_eq!(
+        resolve_relative_address(&t9_relative_address, "MIPS_32", &mem_image),
+        Some(Bitvector::from_u64(0x3002).into())
+    );
+    assert_eq!(
+        "Hello World",
+        get_input_format_string(
+            &pi_state,
+            &sprintf_symbol,
+            1,
+            &properties,
+            "MIPS_32",
+            &mem_image
+        )
+        .unwrap()
+    );
+    // Values relative to other registers have no known base address.
+    let r12_id = AbstractIdentifier::new_from_var(function_tid, &Variable::mock("r12", 8));
+    let r12_relative_address = DataDomain::from_target(r12_id, Bitvector::from_i64(0x102).into());
+    pi_state.set_register(&Variable::mock("RSI", 8), r12_relative_address.clone());
+    assert_eq!(
+        resolve_relative_address(&r12_relative_address, "MIPS_32", &mem_image),
+        None
+    );
+    let error = get_input_format_string(
+        &pi_state,
+        &sprintf_symbol,
+        1,
+        &properties,
+        "MIPS_32",
+        &mem_image,
+    )
+    .unwrap_err();
+    assert!(error.to_string().contains("unknown base address"));
+}
+
+#[test]
+fn test_get_input_format_string_from_stack() {
+    let mem_image = RuntimeMemoryImage::mock();
+    let mut pi_state = mock_pi_state();
+    let sprintf_symbol = ExternSymbol::mock_string();
+    let properties = DatatypeProperties::mock();
+    let stack_pointer = pi_state.get_register(&Variable::mock("RSP", 8));
+    let string_address = stack_pointer.add_offset(&Bitvector::from_i64(-16).into());
+    pi_state.set_register(&Variable::mock("RSI", 8), string_address.clone());
+    // Only the prefix "cat " of the string is known.
+    pi_state
+        .store_value(
+            &string_address,
+            &Bitvector::from_u32(0x20746163).into(),
+            &mem_image,
+        )
+        .unwrap();
+
+    assert_eq!(
+        ("cat ".to_string(), false),
+        read_string_from_pointer_inference_memory(&pi_state, &string_address, &mem_image).unwrap()
+    );
+    assert!(get_input_format_string(
+        &pi_state,
+        &sprintf_symbol,
+        1,
+        &properties,
+        "x86_64",
+        &mem_image
+    )
+    .is_err());
+
+    // The remaining bytes "%s" and the null terminator are known.
+    pi_state
+        .store_value(
+            &stack_pointer.add_offset(&Bitvector::from_i64(-12).into()),
+            &Bitvector::from_u32(0x00007325).into(),
+            &mem_image,
+        )
+        .unwrap();
+
+    assert_eq!(
+        "cat %s",
+        get_input_format_string(
+            &pi_state,
+            &sprintf_symbol,
+            1,
+            &properties,
+            "x86_64",
+            &mem_image
+        )
+        .unwrap()
+    );
+    // Strings longer than the maximal length are returned as incomplete.
+    assert_eq!(
+        ("cat".to_string(), false),
+        read_string_from_pointer_inference_memory_bounded(
+            &pi_state,
+            &string_address,
+            &mem_image,
+            3
+        )
+        .unwrap()
+    );
+    assert_eq!(
+        ("cat %s".to_string(), true),
+        read_string_from_pointer_inference_memory_bounded(
+            &pi_state,
+            &string_address,
+            &mem_image,
+            6
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn test_get_size_parameter() {
+    let mem_image = RuntimeMemoryImage::mock();
+    let mut pi_state = mock_pi_state();
+    let sprintf_symbol = ExternSymbol::mock_string();
+    let mut snprintf_symbol = ExternSymbol::mock_string();
+    snprintf_symbol.name = "snprintf".to_string();
+    snprintf_symbol.parameters = vec![
+        Arg::mock_register("RDI", 8),
+        Arg::mock_register("RSI", 8),
+        Arg::mock_register("RDX", 8),
+    ];
+    let project = Project::mock_x64();
+
+    // Unbounded functions have no size parameter.
+    assert_eq!(
+        get_size_parameter(&project, &pi_state, &sprintf_symbol, &mem_image),
+        None
+    );
+    // The size parameter is unknown.
+    assert_eq!(
+        get_size_parameter(&project, &pi_state, &snprintf_symbol, &mem_image),
+        Some(SizeParameter::Unknown)
+    );
+    // The size parameter is known.
+    let size = IntervalDomain::mock(16, 32);
+    pi_state.set_register(&Variable::mock("RSI", 8), size.clone().into());
+    assert_eq!(
+        get_size_parameter(&project, &pi_state, &snprintf_symbol, &mem_image),
+        Some(SizeParameter::Known(size))
+    );
+}
+
+#[test]
+fn test_get_destination_arg() {
+    let project = Project::mock_x64();
+    let mut symbol = ExternSymbol::mock_string();
+    assert_eq!(
+        get_destination_arg(&symbol, &project),
+        Some(Arg::mock_register("RDI", 8))
+    );
+    // The location of missing parameters is derived from the calling convention.
+    symbol.name = "strcpy".to_string();
+    symbol.parameters = Vec::new();
+    assert_eq!(
+        get_destination_arg(&symbol, &project),
+        Some(Arg::from_var(
+            Variable::mock("RDI", 8),
+            Some(Datatype::Pointer)
+        ))
+    );
+    symbol.name = "printf".to_string();
+    assert_eq!(get_destination_arg(&symbol, &project), None);
+}
+
+#[test]
+fn test_get_fixed_args_of() {
+    let mem_image = RuntimeMemoryImage::mock();
+    let mut pi_state = mock_pi_state();
+    let mut project = Project::mock_empty();
+    let mut memcpy_symbol = ExternSymbol::mock_x64();
+    memcpy_symbol.tid = Tid::new("memcpy");
+    memcpy_symbol.name = "memcpy".to_string();
+    memcpy_symbol.parameters = vec![
+        Arg::mock_register("RDI", 8),
+        Arg::mock_register("RSI", 8),
+        Arg::mock_register("RDX", 8),
+    ];
+    // Unknown symbols have no arguments.
+    assert!(
+        get_fixed_args_of(&memcpy_symbol, &project, &pi_state, &mem_image)
+            .unwrap()
+            .is_empty()
+    );
+
+    project
+        .program
+        .term
+        .extern_symbols
+        .insert(memcpy_symbol.tid.clone(), memcpy_symbol.clone());
+    project
+        .calling_conventions
+        .insert("__stdcall".to_string(), CallingConvention::mock_x64());
+    let length = IntervalDomain::mock(8, 16);
+    pi_state.set_register(&Variable::mock("RDX", 8), length.clone().into());
+    let args = get_fixed_args_of(&memcpy_symbol, &project, &pi_state, &mem_image).unwrap();
+    assert_eq!(args.len(), 3);
+    assert!(args[0].is_top());
+    assert_eq!(args[2], length.into());
+
+    // Symbols with a calling convention unknown to the project result in an error.
+    memcpy_symbol.calling_convention = Some("__unknown".to_string());
+    project
+        .program
+        .term
+        .extern_symbols
+        .insert(memcpy_symbol.tid.clone(), memcpy_symbol.clone());
+    assert!(get_fixed_args_of(&memcpy_symbol, &project, &pi_state, &mem_image).is_err());
+}
+
+#[test]
+fn test_eval_arg() {
+    let mem_image = RuntimeMemoryImage::mock();
+    let mut pi_state = mock_pi_state();
+    let rsp = Variable::mock("RSP", 8);
+    let value: DataDomain<IntervalDomain> = IntervalDomain::mock(3, 5).into();
+    // Register arguments
+    pi_state.set_register(&Variable::mock("RDI", 8), value.clone());
+    assert_eq!(
+        eval_arg(&pi_state, &Arg::mock_register("RDI", 8), &mem_image).unwrap(),
+        value
+    );
+    // Stack arguments
+    let stack_arg = create_stack_arg(ByteSize::new(8), 8, Datatype::Integer, &rsp);
+    pi_state
+        .write_to_address(&Expression::Var(rsp).plus_const(8), &value, &mem_image)
+        .unwrap();
+    assert_eq!(eval_arg(&pi_state, &stack_arg, &mem_image).unwrap(), value);
+    // Stack arguments at addresses that cannot be read
+    let invalid_arg = Arg::Stack {
+        address: Expression::const_from_i64(1),
+        size: ByteSize::new(8),
+        data_type: None,
+    };
+    assert!(eval_arg(&pi_state, &invalid_arg, &mem_image).is_err());
+}
+
+#[test]
+fn test_parse_format_string_destination_and_return_content() {
+    let mem_image = RuntimeMemoryImage::mock();
+    let string_address_vector = Bitvector::from_str_radix(16, "3002").unwrap();
+    let string_address = IntervalDomain::new(string_address_vector.clone(), string_address_vector);
+
+    assert_eq!(
+        "Hello World",
+        parse_format_string_destination_and_return_content(string_address, 0, &mem_image).unwrap()
+    );
+}
+
+#[test]
+fn test_parse_format_string_destination_with_pointer_hops() {
+    let mem_image = RuntimeMemoryImage::mock();
+    let address = |value: u64| IntervalDomain::from(Bitvector::from_u64(value));
+
+    // 0x9000 -> 0x4000 -> 0x3002 ("Hello World")
+    assert_eq!(
+        parse_format_string_destination_and_return_content(address(0x9000), 2, &mem_image).unwrap(),
+        "Hello World"
+    );
+    // Additional allowed hops are not used if the value is no valid address.
+    assert_eq!(
+        parse_format_string_destination_and_return_content(address(0x9000), 5, &mem_image).unwrap(),
+        "Hello World"
+    );
+    // Without pointer hops the bytes of the pointer are read as a string.
+    assert_eq!(
+        parse_format_string_destination_and_return_content(address(0x4000), 0, &mem_image).unwrap(),
+        "\u{2}0"
+    );
+    assert_eq!(
+        parse_format_string_destination_and_return_content(address(0x4000), 1, &mem_image).unwrap(),
+        "Hello World"
+    );
+    // 0x9008 -> 0x9010 -> 0x9008
+    assert!(
+        parse_format_string_destination_and_return_content(address(0x9008), 3, &mem_image).is_err()
+    );
+}
+
+#[test]
+fn test_parse_format_string_destination_with_base_offset() {
+    let mut mem_image = RuntimeMemoryImage::mock_big_endian();
+    mem_image.add_global_memory_offset(0x10_0000);
+    let address = |value: u32| IntervalDomain::from(Bitvector::from_u32(value));
+
+    // The string address and the pointer target are rebased by the base offset.
+    assert_eq!(
+        parse_format_string_destination_and_return_content(address(0x2003), 0, &mem_image).unwrap(),
+        "id %d"
+    );
+    assert_eq!(
+        parse_format_string_destination_and_return_content(address(0x1000), 1, &mem_image).unwrap(),
+        "%s"
+    );
+    // Already rebased addresses are not translated again.
+    assert_eq!(
+        parse_format_string_destination_and_return_content(address(0x10_1004), 1, &mem_image)
+            .unwrap(),
+        "id %d"
+    );
+}
+
+#[test]
+fn test_parse_wide_format_string_destination_with_base_offset() {
+    let mut mem_image = RuntimeMemoryImage::mock();
+    mem_image.add_global_memory_offset(0x10_0000);
+    let char_size = DatatypeProperties::mock().wide_char_size;
+    let address = |value: u64| IntervalDomain::from(Bitvector::from_u64(value));
+
+    // Wide format strings are rebased by the base offset like narrow format strings.
+    assert_eq!(
+        parse_wide_format_string_destination_and_return_content(
+            address(0x800c),
+            char_size,
+            &mem_image
+        )
+        .unwrap(),
+        "%s"
+    );
+    assert_eq!(
+        parse_wide_format_string_destination_and_return_content(
+            address(0x10_800c),
+            char_size,
+            &mem_image
+        )
+        .unwrap(),
+        "%s"
+    );
+}
+
+#[test]
+fn test_parse_format_string_destination_with_big_endian_pointer_hops() {
+    let mem_image = RuntimeMemoryImage::mock_big_endian();
+    let address = |value: u32| IntervalDomain::from(Bitvector::from_u32(value));
+
+    // 0x1000 -> 0x2000 ("%s")
+    assert_eq!(
+        parse_format_string_destination_and_return_content(address(0x1000), 1, &mem_image).unwrap(),
+        "%s"
+    );
+    // 0x1004 -> 0x2003 ("id %d")
+    assert_eq!(
+        parse_format_string_destination_and_return_content(address(0x1004), 1, &mem_image).unwrap(),
+        "id %d"
+    );
+}
+
+#[test]
+fn test_parse_format_string_parameters() {
+    let test_cases: Vec<&str> = vec![
+        "%s \"%s\" %s",
+        "ifconfig eth0 add 3ffe:501:ffff:101:2%02x:%02xff:fe%02x:%02x%02x/64",
+        "/dev/sd%c%d",
+        "%s: Unable to open \'%s\', errno=%d\n",
+        "%s %lli",
+        "%ld %Lf %lu",
+        "%s%n",
+        "%hhn%hn%n",
+        "%zu %td %jd %hhx",
+    ];
+    let properties = DatatypeProperties::mock();
+    let expected_outputs: Vec<Vec<(Datatype, ByteSize)>> = vec![
+        vec![
+            (Datatype::from("s".to_string()), properties.pointer_size),
+            (Datatype::from("s".to_string()), properties.pointer_size),
+            (Datatype::from("s".to_string()), properties.pointer_size),
+        ],
+        vec![
+            (Datatype::from("x".to_string()), properties.integer_size),
+            (Datatype::from("x".to_string()), properties.integer_size),
+            (Datatype::from("x".to_string()), properties.integer_size),
+            (Datatype::from("x".to_string()), properties.integer_size),
+            (Datatype::from("x".to_string()), properties.integer_size),
+        ],
+        vec![
+            (Datatype::from("c".to_string()), properties.integer_size),
+            (Datatype::from("d".to_string()), properties.integer_size),
+        ],
+        vec![
+            (Datatype::from("s".to_string()), properties.pointer_size),
+            (Datatype::from("s".to_string()), properties.pointer_size),
+            (Datatype::from("d".to_string()), properties.integer_size),
+        ],
+        vec![
+            (Datatype::from("s".to_string()), properties.pointer_size),
+            (Datatype::from("lli".to_string()), properties.long_long_size),
+        ],
+        vec![
+            (Datatype::from("ld".to_string()), properties.long_size),
+            (
+                Datatype::from("Lf".to_string()),
+                properties.long_double_size,
+            ),
+            (Datatype::from("lu".to_string()), properties.long_size),
+        ],
+        vec![
+            (Datatype::Pointer, properties.pointer_size),
+            (Datatype::WritebackPointer, properties.pointer_size),
+        ],
+        vec![
+            (Datatype::CharWritebackPointer, properties.pointer_size),
+            (Datatype::ShortWritebackPointer, properties.pointer_size),
+            (Datatype::WritebackPointer, properties.pointer_size),
+        ],
+        vec![
+            (Datatype::SizeT, properties.pointer_size),
+            (Datatype::SizeT, properties.pointer_size),
+            (Datatype::LongLong, properties.long_long_size),
+            (Datatype::Integer, properties.integer_size),
+        ],
+    ];
+
+    for (case, output) in test_cases.into_iter().zip(expected_outputs.into_iter()) {
+        assert_eq!(
+            output,
+            parse_format_string_parameters(case, &properties).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_parse_size_t_format_string_parameters() {
+    let mut properties = DatatypeProperties::mock();
+    for pointer_size in [4, 8] {
+        properties.pointer_size = ByteSize::new(pointer_size);
+        for format_string in ["%zu bytes", "%td", "%ju"] {
+            let parameters = parse_format_string_parameters(format_string, &properties).unwrap();
+            let expected_parameter = if format_string == "%ju" {
+                (Datatype::LongLong, properties.long_long_size)
+            } else {
+                (Datatype::SizeT, ByteSize::new(pointer_size))
+            };
+            assert_eq!(parameters, vec![expected_parameter]);
+        }
+    }
+}
+
+#[test]
+fn test_writeback_target_size() {
+    let properties = DatatypeProperties::mock();
+    let params = parse_format_string_parameters("%hhn %hn %n %d", &properties).unwrap();
+    let target_sizes: Vec<Option<ByteSize>> = params
+        .iter()
+        .map(|(data_type, _)| properties.get_writeback_target_size(data_type))
+        .collect();
+    assert_eq!(
+        target_sizes,
+        vec![
+            Some(properties.char_size),
+            Some(properties.short_size),
+            Some(properties.integer_size),
+            None
+        ]
+    );
+}
+
+#[test]
+fn test_parse_format_string_parameters_without_arguments() {
+    let properties = DatatypeProperties::mock();
+    assert_eq!(
+        vec![(Datatype::Pointer, properties.pointer_size)],
+        parse_format_string_parameters("%m: %s", &properties).unwrap()
+    );
+    assert_eq!(
+        vec![(Datatype::Integer, properties.integer_size)],
+        parse_format_string_parameters("100%% %%s %m%d", &properties).unwrap()
+    );
+}
+
+#[test]
+fn test_parse_format_string_parameters_with_variable_width_and_precision() {
+    let properties = DatatypeProperties::mock();
+    assert_eq!(
+        vec![
+            (Datatype::Integer, properties.integer_size),
+            (Datatype::Integer, properties.integer_size),
+            (Datatype::Pointer, properties.pointer_size),
+        ],
+        parse_format_string_parameters("%*.*s", &properties).unwrap()
+    );
+    assert_eq!(
+        vec![
+            (Datatype::Integer, properties.integer_size),
+            (Datatype::Integer, properties.integer_size),
+            (Datatype::Integer, properties.integer_size),
+            (Datatype::Double, properties.double_size),
+            (Datatype::Double, properties.double_size),
+        ],
+        parse_format_string_parameters("%*d %.*f %.2f", &properties).unwrap()
+    );
+}
+
+#[test]
+fn test_parse_format_string_parameters_with_wide_width_and_precision() {
+    let properties = DatatypeProperties::mock();
+    assert_eq!(
+        vec![
+            (Datatype::Pointer, properties.pointer_size),
+            (Datatype::Pointer, properties.pointer_size),
+            (Datatype::Long, properties.long_size),
+            (Datatype::Double, properties.double_size),
+        ],
+        parse_format_string_parameters("%100s %.256s %1000ld %123.456f", &properties).unwrap()
+    );
+}
+
+#[test]
+fn test_parse_format_string_parameters_with_flags() {
+    let properties = DatatypeProperties::mock();
+    assert_eq!(
+        vec![(Datatype::Pointer, properties.pointer_size)],
+        parse_format_string_parameters("%-20s", &properties).unwrap()
+    );
+    assert_eq!(
+        vec![(Datatype::Double, properties.double_size)],
+        parse_format_string_parameters("%+.3f", &properties).unwrap()
+    );
+    assert_eq!(
+        vec![(Datatype::Integer, properties.integer_size)],
+        parse_format_string_parameters("%#08x", &properties).unwrap()
+    );
+    assert_eq!(
+        vec![
+            (Datatype::Integer, properties.integer_size),
+            (Datatype::Integer, properties.integer_size),
+            // The width argument of `%-*s` and the string argument itself.
+            (Datatype::Integer, properties.integer_size),
+            (Datatype::Pointer, properties.pointer_size),
+            (Datatype::Double, properties.double_size),
+        ],
+        parse_format_string_parameters("% d %'d %-*s %123.456f", &properties).unwrap()
+    );
+}
+
+#[test]
+fn test_parse_format_string_parameters_with_extra_conversions() {
+    let properties = DatatypeProperties::mock();
+    // The GNU `%b` conversion is known by default and respects integer length modifiers.
+    assert_eq!(
+        vec![
+            (Datatype::Integer, properties.integer_size),
+            (Datatype::Long, properties.long_size),
+            (Datatype::Pointer, properties.pointer_size),
+        ],
+        parse_format_string_parameters("%08b %lB %s", &properties).unwrap()
+    );
+    assert!(parse_format_string_parameters("%k", &properties)
         .unwrap()
+        .is_empty());
+    // Custom conversions can be added to the format string functions of the project.
+    let mut project = Project::mock_x64();
+    project
+        .format_string_functions
+        .extra_conversions
+        .insert('k', Datatype::Pointer);
+    assert_eq!(
+        vec![
+            (Datatype::Pointer, properties.pointer_size),
+            (Datatype::Integer, properties.integer_size),
+        ],
+        parse_format_string_parameters_of_project(&project, "printf", "%k %d").unwrap()
+    );
+    // Extra conversions are not recognized for `scanf`-like functions.
+    assert_eq!(
+        vec![(Datatype::WritebackPointer, properties.pointer_size)],
+        parse_format_string_parameters_of_project(&project, "scanf", "%b %d").unwrap()
     );
 }
 
 #[test]
-fn test_get_input_format_string() {
-    let mem_image = RuntimeMemoryImage::mock();
-    let mut pi_state = mock_pi_state();
-    let sprintf_symbol = ExternSymbol::mock_string();
-
-    let global_address = Bitvector::from_str_radix(16, "3002").unwrap();
-    pi_state.set_register(
-        &Variable::mock("RSI", 8 as u64),
-        IntervalDomain::new(global_address.clone(), global_address).into(),
+fn test_parse_format_string_parameters_detailed() {
+    let properties = DatatypeProperties::mock();
+    let param = |data_type: Datatype, size: ByteSize, specifier: &str, offset: usize| {
+        FormatStringParameter {
+            data_type,
+            size,
+            specifier: specifier.to_string(),
+            offset,
+            has_precision: false,
+            is_suspicious_positional_write: false,
+        }
+    };
+    assert_eq!(
+        vec![
+            param(Datatype::Integer, properties.integer_size, "%08x", 4),
+            param(Datatype::Integer, properties.integer_size, "%*s", 13),
+            param(Datatype::Pointer, properties.pointer_size, "%*s", 13),
+            param(Datatype::Integer, properties.integer_size, "%x", 18),
+        ],
+        parse_format_string_parameters_detailed("id: %08x, %% %*s, %x", &properties).unwrap()
     );
-
     assert_eq!(
-        "Hello World",
-        get_input_format_string(&pi_state, &sprintf_symbol, 1, &mem_image).unwrap()
+        vec![
+            param(Datatype::Integer, properties.integer_size, "%1$d", 5),
+            param(Datatype::Pointer, properties.pointer_size, "%2$s", 0),
+        ],
+        parse_format_string_parameters_detailed("%2$s %1$d", &properties).unwrap()
     );
 }
 
 #[test]
-fn test_parse_format_string_destination_and_return_content() {
-    let mem_image = RuntimeMemoryImage::mock();
-    let string_address_vector = Bitvector::from_str_radix(16, "3002").unwrap();
-    let string_address = IntervalDomain::new(string_address_vector.clone(), string_address_vector);
-
+fn test_suspicious_positional_writes() {
+    let properties = DatatypeProperties::mock();
+    let flags = |format_string: &str| -> Vec<(String, bool)> {
+        parse_format_string_parameters_detailed(format_string, &properties)
+            .unwrap()
+            .into_iter()
+            .map(|param| (param.specifier, param.is_suspicious_positional_write))
+            .collect()
+    };
+    assert_eq!(flags("%7$n"), vec![("%7$n".to_string(), true)]);
     assert_eq!(
-        "Hello World",
-        parse_format_string_destination_and_return_content(string_address, &mem_image).unwrap()
+        flags("%1$s%5$hn%6$n"),
+        vec![
+            ("%1$s".to_string(), false),
+            ("%5$hn".to_string(), true),
+            ("%6$n".to_string(), true),
+        ]
+    );
+    // Writes to consecutively referenced arguments are legitimate.
+    assert_eq!(
+        flags("%1$s%2$n"),
+        vec![("%1$s".to_string(), false), ("%2$n".to_string(), false)]
     );
+    // Skipped arguments before other conversions are still an error.
+    assert!(parse_format_string_parameters_detailed("%3$n %4$d", &properties).is_err());
+    // The argument positions are unknown, so the simple parser returns an error.
+    assert!(parse_format_string_parameters("%7$n", &properties).is_err());
 }
 
 #[test]
-fn test_parse_format_string_parameters() {
-    let test_cases: Vec<&str> = vec![
-        "%s \"%s\" %s",
-        "ifconfig eth0 add 3ffe:501:ffff:101:2%02x:%02xff:fe%02x:%02x%02x/64",
-        "/dev/sd%c%d",
-        "%s: Unable to open \'%s\', errno=%d\n",
-        "%s %lli",
-    ];
+fn test_unbounded_string_specifiers() {
     let properties = DatatypeProperties::mock();
-    let expected_outputs: Vec<Vec<(Datatype, ByteSize)>> = vec![
-        vec![
-            (Datatype::from("s".to_string()), properties.pointer_size),
-            (Datatype::from("s".to_string()), properties.pointer_size),
-            (Datatype::from("s".to_string()), properties.pointer_size),
-        ],
+    let params =
+        parse_format_string_parameters_detailed("%s %.32s %.*s %10s %.s %d", &properties).unwrap();
+    let flags: Vec<(bool, bool)> = params
+        .iter()
+        .map(|param| (param.has_precision, param.is_unbounded_string()))
+        .collect();
+    assert_eq!(
+        flags,
         vec![
-            (Datatype::from("x".to_string()), properties.integer_size),
-            (Datatype::from("x".to_string()), properties.integer_size),
-            (Datatype::from("x".to_string()), properties.integer_size),
-            (Datatype::from("x".to_string()), properties.integer_size),
-            (Datatype::from("x".to_string()), properties.integer_size),
-        ],
+            (false, true),
+            (true, false),
+            // The precision argument of `%.*s` and the string argument itself.
+            (true, false),
+            (true, false),
+            (false, true),
+            (true, false),
+            (false, false),
+        ]
+    );
+}
+
+#[test]
+fn test_parse_scanf_format_string_parameters() {
+    let properties = DatatypeProperties::mock();
+    assert_eq!(
         vec![
-            (Datatype::from("c".to_string()), properties.integer_size),
-            (Datatype::from("d".to_string()), properties.integer_size),
+            (Datatype::WritebackPointer, properties.pointer_size),
+            (Datatype::ShortWritebackPointer, properties.pointer_size),
+            (Datatype::CharWritebackPointer, properties.pointer_size),
+            (Datatype::Pointer, properties.pointer_size),
+            (Datatype::GenericWritebackPointer, properties.pointer_size),
+            (Datatype::GenericWritebackPointer, properties.pointer_size),
         ],
+        parse_scanf_format_string_parameters("%d %hu %c %10s %lf %lld", &properties).unwrap()
+    );
+    // Conversions with suppressed assignment do not consume an argument.
+    assert_eq!(
+        vec![(Datatype::WritebackPointer, properties.pointer_size)],
+        parse_scanf_format_string_parameters("%*s %d %%", &properties).unwrap()
+    );
+}
+
+#[test]
+fn test_get_variable_parameters_of_scanf() {
+    let mem_image = RuntimeMemoryImage::mock();
+    let mut pi_state = mock_pi_state();
+    let mut sscanf_symbol = ExternSymbol::mock_string();
+    sscanf_symbol.name = "sscanf".to_string();
+    // The format string '/dev/sd%c%d'
+    let global_address = Bitvector::from_str_radix(16, "5000").unwrap();
+    pi_state.set_register(
+        &Variable::mock("RSI", 8),
+        IntervalDomain::new(global_address.clone(), global_address).into(),
+    );
+    let project = Project::mock_x64();
+
+    assert_eq!(
         vec![
-            (Datatype::from("s".to_string()), properties.pointer_size),
-            (Datatype::from("s".to_string()), properties.pointer_size),
-            (Datatype::from("d".to_string()), properties.integer_size),
+            Arg::from_var(
+                Variable::mock("RDX", 8),
+                Some(Datatype::CharWritebackPointer)
+            ),
+            Arg::from_var(Variable::mock("RCX", 8), Some(Datatype::WritebackPointer)),
         ],
+        get_variable_parameters(&project, &pi_state, &sscanf_symbol, &mem_image)
+            .unwrap()
+            .0
+    );
+}
+
+#[test]
+fn test_parse_positional_format_string_parameters() {
+    let properties = DatatypeProperties::mock();
+    assert_eq!(
         vec![
-            (Datatype::from("s".to_string()), properties.pointer_size),
-            (Datatype::from("lli".to_string()), properties.pointer_size),
+            (Datatype::Pointer, properties.pointer_size),
+            (Datatype::Integer, properties.integer_size),
         ],
-    ];
-
-    for (index, (case, output)) in test_cases
-        .into_iter()
-        .zip(expected_outputs.into_iter())
-        .enumerate()
-    {
-        if index == 4 {
-            assert_ne!(
-                output,
-                parse_format_string_parameters(case, &properties).unwrap_or(vec![])
-            );
-        } else {
-            assert_eq!(
-                output,
-                parse_format_string_parameters(case, &properties).unwrap()
-            );
-        }
-    }
+        parse_format_string_parameters("%2$d: %1$s (%2$d)", &properties).unwrap()
+    );
+    // Mixing positional and non-positional specifiers is an error.
+    assert!(parse_format_string_parameters("%2$d %s", &properties).is_err());
+    // Gaps in the referenced arguments are an error.
+    assert!(parse_format_string_parameters("%1$s %3$d", &properties).is_err());
+    // Conflicting data types for the same argument are an error.
+    assert!(parse_format_string_parameters("%1$s %1$d", &properties).is_err());
 }
 
 #[test]
@@ -166,7 +1224,7 @@ fn test_calculate_parameter_locations() {
     let mut parameters: Vec<(Datatype, ByteSize)> = Vec::new();
     parameters.push(("d".to_string().into(), ByteSize::new(8)));
     parameters.push(("f".to_string().into(), ByteSize::new(16)));
-    parameters.push(("s".to_string().into(), ByteSize::new(8)));
+    parameters.push(("n".to_string().into(), ByteSize::new(8)));
 
     let mut expected_args = vec![
         Arg::Register {
@@ -183,11 +1241,11 @@ fn test_calculate_parameter_locations() {
         },
         Arg::Register {
             expr: Expression::Var(Variable::mock("RCX", ByteSize::new(8))),
-            data_type: Some(Datatype::Pointer),
+            data_type: Some(Datatype::WritebackPointer),
         },
     ];
 
-    // Test Case 1: The string parameter is still written in the RCX register since 'f' is contained in the float register.
+    // Test Case 1: The writeback pointer parameter is still written in the RCX register since 'f' is contained in the float register.
     assert_eq!(
         expected_args,
         calculate_parameter_locations(
@@ -195,8 +1253,10 @@ fn test_calculate_parameter_locations() {
             &cconv,
             format_string_index,
             &Variable::mock("RSP", 8),
-            "x86_64"
+            "x86_64",
+            &DatatypeProperties::mock()
         )
+        .0
     );
 
     parameters.push(("s".to_string().into(), ByteSize::new(8)));
@@ -225,9 +1285,265 @@ fn test_calculate_parameter_locations() {
             &cconv,
             format_string_index,
             &Variable::mock("RSP", 8),
-            "x86_64"
+            "x86_64",
+            &DatatypeProperties::mock()
+        )
+        .0
+    );
+}
+
+#[test]
+fn test_calculate_parameter_locations_for_hexadecimal_floats() {
+    let properties = DatatypeProperties::mock_x64();
+    let parameters = parse_format_string_parameters("%a %f %LA", &properties).unwrap();
+    assert_eq!(
+        parameters,
+        vec![
+            (Datatype::Double, properties.double_size),
+            (Datatype::Double, properties.double_size),
+            (Datatype::LongDouble, properties.long_double_size),
+        ]
+    );
+    let float_register = |index: u64| Arg::Register {
+        expr: Expression::subpiece(
+            Expression::Var(Variable::mock(format!("ZMM{}", index), 64)),
+            ByteSize::new(0),
+            ByteSize::new(8),
+        ),
+        data_type: Some(Datatype::Double),
+    };
+    let expected_args = vec![
+        float_register(0),
+        float_register(1),
+        // The long double value is passed on the stack on x86.
+        Arg::Stack {
+            address: Expression::Var(Variable::mock("RSP", 8)).plus_const(8),
+            size: ByteSize::new(16),
+            data_type: Some(Datatype::LongDouble),
+        },
+    ];
+    assert_eq!(
+        calculate_parameter_locations(
+            parameters,
+            &CallingConvention::mock_x64(),
+            1,
+            &Variable::mock("RSP", 8),
+            "x86_64",
+            &properties
+        )
+        .0,
+        expected_args
+    );
+}
+
+#[test]
+/// Tests parameter locations if the format string itself is passed on the stack.
+fn test_calculate_parameter_locations_for_format_string_on_stack() {
+    let cconv = CallingConvention::mock_x64();
+    let format_string_index = cconv.integer_parameter_register.len();
+    let parameters: Vec<(Datatype, ByteSize)> = vec![
+        (Datatype::Integer, ByteSize::new(8)),
+        (Datatype::Pointer, ByteSize::new(8)),
+    ];
+    let (var_args, logs) = calculate_parameter_locations(
+        parameters,
+        &cconv,
+        format_string_index,
+        &Variable::mock("RSP", 8),
+        "x86_64",
+        &DatatypeProperties::mock(),
+    );
+    // The return address and the format string parameter precede the variable parameters.
+    let expected_args = vec![
+        Arg::Stack {
+            address: Expression::Var(Variable::mock("RSP", 8)).plus_const(16),
+            size: ByteSize::new(8),
+            data_type: Some(Datatype::Integer),
+        },
+        Arg::Stack {
+            address: Expression::Var(Variable::mock("RSP", 8)).plus_const(24),
+            size: ByteSize::new(8),
+            data_type: Some(Datatype::Pointer),
+        },
+    ];
+    assert_eq!(var_args, expected_args);
+    assert_eq!(logs.len(), 1);
+}
+
+#[test]
+/// Tests tracking of long, long long and long double parameters.
+fn test_calculate_parameter_locations_for_long_types() {
+    // Test Case 1: On x86_64 long types fit into a single register and long double is passed on the stack.
+    let cconv = CallingConvention::mock_x64();
+    let parameters: Vec<(Datatype, ByteSize)> = vec![
+        ("ld".to_string().into(), ByteSize::new(8)),
+        ("Lf".to_string().into(), ByteSize::new(16)),
+        ("lld".to_string().into(), ByteSize::new(8)),
+    ];
+    let expected_args = vec![
+        Arg::from_var(Variable::mock("RDX", 8), Some(Datatype::Long)),
+        Arg::Stack {
+            address: Expression::Var(Variable::mock("RSP", 8)).plus_const(8),
+            size: ByteSize::new(16),
+            data_type: Some(Datatype::LongDouble),
+        },
+        Arg::from_var(Variable::mock("RCX", 8), Some(Datatype::LongLong)),
+    ];
+    assert_eq!(
+        expected_args,
+        calculate_parameter_locations(
+            parameters,
+            &cconv,
+            1,
+            &Variable::mock("RSP", 8),
+            "x86_64",
+            &DatatypeProperties::mock()
+        )
+        .0
+    );
+
+    // Test Case 2: On 32-bit ARM a long long value consumes a register pair starting at an even register,
+    // so `r1` is skipped.
+    // If no register pair is left, the value is passed on the stack.
+    let project = Project::mock_arm32();
+    let parameters: Vec<(Datatype, ByteSize)> = vec![
+        ("lld".to_string().into(), ByteSize::new(8)),
+        ("lld".to_string().into(), ByteSize::new(8)),
+        ("d".to_string().into(), ByteSize::new(4)),
+    ];
+    let expected_args = vec![
+        create_register_pair_arg(
+            Variable::mock("r2", 4),
+            Variable::mock("r3", 4),
+            Datatype::LongLong,
+        ),
+        Arg::Stack {
+            address: Expression::Var(Variable::mock("sp", 4)).plus_const(0),
+            size: ByteSize::new(8),
+            data_type: Some(Datatype::LongLong),
+        },
+        Arg::Stack {
+            address: Expression::Var(Variable::mock("sp", 4)).plus_const(8),
+            size: ByteSize::new(4),
+            data_type: Some(Datatype::Integer),
+        },
+    ];
+    assert_eq!(
+        expected_args,
+        calculate_parameter_locations(
+            parameters,
+            project.get_standard_calling_convention().unwrap(),
+            0,
+            &project.stack_pointer_register,
+            &project.cpu_architecture,
+            &project.datatype_properties
+        )
+        .0
+    );
+}
+
+#[test]
+/// Tests the even-register alignment of register pairs on 32-bit ARM.
+fn test_calculate_parameter_locations_for_arm32_register_pairs() {
+    let project = Project::mock_arm32();
+    let calculate = |parameters: Vec<(Datatype, ByteSize)>| {
+        calculate_parameter_locations(
+            parameters,
+            project.get_standard_calling_convention().unwrap(),
+            0,
+            &project.stack_pointer_register,
+            &project.cpu_architecture,
+            &project.datatype_properties,
         )
+        .0
+    };
+    // An int in `r1` is followed by a long long in the register pair `r2:r3`.
+    assert_eq!(
+        calculate(vec![
+            (Datatype::Integer, ByteSize::new(4)),
+            (Datatype::LongLong, ByteSize::new(8)),
+        ]),
+        vec![
+            Arg::from_var(Variable::mock("r1", 4), Some(Datatype::Integer)),
+            create_register_pair_arg(
+                Variable::mock("r2", 4),
+                Variable::mock("r3", 4),
+                Datatype::LongLong,
+            ),
+        ]
+    );
+    // After two ints only `r3` is left, so the long long is passed on the stack
+    // and all following parameters are also passed on the stack.
+    assert_eq!(
+        calculate(vec![
+            (Datatype::Integer, ByteSize::new(4)),
+            (Datatype::Integer, ByteSize::new(4)),
+            (Datatype::LongLong, ByteSize::new(8)),
+            (Datatype::Integer, ByteSize::new(4)),
+        ]),
+        vec![
+            Arg::from_var(Variable::mock("r1", 4), Some(Datatype::Integer)),
+            Arg::from_var(Variable::mock("r2", 4), Some(Datatype::Integer)),
+            create_stack_arg(
+                ByteSize::new(8),
+                0,
+                Datatype::LongLong,
+                &project.stack_pointer_register
+            ),
+            create_stack_arg(
+                ByteSize::new(4),
+                8,
+                Datatype::Integer,
+                &project.stack_pointer_register
+            ),
+        ]
+    );
+}
+
+#[test]
+/// Tests the alignment of mixed-size parameters passed on the stack.
+fn test_calculate_parameter_locations_with_stack_alignment() {
+    let project = Project::mock_x64();
+    // The format string is passed in the last integer parameter register,
+    // so the integer is passed on the stack.
+    // The long double is always passed on the stack.
+    // The first eight doubles are passed in float registers and the ninth double is passed on the stack.
+    let mut parameters: Vec<(Datatype, ByteSize)> = vec![
+        (Datatype::Integer, ByteSize::new(4)),
+        (Datatype::LongDouble, ByteSize::new(16)),
+    ];
+    parameters.extend(vec![(Datatype::Double, ByteSize::new(8)); 9]);
+    let (var_args, _) = calculate_parameter_locations(
+        parameters,
+        project.get_standard_calling_convention().unwrap(),
+        5,
+        &project.stack_pointer_register,
+        &project.cpu_architecture,
+        &project.datatype_properties,
     );
+    let stack_args: Vec<Arg> = var_args
+        .into_iter()
+        .filter(|arg| matches!(arg, Arg::Stack { .. }))
+        .collect();
+    // The integer occupies a whole stack slot and the long double is aligned to 16 bytes.
+    let expected_stack_args = vec![
+        Arg::Stack {
+            address: Expression::Var(Variable::mock("RSP", 8)).plus_const(8),
+            size: ByteSize::new(4),
+            data_type: Some(Datatype::Integer),
+        },
+        Arg::Stack {
+            address: Expression::Var(Variable::mock("RSP", 8)).plus_const(24),
+            size: ByteSize::new(16),
+            data_type: Some(Datatype::LongDouble),
+        },
+        Arg::Stack {
+            address: Expression::Var(Variable::mock("RSP", 8)).plus_const(40),
+            size: ByteSize::new(8),
+            data_type: Some(Datatype::Double),
+        },
+    ];
+    assert_eq!(stack_args, expected_stack_args);
 }
 
 #[test]
@@ -246,3 +1562,141 @@ fn test_create_stack_arg() {
         ),
     )
 }
+
+#[test]
+/// Tests that stack parameters start at the first stack argument offset of the calling convention.
+fn test_calculate_parameter_locations_with_first_stack_arg_offset() {
+    let project = Project::mock_arm32();
+    // Simulate a MIPS o32-like calling convention, where the caller reserves a home area
+    // for the four register parameters on the stack.
+    let mut cconv = project.get_standard_calling_convention().unwrap().clone();
+    cconv.first_stack_arg_offset = 16;
+    let parameters: Vec<(Datatype, ByteSize)> = vec![
+        (Datatype::Integer, ByteSize::new(4)),
+        (Datatype::Pointer, ByteSize::new(4)),
+    ];
+    let expected_args = vec![
+        Arg::Stack {
+            address: Expression::Var(Variable::mock("sp", 4)).plus_const(16),
+            size: ByteSize::new(4),
+            data_type: Some(Datatype::Integer),
+        },
+        Arg::Stack {
+            address: Expression::Var(Variable::mock("sp", 4)).plus_const(20),
+            size: ByteSize::new(4),
+            data_type: Some(Datatype::Pointer),
+        },
+    ];
+    assert_eq!(
+        expected_args,
+        calculate_parameter_locations(
+            parameters,
+            &cconv,
+            3,
+            &project.stack_pointer_register,
+            &project.cpu_architecture,
+            &project.datatype_properties
+        )
+        .0
+    );
+}
+
+#[test]
+/// Tests that stack parameters on MIPS o32 are placed behind the shadow area of the register parameters
+/// if the calling convention does not define the offset of the first stack parameter.
+fn test_calculate_parameter_locations_with_mips_shadow_area() {
+    let sp = Variable::mock("sp", 4);
+    let mut cconv = CallingConvention::mock_arm32();
+    cconv.integer_parameter_register = ["a0", "a1", "a2", "a3"]
+        .into_iter()
+        .map(|name| Variable::mock(name, 4))
+        .collect();
+    cconv.float_parameter_register = Vec::new();
+    cconv.first_stack_arg_offset = 0;
+    let parameters: Vec<(Datatype, ByteSize)> = vec![(Datatype::Integer, ByteSize::new(4)); 5];
+    let mut expected_args: Vec<Arg> = ["a1", "a2", "a3"]
+        .into_iter()
+        .map(|name| Arg::from_var(Variable::mock(name, 4), Some(Datatype::Integer)))
+        .collect();
+    for offset in [16, 20] {
+        expected_args.push(Arg::Stack {
+            address: Expression::Var(sp.clone()).plus_const(offset),
+            size: ByteSize::new(4),
+            data_type: Some(Datatype::Integer),
+        });
+    }
+    let properties = DatatypeProperties::mock_arm32();
+    assert_eq!(
+        calculate_parameter_locations(parameters.clone(), &cconv, 0, &sp, "MIPS_32", &properties).0,
+        expected_args
+    );
+    // An offset given by the calling convention takes precedence.
+    cconv.first_stack_arg_offset = 24;
+    let (var_args, _) =
+        calculate_parameter_locations(parameters, &cconv, 0, &sp, "MIPS_32", &properties);
+    assert_eq!(
+        var_args[3],
+        Arg::Stack {
+            address: Expression::Var(sp).plus_const(24),
+            size: ByteSize::new(4),
+            data_type: Some(Datatype::Integer),
+        }
+    );
+}
+
+#[test]
+/// Tests that unexpected data types are treated as integers instead of aborting the analysis.
+fn test_calculate_parameter_locations_for_unexpected_data_types() {
+    let project = Project::mock_x64();
+    let parameters: Vec<(Datatype, ByteSize)> = vec![
+        (Datatype::Short, ByteSize::new(2)),
+        (Datatype::Float, ByteSize::new(4)),
+    ];
+    let (var_args, logs) = calculate_parameter_locations(
+        parameters,
+        project.get_standard_calling_convention().unwrap(),
+        0,
+        &project.stack_pointer_register,
+        &project.cpu_architecture,
+        &project.datatype_properties,
+    );
+    assert_eq!(
+        var_args,
+        vec![
+            Arg::from_var(Variable::mock("RSI", 8), Some(Datatype::Integer)),
+            Arg::from_var(Variable::mock("RDX", 8), Some(Datatype::Integer)),
+        ]
+    );
+    assert_eq!(logs.len(), 2);
+}
+
+#[test]
+fn test_estimate_string_length() {
+    let mem_image = RuntimeMemoryImage::mock();
+    // The 'Hello World' string.
+    let pointer: DataDomain<IntervalDomain> =
+        IntervalDomain::from(Bitvector::from_u64(0x3002)).into();
+    assert_eq!(
+        estimate_string_length(&pointer, &mem_image),
+        Bitvector::from_u64(11).into()
+    );
+    // Pointers that are not known constants.
+    let stack_id = AbstractIdentifier::new_from_var(Tid::new("func"), &Variable::mock("RSP", 8));
+    let stack_pointer = DataDomain::from_target(stack_id, IntervalDomain::mock(0, 0));
+    assert_eq!(
+        estimate_string_length(&stack_pointer, &mem_image),
+        IntervalDomain::new_top(ByteSize::new(8))
+    );
+    let unknown_pointer = DataDomain::new_top(ByteSize::new(8));
+    assert_eq!(
+        estimate_string_length(&unknown_pointer, &mem_image),
+        IntervalDomain::new_top(ByteSize::new(8))
+    );
+    // Addresses outside of global memory.
+    let unmapped_pointer: DataDomain<IntervalDomain> =
+        IntervalDomain::from(Bitvector::from_u64(0x10_0000)).into();
+    assert_eq!(
+        estimate_string_length(&unmapped_pointer, &mem_image),
+        IntervalDomain::new_top(ByteSize::new(8))
+    );
+}
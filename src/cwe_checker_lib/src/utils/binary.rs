@@ -6,6 +6,8 @@ use crate::prelude::*;
 use goblin::elf;
 use goblin::pe;
 use goblin::Object;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
 
 /// Contains all information parsed out of the bare metal configuration JSON file.
 ///
@@ -57,11 +59,107 @@ fn parse_hex_string_to_u64(mut string: &str) -> Result<u64, Error> {
     Ok(u64::from_str_radix(string, 16)?)
 }
 
+/// The maximal length of strings read from the memory image
+/// if no explicit bound for the length of the string is given.
+pub const MAX_STRING_LENGTH: usize = 1 << 20;
+
 /// A representation of the runtime image of a binary after being loaded into memory by the loader.
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Hash, Clone)]
 pub struct RuntimeMemoryImage {
     memory_segments: Vec<MemorySegment>,
     is_little_endian: bool,
+    /// The global offset added to the addresses of all memory segments,
+    /// see [`add_global_memory_offset`](RuntimeMemoryImage::add_global_memory_offset).
+    #[serde(default)]
+    base_offset: u64,
+    #[serde(skip)]
+    string_cache: StringCache,
+    #[serde(skip)]
+    segment_decoders: SegmentDecoders,
+}
+
+/// The key of a cached string, consisting of the address of the string,
+/// the size of its characters and the maximal length of the string.
+type StringCacheKey = (u64, ByteSize, usize);
+
+/// A cache for strings read from the memory image,
+/// indexed by the address of the string, the size of its characters and the maximal length of the string.
+///
+/// Since the cache only memoizes results that can be recomputed from the memory image,
+/// it is ignored when comparing or hashing memory images.
+/// Cloning the cache yields an empty cache.
+#[derive(Debug, Default)]
+struct StringCache(Mutex<HashMap<StringCacheKey, Result<String, String>>>);
+
+impl StringCache {
+    /// Remove all cached strings.
+    fn clear(&self) {
+        self.0.lock().unwrap().clear();
+    }
+}
+
+impl Clone for StringCache {
+    fn clone(&self) -> Self {
+        StringCache::default()
+    }
+}
+
+impl PartialEq for StringCache {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for StringCache {}
+
+impl std::hash::Hash for StringCache {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
+/// A function decoding the raw bytes of a memory segment, e.g. a decompressor for a packed section.
+///
+/// The function gets the raw bytes of the whole segment and returns the decoded bytes.
+pub type SegmentDecodeFunction = dyn Fn(&[u8]) -> Result<Vec<u8>, Error> + Send + Sync;
+
+/// A decoder registered for a memory segment together with the decoded contents of the segment.
+#[derive(Clone)]
+struct SegmentDecoder {
+    decode: Arc<SegmentDecodeFunction>,
+    decoded_bytes: Arc<Result<Vec<u8>, String>>,
+}
+
+/// The decoders registered for the memory segments of a memory image,
+/// indexed by the index of the segment in the memory image.
+///
+/// Two sets of decoders are equal if the same decode functions are registered for the same segments.
+#[derive(Default, Clone)]
+struct SegmentDecoders(BTreeMap<usize, SegmentDecoder>);
+
+impl std::fmt::Debug for SegmentDecoders {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.0.keys()).finish()
+    }
+}
+
+impl PartialEq for SegmentDecoders {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.len() == other.0.len()
+            && self.0.iter().zip(other.0.iter()).all(
+                |((index, decoder), (other_index, other_decoder))| {
+                    index == other_index && Arc::ptr_eq(&decoder.decode, &other_decoder.decode)
+                },
+            )
+    }
+}
+
+impl Eq for SegmentDecoders {}
+
+impl std::hash::Hash for SegmentDecoders {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        for index in self.0.keys() {
+            index.hash(state);
+        }
+    }
 }
 
 /// A continuous segment in the memory image.
@@ -165,6 +263,9 @@ impl RuntimeMemoryImage {
                 Ok(RuntimeMemoryImage {
                     memory_segments,
                     is_little_endian: elf_file.header.endianness().unwrap().is_little(),
+                    base_offset: 0,
+                    string_cache: StringCache::default(),
+                    segment_decoders: SegmentDecoders::default(),
                 })
             }
             Object::PE(pe_file) => {
@@ -181,6 +282,9 @@ impl RuntimeMemoryImage {
                 let mut memory_image = RuntimeMemoryImage {
                     memory_segments,
                     is_little_endian: true,
+                    base_offset: 0,
+                    string_cache: StringCache::default(),
+                    segment_decoders: SegmentDecoders::default(),
                 };
                 memory_image.add_global_memory_offset(pe_file.image_base as u64);
                 Ok(memory_image)
@@ -229,6 +333,64 @@ impl RuntimeMemoryImage {
                 MemorySegment::new_bare_metal_ram_segment(ram_base_address, ram_size),
             ],
             is_little_endian,
+            base_offset: 0,
+            string_cache: StringCache::default(),
+            segment_decoders: SegmentDecoders::default(),
+        })
+    }
+
+    /// Combine the runtime memory images of several binaries (e.g. of a main binary and its shared libraries)
+    /// that are loaded at distinct addresses into one memory image containing the memory segments of all binaries.
+    ///
+    /// All read functions of the combined image (e.g. [`read_string_until_null_terminator`](Self::read_string_until_null_terminator))
+    /// read from the segment of the binary that contains the given address.
+    /// Registered segment decoders are kept.
+    /// The global memory offset of the combined image is the global memory offset of the first image,
+    /// so the first image should be the main binary.
+    ///
+    /// Returns an error if no images are given, if the images have different endianness
+    /// or if memory segments of different images overlap.
+    pub fn new_from_images(images: &[RuntimeMemoryImage]) -> Result<Self, Error> {
+        let first_image = images
+            .first()
+            .ok_or_else(|| anyhow!("No memory images to combine."))?;
+        let mut memory_segments: Vec<MemorySegment> = Vec::new();
+        let mut segment_decoders = SegmentDecoders::default();
+        for image in images {
+            if image.is_little_endian != first_image.is_little_endian {
+                return Err(anyhow!(
+                    "Cannot combine memory images with different endianness."
+                ));
+            }
+            for segment in image.memory_segments.iter() {
+                let segment_end = segment.base_address + segment.bytes.len() as u64;
+                if let Some(other) = memory_segments.iter().find(|other| {
+                    segment.base_address < other.base_address + other.bytes.len() as u64
+                        && other.base_address < segment_end
+                }) {
+                    return Err(anyhow!(
+                        "Memory segment at {:#x}-{:#x} overlaps with memory segment at {:#x}-{:#x}.",
+                        segment.base_address,
+                        segment_end,
+                        other.base_address,
+                        other.base_address + other.bytes.len() as u64
+                    ));
+                }
+            }
+            let index_offset = memory_segments.len();
+            for (index, decoder) in image.segment_decoders.0.iter() {
+                segment_decoders
+                    .0
+                    .insert(index + index_offset, decoder.clone());
+            }
+            memory_segments.extend(image.memory_segments.iter().cloned());
+        }
+        Ok(RuntimeMemoryImage {
+            memory_segments,
+            is_little_endian: first_image.is_little_endian,
+            base_offset: first_image.base_offset,
+            string_cache: StringCache::default(),
+            segment_decoders,
         })
     }
 
@@ -245,6 +407,106 @@ impl RuntimeMemoryImage {
         for segment in self.memory_segments.iter_mut() {
             segment.base_address += offset;
         }
+        self.base_offset += offset;
+        // The cached strings are indexed by their (now outdated) addresses.
+        self.string_cache.clear();
+    }
+
+    /// Register a function that decodes the contents of the memory segment containing the given address
+    /// before strings are read from it, e.g. a decompressor for a section that is only unpacked at runtime.
+    ///
+    /// The decoded contents are seen by all functions reading strings from the memory image
+    /// (and thus by the format string resolution),
+    /// while other reads (e.g. [`read`](Self::read)) still see the raw contents of the segment.
+    /// Addresses inside the segment are interpreted as offsets into the decoded contents.
+    /// The decoder is applied once when it is registered.
+    /// Errors of the decoder are reported when strings are read from the segment.
+    /// If no decoder is registered for a segment, then its contents are read unchanged.
+    /// A previously registered decoder of the segment is replaced.
+    ///
+    /// Returns an error if the address is not contained in any memory segment.
+    pub fn register_segment_decoder(
+        &mut self,
+        address: u64,
+        decoder: impl Fn(&[u8]) -> Result<Vec<u8>, Error> + Send + Sync + 'static,
+    ) -> Result<(), Error> {
+        let index = self
+            .memory_segments
+            .iter()
+            .position(|segment| {
+                address >= segment.base_address
+                    && address < segment.base_address + segment.bytes.len() as u64
+            })
+            .ok_or_else(|| anyhow!("Address is not a valid global memory address."))?;
+        let decoded_bytes =
+            decoder(&self.memory_segments[index].bytes).map_err(|err| err.to_string());
+        self.segment_decoders.0.insert(
+            index,
+            SegmentDecoder {
+                decode: Arc::new(decoder),
+                decoded_bytes: Arc::new(decoded_bytes),
+            },
+        );
+        // The cached strings may have been read from the raw contents of the segment.
+        self.string_cache.clear();
+        Ok(())
+    }
+
+    /// Return the contents of the memory segment with the given index that strings are read from,
+    /// i.e. the decoded contents if a decoder is registered for the segment and the raw contents otherwise.
+    ///
+    /// Returns an error if the decoder of the segment failed.
+    fn get_string_bytes_of_segment(&self, index: usize) -> Result<&[u8], Error> {
+        let segment = &self.memory_segments[index];
+        let decoder = match self.segment_decoders.0.get(&index) {
+            Some(decoder) => decoder,
+            None => return Ok(&segment.bytes),
+        };
+        match decoder.decoded_bytes.as_ref() {
+            Ok(bytes) => Ok(bytes),
+            Err(err) => Err(anyhow!(
+                "Could not decode the memory segment at {:#x}-{:#x}: {}",
+                segment.base_address,
+                segment.base_address + segment.bytes.len() as u64,
+                err
+            )),
+        }
+    }
+
+    /// Get the global offset that was added to the addresses of all memory segments.
+    pub fn get_base_offset(&self) -> u64 {
+        self.base_offset
+    }
+
+    /// Translate an address of the binary without the global memory offset
+    /// (see [`add_global_memory_offset`](Self::add_global_memory_offset))
+    /// to the corresponding address in the runtime memory image.
+    ///
+    /// Addresses contained in the memory image are returned unchanged.
+    /// Otherwise the address is rebased by the global memory offset
+    /// if the resulting address is contained in the memory image.
+    pub fn translate_address(&self, address: &Bitvector) -> Bitvector {
+        if self.base_offset == 0 || self.is_mapped_address(address) {
+            return address.clone();
+        }
+        let offset = Bitvector::from_u64(self.base_offset).into_resize_unsigned(address.bytesize());
+        let rebased_address = address.clone() + &offset;
+        if self.is_mapped_address(&rebased_address) {
+            rebased_address
+        } else {
+            address.clone()
+        }
+    }
+
+    /// Returns true if the given address is contained in some memory segment.
+    fn is_mapped_address(&self, address: &Bitvector) -> bool {
+        match address.try_to_u64() {
+            Ok(address) => self.memory_segments.iter().any(|segment| {
+                address >= segment.base_address
+                    && address < segment.base_address + segment.bytes.len() as u64
+            }),
+            Err(_) => false,
+        }
     }
 
     /// Read the contents of the memory image at the given address
@@ -285,20 +547,84 @@ impl RuntimeMemoryImage {
         Err(anyhow!("Address is not a valid global memory address."))
     }
 
+    /// Read a pointer (or any other integer) of the given size from the given address
+    /// respecting the endianness of the binary.
+    ///
+    /// Returns an error if the address is not contained in any memory segment,
+    /// if the read crosses a segment boundary
+    /// or if the segment is writeable, since then its content at runtime is unknown.
+    pub fn read_pointer(&self, address: &Bitvector, size: ByteSize) -> Result<Bitvector, Error> {
+        let address_u64 = address.try_to_u64()?;
+        let segment = self
+            .memory_segments
+            .iter()
+            .find(|segment| {
+                address_u64 >= segment.base_address
+                    && address_u64 < segment.base_address + segment.bytes.len() as u64
+            })
+            .ok_or_else(|| anyhow!("Address {:#x} is not mapped.", address_u64))?;
+        if address_u64 + u64::from(size) > segment.base_address + segment.bytes.len() as u64 {
+            return Err(anyhow!(
+                "Read of {} bytes at address {:#x} crosses a segment boundary.",
+                u64::from(size),
+                address_u64
+            ));
+        }
+        self.read(address, size)?.ok_or_else(|| {
+            anyhow!(
+                "Address {:#x} is contained in a writeable segment.",
+                address_u64
+            )
+        })
+    }
+
     /// Read the contents of memory from a given address onwards until a null byte is reached and checks whether the
     /// content is a valid UTF8 string.
+    ///
+    /// Strings longer than [`MAX_STRING_LENGTH`] are not read,
+    /// see [`read_string_until_null_terminator_bounded`](Self::read_string_until_null_terminator_bounded).
     pub fn read_string_until_null_terminator(&self, address: &Bitvector) -> Result<&str, Error> {
-        let address = address.try_to_u64().unwrap();
-        for segment in self.memory_segments.iter() {
+        self.read_string_until_null_terminator_bounded(address, MAX_STRING_LENGTH)
+    }
+
+    /// Read the contents of memory from a given address onwards until a null byte is reached and checks whether the
+    /// content is a valid UTF8 string.
+    ///
+    /// At most `max_len` bytes (not counting the null terminator) are read.
+    /// Returns an error if no null byte is found within these bytes,
+    /// so that a missing null terminator does not result in scanning the whole memory segment.
+    pub fn read_string_until_null_terminator_bounded(
+        &self,
+        address: &Bitvector,
+        max_len: usize,
+    ) -> Result<&str, Error> {
+        let address = address.try_to_u64()?;
+        for (segment_index, segment) in self.memory_segments.iter().enumerate() {
             if address >= segment.base_address
                 && address <= segment.base_address + segment.bytes.len() as u64
             {
+                let bytes = self.get_string_bytes_of_segment(segment_index)?;
                 let start_index = (address - segment.base_address) as usize;
-                if let Some(end_index) = segment.bytes[start_index..].iter().position(|&b| b == 0) {
+                if start_index > bytes.len() {
+                    return Err(anyhow!("Not a valid string in memory."));
+                }
+                let search_end_index = std::cmp::min(
+                    start_index.saturating_add(max_len).saturating_add(1),
+                    bytes.len(),
+                );
+                if let Some(end_index) = bytes[start_index..search_end_index]
+                    .iter()
+                    .position(|&b| b == 0)
+                {
                     let c_str = std::ffi::CStr::from_bytes_with_nul(
-                        &segment.bytes[start_index..start_index + end_index + 1],
+                        &bytes[start_index..start_index + end_index + 1],
                     )?;
                     return Ok(c_str.to_str()?);
+                } else if search_end_index < bytes.len() {
+                    return Err(anyhow!(
+                        "String exceeds the maximal length of {} bytes.",
+                        max_len
+                    ));
                 } else {
                     return Err(anyhow!("Not a valid string in memory."));
                 }
@@ -308,6 +634,165 @@ impl RuntimeMemoryImage {
         Err(anyhow!("Address is not a valid global memory address."))
     }
 
+    /// Read the contents of memory from a given address onwards until a null character is reached
+    /// and decode the content as a wide character string.
+    ///
+    /// Characters of size 2 are decoded as UTF-16 and characters of size 4 as UTF-32,
+    /// where the bytes of each character are interpreted with the endianness of the CPU architecture.
+    /// Returns an error if no null character is found before the end of the containing memory segment
+    /// or if the content is not a valid wide character string.
+    /// Strings longer than [`MAX_STRING_LENGTH`] characters are not read.
+    pub fn read_wide_string_until_null_terminator(
+        &self,
+        address: &Bitvector,
+        char_size: ByteSize,
+    ) -> Result<String, Error> {
+        self.read_wide_string_until_null_terminator_bounded(address, char_size, MAX_STRING_LENGTH)
+    }
+
+    /// Read a wide character string like [`read_wide_string_until_null_terminator`](Self::read_wide_string_until_null_terminator),
+    /// but read at most `max_len` characters (not counting the null terminator).
+    /// Returns an error if no null character is found within these characters.
+    pub fn read_wide_string_until_null_terminator_bounded(
+        &self,
+        address: &Bitvector,
+        char_size: ByteSize,
+        max_len: usize,
+    ) -> Result<String, Error> {
+        let char_size = u64::from(char_size) as usize;
+        if char_size != 2 && char_size != 4 {
+            return Err(anyhow!("Unsupported wide character size."));
+        }
+        let address = address.try_to_u64()?;
+        for (segment_index, segment) in self.memory_segments.iter().enumerate() {
+            if address >= segment.base_address
+                && address < segment.base_address + segment.bytes.len() as u64
+            {
+                let bytes = self.get_string_bytes_of_segment(segment_index)?;
+                let start_index = (address - segment.base_address) as usize;
+                if start_index > bytes.len() {
+                    return Err(anyhow!("Not a valid string in memory."));
+                }
+                let mut code_units: Vec<u32> = Vec::new();
+                for character in bytes[start_index..].chunks_exact(char_size) {
+                    let code_unit = if self.is_little_endian {
+                        character
+                            .iter()
+                            .rev()
+                            .fold(0u32, |value, byte| (value << 8) | *byte as u32)
+                    } else {
+                        character
+                            .iter()
+                            .fold(0u32, |value, byte| (value << 8) | *byte as u32)
+                    };
+                    if code_unit == 0 {
+                        return if char_size == 2 {
+                            let code_units: Vec<u16> =
+                                code_units.into_iter().map(|unit| unit as u16).collect();
+                            Ok(String::from_utf16(&code_units)?)
+                        } else {
+                            code_units
+                                .into_iter()
+                                .map(char::from_u32)
+                                .collect::<Option<String>>()
+                                .ok_or_else(|| anyhow!("Not a valid UTF-32 string in memory."))
+                        };
+                    }
+                    if code_units.len() == max_len {
+                        return Err(anyhow!(
+                            "String exceeds the maximal length of {} characters.",
+                            max_len
+                        ));
+                    }
+                    code_units.push(code_unit);
+                }
+                return Err(anyhow!("Not a valid string in memory."));
+            }
+        }
+
+        Err(anyhow!("Address is not a valid global memory address."))
+    }
+
+    /// Read a string of at most `max_len` characters from the given address until a null character is reached.
+    ///
+    /// Characters of size 1 are read as a UTF-8 string (see [`read_string_until_null_terminator_bounded`](Self::read_string_until_null_terminator_bounded)),
+    /// larger characters as a wide character string (see [`read_wide_string_until_null_terminator_bounded`](Self::read_wide_string_until_null_terminator_bounded)).
+    /// The results are cached, so that repeated reads of the same string do not need to decode the string again.
+    pub fn read_cached_string(
+        &self,
+        address: &Bitvector,
+        char_size: ByteSize,
+        max_len: usize,
+    ) -> Result<String, Error> {
+        let key = (address.try_to_u64()?, char_size, max_len);
+        if let Some(result) = self.string_cache.0.lock().unwrap().get(&key) {
+            return result.clone().map_err(|err| anyhow!("{}", err));
+        }
+        let result = if char_size == ByteSize::new(1) {
+            self.read_string_until_null_terminator_bounded(address, max_len)
+                .map(|string| string.to_string())
+        } else {
+            self.read_wide_string_until_null_terminator_bounded(address, char_size, max_len)
+        };
+        self.string_cache.0.lock().unwrap().insert(
+            key,
+            result
+                .as_ref()
+                .map(|string| string.clone())
+                .map_err(|err| err.to_string()),
+        );
+        result
+    }
+
+    /// Enumerate all null-terminated strings of printable ASCII characters
+    /// with a length of at least `min_length` characters in the memory segment containing the given address,
+    /// e.g. to collect candidate format strings.
+    ///
+    /// Returns the strings together with their start addresses in the order of their addresses.
+    /// Each string ends at a null byte and starts after the preceding null byte or non-printable byte.
+    /// Thus strings embedded in other strings (i.e. suffixes of other strings) are not returned separately,
+    /// but a string starting right after the null terminator of another string is.
+    /// Like all string reads, the strings are read from the decoded contents of the segment
+    /// if a decoder is registered for it (see [`register_segment_decoder`](Self::register_segment_decoder)).
+    ///
+    /// Returns an error if the address is not contained in any memory segment
+    /// or if the decoder of the segment failed.
+    pub fn iter_strings_in_segment(
+        &self,
+        address: u64,
+        min_length: usize,
+    ) -> Result<impl Iterator<Item = (u64, &str)> + '_, Error> {
+        let index = self
+            .memory_segments
+            .iter()
+            .position(|segment| {
+                address >= segment.base_address
+                    && address < segment.base_address + segment.bytes.len() as u64
+            })
+            .ok_or_else(|| anyhow!("Address is not a valid global memory address."))?;
+        let base_address = self.memory_segments[index].base_address;
+        let bytes = self.get_string_bytes_of_segment(index)?;
+        let is_printable =
+            |byte: &u8| byte.is_ascii_graphic() || matches!(byte, b' ' | b'\t' | b'\n' | b'\r');
+        let mut chunk_start = 0;
+        Ok(bytes
+            .iter()
+            .enumerate()
+            .filter(|(_, byte)| **byte == 0)
+            .filter_map(move |(end, _)| {
+                let string_start = bytes[chunk_start..end]
+                    .iter()
+                    .rposition(|byte| !is_printable(byte))
+                    .map_or(chunk_start, |position| chunk_start + position + 1);
+                chunk_start = end + 1;
+                if end - string_start < min_length {
+                    return None;
+                }
+                let string = std::str::from_utf8(&bytes[string_start..end]).ok()?;
+                Some((base_address + string_start as u64, string))
+            }))
+    }
+
     /// Checks whether the constant is a global memory address.
     pub fn is_global_memory_address(&self, constant: &Bitvector) -> bool {
         if self.read(constant, constant.bytesize()).is_ok() {
@@ -325,18 +810,9 @@ impl RuntimeMemoryImage {
         start_address: u64,
         end_address: u64,
     ) -> Result<bool, Error> {
-        for segment in self.memory_segments.iter() {
-            if start_address >= segment.base_address
-                && start_address < segment.base_address + segment.bytes.len() as u64
-            {
-                if end_address <= segment.base_address + segment.bytes.len() as u64 {
-                    return Ok(segment.read_flag);
-                } else {
-                    return Err(anyhow!("Interval spans more than one segment"));
-                }
-            }
-        }
-        Err(anyhow!("Address not contained in runtime memory image"))
+        Ok(self
+            .get_segment_of_interval(start_address, end_address)?
+            .read_flag)
     }
 
     /// For an address to global read-only memory, return the memory segment it points to
@@ -387,12 +863,26 @@ impl RuntimeMemoryImage {
         start_address: u64,
         end_address: u64,
     ) -> Result<bool, Error> {
+        Ok(self
+            .get_segment_of_interval(start_address, end_address)?
+            .write_flag)
+    }
+
+    /// Return the memory segment containing all addresses of the interval from `start_address` to `end_address`,
+    /// where the end address is not included in the interval.
+    ///
+    /// Returns an error if the interval spans more than one memory segment
+    /// or if the start address is not contained in the runtime memory image.
+    fn get_segment_of_interval(
+        &self,
+        start_address: u64,
+        end_address: u64,
+    ) -> Result<&MemorySegment, Error> {
         for segment in self.memory_segments.iter() {
-            if start_address >= segment.base_address
-                && start_address < segment.base_address + segment.bytes.len() as u64
-            {
-                if end_address <= segment.base_address + segment.bytes.len() as u64 {
-                    return Ok(segment.write_flag);
+            let segment_end = segment.base_address + segment.bytes.len() as u64;
+            if start_address >= segment.base_address && start_address < segment_end {
+                if end_address <= segment_end {
+                    return Ok(segment);
                 } else {
                     return Err(anyhow!("Interval spans more than one segment"));
                 }
@@ -400,6 +890,56 @@ impl RuntimeMemoryImage {
         }
         Err(anyhow!("Address not contained in runtime memory image"))
     }
+
+    /// Return the memory segment containing all bytes of the address range
+    /// starting at `address` with the given length.
+    ///
+    /// Returns an error if the range spans more than one memory segment
+    /// or if the start address is not contained in the runtime memory image.
+    fn get_segment_of_address_range(
+        &self,
+        address: &Bitvector,
+        length: u64,
+    ) -> Result<&MemorySegment, Error> {
+        let start_address = address.try_to_u64()?;
+        let end_address = start_address
+            .checked_add(length)
+            .ok_or_else(|| anyhow!("Address range overflows the address space"))?;
+        self.get_segment_of_interval(start_address, end_address)
+    }
+
+    /// Check whether the address range starting at `address` with the given length
+    /// is contained in a writeable segment of the runtime memory image.
+    ///
+    /// Returns an error if the range spans more than one memory segment
+    /// or if it does not point to global memory at all.
+    pub fn is_writable(&self, address: &Bitvector, length: u64) -> Result<bool, Error> {
+        Ok(self
+            .get_segment_of_address_range(address, length)?
+            .write_flag)
+    }
+
+    /// Check whether the address range starting at `address` with the given length
+    /// is contained in a readable segment of the runtime memory image.
+    ///
+    /// Returns an error if the range spans more than one memory segment
+    /// or if it does not point to global memory at all.
+    pub fn is_readable(&self, address: &Bitvector, length: u64) -> Result<bool, Error> {
+        Ok(self
+            .get_segment_of_address_range(address, length)?
+            .read_flag)
+    }
+
+    /// Check whether the address range starting at `address` with the given length
+    /// is contained in an executable segment of the runtime memory image.
+    ///
+    /// Returns an error if the range spans more than one memory segment
+    /// or if it does not point to global memory at all.
+    pub fn is_executable(&self, address: &Bitvector, length: u64) -> Result<bool, Error> {
+        Ok(self
+            .get_segment_of_address_range(address, length)?
+            .execute_flag)
+    }
 }
 
 #[cfg(test)]
@@ -480,8 +1020,68 @@ pub mod tests {
                         write_flag: false,
                         execute_flag: false,
                     },
+                    // Contains the wide strings: UTF-16 'id %d' at 0x8000, UTF-32 '%s' at 0x800c
+                    // and the unterminated UTF-16 string 'a' at 0x8018.
+                    MemorySegment {
+                        bytes: [
+                            0x69, 0x00, 0x64, 0x00, 0x20, 0x00, 0x25, 0x00, 0x64, 0x00, 0x00, 0x00,
+                            0x25, 0x00, 0x00, 0x00, 0x73, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                            0x61, 0x00,
+                        ]
+                        .to_vec(),
+                        base_address: 0x8000,
+                        read_flag: true,
+                        write_flag: false,
+                        execute_flag: false,
+                    },
+                    // Contains a pointer to the pointer at 0x4000 at 0x9000
+                    // and two pointers pointing to each other at 0x9008 and 0x9010.
+                    MemorySegment {
+                        bytes: [
+                            0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x90, 0x00, 0x00,
+                            0x00, 0x00, 0x00, 0x00, 0x08, 0x90, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                        ]
+                        .to_vec(),
+                        base_address: 0x9000,
+                        read_flag: true,
+                        write_flag: false,
+                        execute_flag: false,
+                    },
                 ],
                 is_little_endian: true,
+                base_offset: 0,
+                string_cache: StringCache::default(),
+                segment_decoders: SegmentDecoders::default(),
+            }
+        }
+
+        /// Creates a mock runtime memory image of a big-endian 32-bit binary (e.g. for MIPS or PowerPC)
+        /// with a table of pointers to format strings.
+        pub fn mock_big_endian() -> RuntimeMemoryImage {
+            RuntimeMemoryImage {
+                memory_segments: vec![
+                    // Contains a pointer to the format string at 0x2000 at 0x1000
+                    // and a pointer to the format string at 0x2003 at 0x1004.
+                    MemorySegment {
+                        bytes: [0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x03].to_vec(),
+                        base_address: 0x1000,
+                        read_flag: true,
+                        write_flag: false,
+                        execute_flag: false,
+                    },
+                    // Contains the strings '%s' and 'id %d'.
+                    MemorySegment {
+                        bytes: [0x25, 0x73, 0x00, 0x69, 0x64, 0x20, 0x25, 0x64, 0x00].to_vec(),
+                        base_address: 0x2000,
+                        read_flag: true,
+                        write_flag: false,
+                        execute_flag: false,
+                    },
+                ],
+                is_little_endian: false,
+                base_offset: 0,
+                string_cache: StringCache::default(),
+                segment_decoders: SegmentDecoders::default(),
             }
         }
     }
@@ -501,6 +1101,221 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_read_pointer() {
+        let mut mem_image = RuntimeMemoryImage::mock();
+        let address = Bitvector::from_u64(0x9000);
+        assert_eq!(
+            mem_image.read_pointer(&address, ByteSize::new(8)).unwrap(),
+            Bitvector::from_u64(0x4000)
+        );
+        mem_image.is_little_endian = false;
+        assert_eq!(
+            mem_image.read_pointer(&address, ByteSize::new(4)).unwrap(),
+            Bitvector::from_u32(0x00400000)
+        );
+        // Unmapped address
+        assert!(mem_image
+            .read_pointer(&Bitvector::from_u64(0xa000), ByteSize::new(8))
+            .is_err());
+        // Read crossing the segment boundary
+        assert!(mem_image
+            .read_pointer(&Bitvector::from_u64(0x1002), ByteSize::new(4))
+            .is_err());
+        // Writeable segment
+        assert!(mem_image
+            .read_pointer(&Bitvector::from_u64(0x2000), ByteSize::new(8))
+            .is_err());
+    }
+
+    #[test]
+    fn test_segment_permissions_of_address_range() {
+        let mut mem_image = RuntimeMemoryImage::mock();
+        mem_image.memory_segments[0].execute_flag = true;
+        // Read-only segment
+        let address = Bitvector::from_u64(0x1000);
+        assert!(!mem_image.is_writable(&address, 5).unwrap());
+        assert!(mem_image.is_readable(&address, 5).unwrap());
+        assert!(mem_image.is_executable(&address, 5).unwrap());
+        // Writeable segment
+        let address = Bitvector::from_u64(0x2004);
+        assert!(mem_image.is_writable(&address, 4).unwrap());
+        assert!(!mem_image.is_executable(&address, 4).unwrap());
+        // Range crossing the segment boundary
+        assert!(mem_image.is_writable(&address, 5).is_err());
+        assert!(mem_image.is_readable(&address, 5).is_err());
+        // Unmapped address
+        let address = Bitvector::from_u64(0xa000);
+        assert!(mem_image.is_writable(&address, 1).is_err());
+        assert!(mem_image.is_executable(&address, 1).is_err());
+    }
+
+    #[test]
+    fn test_read_cached_string() {
+        let mut mem_image = RuntimeMemoryImage::mock();
+        let address = Bitvector::from_u32(0x7000);
+        let wide_address = Bitvector::from_u32(0x8000);
+        for _ in 0..2 {
+            assert_eq!(
+                mem_image
+                    .read_cached_string(&address, ByteSize::new(1), MAX_STRING_LENGTH)
+                    .unwrap(),
+                "str1 str2 str3 str4"
+            );
+            assert_eq!(
+                mem_image
+                    .read_cached_string(&wide_address, ByteSize::new(2), MAX_STRING_LENGTH)
+                    .unwrap(),
+                "id %d"
+            );
+            assert!(mem_image
+                .read_cached_string(
+                    &Bitvector::from_u32(0x8018),
+                    ByteSize::new(2),
+                    MAX_STRING_LENGTH
+                )
+                .is_err());
+        }
+        assert_eq!(mem_image.string_cache.0.lock().unwrap().len(), 3);
+        // Cached strings do not influence comparisons of memory images.
+        assert_eq!(mem_image, RuntimeMemoryImage::mock());
+        // Changing the memory image invalidates the cache.
+        mem_image.add_global_memory_offset(0x1000);
+        assert!(mem_image.string_cache.0.lock().unwrap().is_empty());
+        assert_eq!(
+            mem_image
+                .read_cached_string(
+                    &Bitvector::from_u32(0x8000),
+                    ByteSize::new(1),
+                    MAX_STRING_LENGTH
+                )
+                .unwrap(),
+            "str1 str2 str3 str4"
+        );
+    }
+
+    #[test]
+    fn test_segment_decoder() {
+        let mut mem_image = RuntimeMemoryImage::mock();
+        let address = Bitvector::from_u32(0x7000);
+        assert_eq!(
+            mem_image
+                .read_cached_string(&address, ByteSize::new(1), MAX_STRING_LENGTH)
+                .unwrap(),
+            "str1 str2 str3 str4"
+        );
+        assert!(mem_image
+            .register_segment_decoder(0xa000, |bytes| Ok(bytes.to_vec()))
+            .is_err());
+        mem_image
+            .register_segment_decoder(0x7004, |bytes| Ok(bytes.to_ascii_uppercase()))
+            .unwrap();
+        // String reads see the decoded contents, other reads see the raw contents.
+        assert_eq!(
+            mem_image
+                .read_string_until_null_terminator(&Bitvector::from_u32(0x7005))
+                .unwrap(),
+            "STR2 STR3 STR4"
+        );
+        assert_eq!(
+            mem_image
+                .read_cached_string(&address, ByteSize::new(1), MAX_STRING_LENGTH)
+                .unwrap(),
+            "STR1 STR2 STR3 STR4"
+        );
+        assert_eq!(
+            mem_image.read(&address, ByteSize::new(1)).unwrap(),
+            Some(Bitvector::from_u8(b's'))
+        );
+        assert_ne!(mem_image, RuntimeMemoryImage::mock());
+        // Errors of the decoder are reported.
+        mem_image
+            .register_segment_decoder(0x7000, |_| Err(anyhow!("Unknown compression")))
+            .unwrap();
+        let err = mem_image
+            .read_string_until_null_terminator(&address)
+            .unwrap_err();
+        assert!(err.to_string().contains("Unknown compression"));
+    }
+
+    #[test]
+    fn test_iter_strings_in_segment() {
+        let mem_image = RuntimeMemoryImage::mock();
+        assert_eq!(
+            mem_image
+                .iter_strings_in_segment(0x5004, 1)
+                .unwrap()
+                .collect::<Vec<_>>(),
+            vec![(0x5000, "/dev/sd%c%d"), (0x500c, "cat %s")]
+        );
+        assert_eq!(
+            mem_image
+                .iter_strings_in_segment(0x5000, 7)
+                .unwrap()
+                .collect::<Vec<_>>(),
+            vec![(0x5000, "/dev/sd%c%d")]
+        );
+        // Non-printable bytes before a string are skipped.
+        assert_eq!(
+            mem_image
+                .iter_strings_in_segment(0x3000, 1)
+                .unwrap()
+                .collect::<Vec<_>>(),
+            vec![(0x3002, "Hello World")]
+        );
+        // A segment without null-terminated printable strings.
+        assert_eq!(
+            mem_image
+                .iter_strings_in_segment(0x1000, 0)
+                .unwrap()
+                .count(),
+            0
+        );
+        assert!(mem_image.iter_strings_in_segment(0xa000, 1).is_err());
+    }
+
+    #[test]
+    fn test_combine_memory_images() {
+        let main_image = RuntimeMemoryImage::mock();
+        let mut library_image = RuntimeMemoryImage::mock();
+        library_image.add_global_memory_offset(0x10000);
+        library_image
+            .register_segment_decoder(0x17000, |bytes| Ok(bytes.to_ascii_uppercase()))
+            .unwrap();
+        let combined_image =
+            RuntimeMemoryImage::new_from_images(&[main_image.clone(), library_image]).unwrap();
+        assert_eq!(
+            combined_image
+                .read_string_until_null_terminator(&Bitvector::from_u64(0x7000))
+                .unwrap(),
+            "str1 str2 str3 str4"
+        );
+        assert_eq!(
+            combined_image
+                .read_string_until_null_terminator(&Bitvector::from_u64(0x17000))
+                .unwrap(),
+            "STR1 STR2 STR3 STR4"
+        );
+        assert_eq!(
+            combined_image
+                .read_pointer(&Bitvector::from_u64(0x19000), ByteSize::new(8))
+                .unwrap(),
+            Bitvector::from_u64(0x4000)
+        );
+        assert!(combined_image
+            .read_pointer(&Bitvector::from_u64(0x1a000), ByteSize::new(8))
+            .is_err());
+        // Overlapping images
+        assert!(
+            RuntimeMemoryImage::new_from_images(&[main_image.clone(), main_image.clone()]).is_err()
+        );
+        // Images with different endianness
+        let mut big_endian_image = RuntimeMemoryImage::mock_big_endian();
+        big_endian_image.add_global_memory_offset(0x10000);
+        assert!(RuntimeMemoryImage::new_from_images(&[main_image, big_endian_image]).is_err());
+        assert!(RuntimeMemoryImage::new_from_images(&[]).is_err());
+    }
+
     #[test]
     fn ro_data_pointer() {
         let mem_image = RuntimeMemoryImage::mock();
@@ -524,4 +1339,90 @@ pub mod tests {
                 .unwrap(),
         );
     }
+
+    #[test]
+    fn test_read_string_until_null_terminator_bounded() {
+        let mem_image = RuntimeMemoryImage::mock();
+        let address = Bitvector::from_u32(0x3002);
+        assert_eq!(
+            mem_image
+                .read_string_until_null_terminator_bounded(&address, 11)
+                .unwrap(),
+            "Hello World"
+        );
+        // The null terminator is not found within the first 10 bytes.
+        assert!(mem_image
+            .read_string_until_null_terminator_bounded(&address, 10)
+            .is_err());
+        // The maximal length may be arbitrarily large.
+        assert_eq!(
+            mem_image
+                .read_string_until_null_terminator_bounded(&address, usize::MAX)
+                .unwrap(),
+            "Hello World"
+        );
+        // Addresses that do not fit into 64 bits are not valid addresses.
+        assert!(mem_image
+            .read_string_until_null_terminator_bounded(&Bitvector::from_u128(1 << 64), 11)
+            .is_err());
+        // The same holds for wide character strings.
+        let wide_address = Bitvector::from_u32(0x8000);
+        assert_eq!(
+            mem_image
+                .read_wide_string_until_null_terminator_bounded(&wide_address, ByteSize::new(2), 5)
+                .unwrap(),
+            "id %d"
+        );
+        assert!(mem_image
+            .read_wide_string_until_null_terminator_bounded(&wide_address, ByteSize::new(2), 4)
+            .is_err());
+        // Results with different bounds are cached separately.
+        assert!(mem_image
+            .read_cached_string(&address, ByteSize::new(1), 10)
+            .is_err());
+        assert_eq!(
+            mem_image
+                .read_cached_string(&address, ByteSize::new(1), 11)
+                .unwrap(),
+            "Hello World"
+        );
+    }
+
+    #[test]
+    fn test_read_wide_string_until_null_terminator() {
+        let mut mem_image = RuntimeMemoryImage::mock();
+        assert_eq!(
+            "id %d",
+            mem_image
+                .read_wide_string_until_null_terminator(
+                    &Bitvector::from_u32(0x8000),
+                    ByteSize::new(2)
+                )
+                .unwrap()
+        );
+        assert_eq!(
+            "%s",
+            mem_image
+                .read_wide_string_until_null_terminator(
+                    &Bitvector::from_u32(0x800c),
+                    ByteSize::new(4)
+                )
+                .unwrap()
+        );
+        // The string is not terminated before the end of the segment.
+        assert!(mem_image
+            .read_wide_string_until_null_terminator(&Bitvector::from_u32(0x8018), ByteSize::new(2))
+            .is_err());
+        // With big-endian byte order the first character is decoded as U+6900.
+        mem_image.is_little_endian = false;
+        assert_eq!(
+            "\u{6900}\u{6400}\u{2000}\u{2500}\u{6400}",
+            mem_image
+                .read_wide_string_until_null_terminator(
+                    &Bitvector::from_u32(0x8000),
+                    ByteSize::new(2)
+                )
+                .unwrap()
+        );
+    }
 }
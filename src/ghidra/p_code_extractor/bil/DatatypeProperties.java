@@ -21,6 +21,8 @@ public class DatatypeProperties {
     private int pointerSize;
     @SerializedName("short_size")
     private int shortSize;
+    @SerializedName("wide_char_size")
+    private int wideCharSize;
 
     public DatatypeProperties(
         int charSize,
@@ -31,7 +33,8 @@ public class DatatypeProperties {
         int longLongSize,
         int longSize,
         int pointerSize,
-        int shortSize
+        int shortSize,
+        int wideCharSize
     ) {
         this.setCharSize(charSize);
         this.setDoubleSize(doubleSize);
@@ -42,6 +45,7 @@ public class DatatypeProperties {
         this.setLongSize(longSize);
         this.setPointerSize(pointerSize);
         this.setShortSize(shortSize);
+        this.setWideCharSize(wideCharSize);
     }
 
     public void setCharSize(int size) {
@@ -79,5 +83,9 @@ public class DatatypeProperties {
     public void setShortSize(int size) {
         this.shortSize = size;
     }
+
+    public void setWideCharSize(int size) {
+        this.wideCharSize = size;
+    }
 }
 
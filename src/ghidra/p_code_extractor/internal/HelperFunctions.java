@@ -52,7 +52,8 @@ public final class HelperFunctions {
             dataOrga.getLongLongSize(),
             dataOrga.getLongSize(),
             dataOrga.getPointerSize(),
-            dataOrga.getShortSize()
+            dataOrga.getShortSize(),
+            dataOrga.getWideCharSize()
         );
     }
 
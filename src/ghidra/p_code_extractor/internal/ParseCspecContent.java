@@ -317,10 +317,12 @@ public class ParseCspecContent {
      * 
      * Parses the parameter registers for an external symbol.
      * Differentiates between integer and float registers.
+     * The offset of the first stack parameter entry is also extracted.
      */
     public static void parseInput(XmlPullParser parser, RegisterConvention convention) {
         ArrayList<String> integerRegisters = new ArrayList<String>();
         ArrayList<String> floatRegisters = new ArrayList<String>(); 
+        Long firstStackArgOffset = null;
         parser.start("input");
         while(parser.peek().isStart()) {
             XmlElement pentry = parser.peek();
@@ -334,6 +336,12 @@ public class ParseCspecContent {
                     integerRegisters.add(entry.getAttribute("name"));
                 }
                 parser.end();
+            } else if(entry.getName().equals("addr") && isStackEntry(entry)) {
+                parser.start("addr");
+                if(firstStackArgOffset == null) {
+                    firstStackArgOffset = Long.decode(entry.getAttribute("offset"));
+                }
+                parser.end();
             } else {
                 discardSubTree(parser);
             }
@@ -343,6 +351,18 @@ public class ParseCspecContent {
 
         convention.setFloatParameter(floatRegisters);
         convention.setIntegerParameter(integerRegisters);
+        if(firstStackArgOffset != null) {
+            convention.setFirstStackArgOffset(firstStackArgOffset);
+        }
+    }
+
+    /**
+     * 
+     * @param entry: Address element of a parameter entry
+     * @return: indicates whether the address is located on the stack.
+     */
+    public static Boolean isStackEntry(XmlElement entry) {
+        return entry.hasAttribute("space") && entry.getAttribute("space").equals("stack");
     }
 
     /**
@@ -20,6 +20,8 @@ public class RegisterConvention {
     private ArrayList<String> unaffected;
     @SerializedName("killed_by_call_register")
     private ArrayList<String> killedByCall;
+    @SerializedName("first_stack_arg_offset")
+    private long firstStackArgOffset;
 
     public RegisterConvention() {
         this.setIntegerParameter(new ArrayList<String>());
@@ -102,4 +104,12 @@ public class RegisterConvention {
     public void setKilledByCall(ArrayList<String> killedByCall) {
         this.killedByCall = killedByCall;
     }
+
+    public long getFirstStackArgOffset() {
+        return firstStackArgOffset;
+    }
+
+    public void setFirstStackArgOffset(long firstStackArgOffset) {
+        this.firstStackArgOffset = firstStackArgOffset;
+    }
 }